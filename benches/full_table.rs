@@ -0,0 +1,70 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use lib_blackjack::{compute_all_hand_ev, Card, Deck, Hand, RuleSet};
+use rustc_hash::FxHashMap;
+
+// Splitting is nearly all of it for a single deck, where every split hand plays
+// the second hand from its own deck. Bigger shoes play the second hands once
+// from the deck at the split. On one core in release this measured about 6.6s
+// for 1 deck and 0.12s to 0.14s for 2, 6 and 8, which rayon divides between
+// the cores.
+fn full_table(c: &mut Criterion) {
+  let mut group = c.benchmark_group("full_table");
+  // A single run takes seconds, so keep to the fewest samples criterion allows
+  group.sample_size(10);
+  for decks in [1, 2, 6, 8].iter() {
+    group.bench_with_input(BenchmarkId::from_parameter(decks), decks, |b, decks| {
+      let deck = Deck::generate(*decks);
+      b.iter(|| compute_all_hand_ev(&deck, &RuleSet::default()))
+    });
+  }
+  group.finish();
+}
+
+// Resplitting to four hands, where the paths to the same cards share passes.
+// This measured about 15s on one core, from 18.4s before they were shared.
+fn resplits(c: &mut Criterion) {
+  let mut group = c.benchmark_group("resplits");
+  group.sample_size(10);
+  let deck = Deck::generate(1);
+  let rules = RuleSet {
+    max_split_hands: 4,
+    ..Default::default()
+  };
+  group.bench_function("1", |b| b.iter(|| compute_all_hand_ev(&deck, &rules)));
+  group.finish();
+}
+
+// Every hand without any splitting, which is mostly the hand enumeration
+fn no_splits(c: &mut Criterion) {
+  let mut group = c.benchmark_group("no_splits");
+  for decks in [1, 2, 6, 8].iter() {
+    group.bench_with_input(BenchmarkId::from_parameter(decks), decks, |b, decks| {
+      let deck = Deck::generate(*decks);
+      let rules = RuleSet {
+        max_split_hands: 1,
+        ..Default::default()
+      };
+      b.iter(|| compute_all_hand_ev(&deck, &rules))
+    });
+  }
+  group.finish();
+}
+
+// What every dealer cache lookup does with the remaining shoe
+fn deck_key(c: &mut Criterion) {
+  let shoe = Deck::generate(8);
+  let hand = Hand::from([Card::Eight, Card::Eight, Card::Three, Card::Two]);
+  let mut cache = FxHashMap::default();
+  for card in shoe.rank_iter() {
+    cache.insert((&(&shoe - &hand).unwrap() - card).unwrap(), card);
+  }
+  c.bench_function("deck_key/remove_and_look_up", |b| {
+    b.iter(|| {
+      let rest = (black_box(&shoe) - black_box(&hand)).unwrap();
+      cache.get(&(&rest - Card::Ten).unwrap()).cloned()
+    })
+  });
+}
+
+criterion_group!(benches, deck_key, no_splits, full_table, resplits);
+criterion_main!(benches);
@@ -0,0 +1,217 @@
+// Generates the unrolled dealer probability functions for every dealer variant
+// listed in VARIANTS.
+//
+// Every non-busting final dealer hand is a multiset of cards, and the chance
+// of drawing a multiset in any one order doesn't depend on the order. So for
+// each multiset we emit one running product, built on the product for the
+// multiset with its highest card removed, and add it to each up card's
+// outcome once per draw order that the stopping rule would actually follow.
+
+use std::collections::{BTreeSet, HashMap};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+type Rule = fn(u32, bool) -> bool;
+
+const VARIANTS: &[(&str, Rule)] = &[
+  ("s17", |total, _soft| total >= 17),
+  ("h17", |total, soft| total > 17 || (total == 17 && !soft)),
+  ("s16", |total, _soft| total >= 16),
+];
+
+// Index of a dealer blackjack in the prob rows, after the totals 0..=21
+const BLACKJACK: usize = 22;
+// Followed by the dealer busting on exactly 22
+const TWENTY_TWO: usize = 23;
+
+fn main() {
+  let mut out = String::new();
+  for (name, rule) in VARIANTS {
+    generate(&mut out, name, *rule);
+  }
+  let path = Path::new(&env::var("OUT_DIR").unwrap()).join("generated.rs");
+  fs::write(path, out).unwrap();
+  println!("cargo:rerun-if-changed=build.rs");
+}
+
+// Counts the orders in which the dealer, starting from `up`, draws exactly
+// the cards in `rest` and then stops, keyed by where they stop.
+fn count_orders(
+  rest: &mut [u32; 10],
+  left: u32,
+  hard: u32,
+  has_ace: bool,
+  cards: u32,
+  rule: Rule,
+  counts: &mut HashMap<usize, u64>,
+) {
+  if cards >= 2 {
+    let soft = has_ace && hard <= 11;
+    let total = if soft { hard + 10 } else { hard };
+    if total > 22 {
+      return;
+    }
+    let outcome = if total == 22 {
+      Some(TWENTY_TWO)
+    } else if cards == 2 && total == 21 {
+      Some(BLACKJACK)
+    } else if rule(total, soft) {
+      Some(total as usize)
+    } else {
+      None
+    };
+    if let Some(outcome) = outcome {
+      if left == 0 {
+        *counts.entry(outcome).or_insert(0) += 1;
+      }
+      return;
+    }
+  }
+  if left == 0 {
+    return;
+  }
+
+  for r in 0..10 {
+    if rest[r] == 0 {
+      continue;
+    }
+    rest[r] -= 1;
+    count_orders(
+      rest,
+      left - 1,
+      hard + r as u32 + 1,
+      has_ace || r == 0,
+      cards + 1,
+      rule,
+      counts,
+    );
+    rest[r] += 1;
+  }
+}
+
+struct Node {
+  cards: Vec<usize>,
+  // (up card, outcome, number of orders)
+  contributions: Vec<(usize, usize, u64)>,
+}
+
+fn enumerate(cards: &mut Vec<usize>, hard: u32, rule: Rule, nodes: &mut Vec<Node>) {
+  let min = cards.last().cloned().unwrap_or(0);
+  for r in min..10 {
+    if hard + r as u32 + 1 > 22 {
+      break;
+    }
+    cards.push(r);
+    let mut hist = [0_u32; 10];
+    for c in cards.iter() {
+      hist[*c] += 1;
+    }
+    let mut contributions = Vec::new();
+    for up in 0..10 {
+      if hist[up] == 0 {
+        continue;
+      }
+      let mut rest = hist;
+      rest[up] -= 1;
+      let mut counts = HashMap::new();
+      count_orders(
+        &mut rest,
+        cards.len() as u32 - 1,
+        up as u32 + 1,
+        up == 0,
+        1,
+        rule,
+        &mut counts,
+      );
+      let mut counts: Vec<_> = counts.into_iter().collect();
+      counts.sort();
+      for (outcome, n) in counts {
+        contributions.push((up, outcome, n));
+      }
+    }
+    nodes.push(Node {
+      cards: cards.clone(),
+      contributions,
+    });
+    enumerate(cards, hard + r as u32 + 1, rule, nodes);
+    cards.pop();
+  }
+}
+
+fn generate(out: &mut String, name: &str, rule: Rule) {
+  let mut nodes = Vec::new();
+  enumerate(&mut Vec::new(), 0, rule, &mut nodes);
+
+  // Only emit the products that contribute, plus the chains leading to them
+  let mut needed = BTreeSet::new();
+  for node in nodes.iter().filter(|n| !n.contributions.is_empty()) {
+    for len in 1..=node.cards.len() {
+      needed.insert(node.cards[..len].to_vec());
+    }
+  }
+
+  let mut factors = BTreeSet::new();
+  let mut body = String::new();
+  let mut ids = HashMap::new();
+  for node in nodes.iter().filter(|n| needed.contains(&n.cards)) {
+    let id = ids.len();
+    let (&last, prefix) = node.cards.split_last().unwrap();
+    if prefix.is_empty() {
+      writeln!(body, "p[{}] = d[{}] as f64;", id, last).unwrap();
+    } else {
+      let k = prefix.iter().filter(|c| **c == last).count();
+      let j = prefix.len();
+      factors.insert((last, k, j));
+      writeln!(
+        body,
+        "p[{}] = p[{}] * p_{}_{}_{};",
+        id,
+        ids[prefix],
+        last + 1,
+        k,
+        j
+      )
+      .unwrap();
+    }
+    for (up, outcome, n) in node.contributions.iter() {
+      if *n == 1 {
+        writeln!(body, "    prob[{}][{}] += p[{}];", up, outcome, id).unwrap();
+      } else {
+        writeln!(body, "    prob[{}][{}] += {}_f64 * p[{}];", up, outcome, n, id).unwrap();
+      }
+    }
+    ids.insert(node.cards.clone(), id);
+  }
+
+  writeln!(
+    out,
+    "pub fn calculate_dealer_prob_{}(d: &[usize; 10]) -> [DealerProb; 10] {{",
+    name
+  )
+  .unwrap();
+  writeln!(out, "  let t: usize = d.iter().sum();").unwrap();
+  writeln!(out, "  let mut prob = [[0_f64; 24]; 10];").unwrap();
+  for (r, k, j) in factors {
+    writeln!(
+      out,
+      "let p_{}_{}_{} = if t > {} {{(d[{}] as isize - {}) as f64 / (t - {}) as f64}} else {{0_f64}};",
+      r + 1,
+      k,
+      j,
+      j,
+      r,
+      k,
+      j
+    )
+    .unwrap();
+  }
+  // The products live in one array rather than a binding each, as thousands
+  // of nested let scopes overflow the stack when rustc emits debug info
+  writeln!(out, "  let mut p = [0_f64; {}];", ids.len()).unwrap();
+  out.push_str(&body);
+  writeln!(out, "  finish(d, &prob)").unwrap();
+  writeln!(out, "}}").unwrap();
+  writeln!(out).unwrap();
+}
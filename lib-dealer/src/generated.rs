@@ -0,0 +1,35 @@
+use crate::DealerProb;
+
+include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+// Turns the per up card sums of the generated functions, indexed by final
+// total followed by blackjack and a bust on 22, into probabilities
+fn finish(d: &[usize; 10], prob: &[[f64; 24]; 10]) -> [DealerProb; 10] {
+  let mut ret = [DealerProb::default(); 10];
+  for (i, p) in prob.iter().enumerate() {
+    if d[i] == 0 {
+      ret[i].p_bust = 1.0;
+      continue;
+    }
+    let n = d[i] as f64;
+    let mut p_low = [0_f64; 17];
+    for (l, x) in p_low.iter_mut().zip(p.iter()) {
+      *l = x / n;
+    }
+    let mut dp = DealerProb {
+      p_17: p[17] / n,
+      p_18: p[18] / n,
+      p_19: p[19] / n,
+      p_20: p[20] / n,
+      p_21: p[21] / n,
+      p_bust: 1.0,
+      p_22: p[23] / n,
+      p_bj: p[22] / n,
+      p_low,
+    };
+    dp.p_bust = dp.p_bust - dp.p_17 - dp.p_18 - dp.p_19 - dp.p_20 - dp.p_21 - dp.p_bj;
+    dp.p_bust -= dp.p_low.iter().sum::<f64>();
+    ret[i] = dp;
+  }
+  ret
+}
@@ -1,4 +1,16 @@
-#[derive(Copy, Clone, Debug,Default)]
+// Unrolled variants written by build.rs, with the standard s17 dealer as the
+// default calculate_dealer_prob
+#[allow(clippy::all)]
+pub mod generated;
+mod recursive;
+
+pub use generated::{calculate_dealer_prob_h17, calculate_dealer_prob_s17 as calculate_dealer_prob};
+pub use recursive::{
+  calculate_dealer_prob_with, calculate_dealer_prob_with_draws, calculate_dealer_prob_with_hole,
+  calculate_dealer_prob_with_hole_in, HitSoft17, StandOn, StandSoft17, StoppingRule,
+};
+
+#[derive(Copy, Clone, Debug, Default)]
 pub struct DealerProb {
   pub p_17: f64,
   pub p_18: f64,
@@ -6,6912 +18,35 @@ pub struct DealerProb {
   pub p_20: f64,
   pub p_21: f64,
   pub p_bust: f64,
-  pub p_bj: f64
+  // The part of p_bust where the dealer finished on exactly 22
+  pub p_22: f64,
+  pub p_bj: f64,
+  // Dealer stood on a total below 17, indexed by that total. Always zero for
+  // the casino rules.
+  pub p_low: [f64; 17],
 }
 
-//https://possiblywrong.wordpress.com/2012/09/29/blackjack-ca-optimization-as-a-steiner-tree-problem/
-
-pub fn calculate_dealer_prob(d: &[usize; 10]) -> [DealerProb;10] {
-  let t: usize = d.iter().sum();
-  let mut prob = [[0_f64;5];10];
-let p_1_0_1 = if t > 1 {(d[0] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_1_0_2 = if t > 2 {(d[0] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_1_0_3 = if t > 3 {(d[0] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_1_0_4 = if t > 4 {(d[0] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_1_0_5 = if t > 5 {(d[0] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_1_0_6 = if t > 6 {(d[0] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_1_0_7 = if t > 7 {(d[0] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_0_8 = if t > 8 {(d[0] as isize - 0) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_1_1 = if t > 1 {(d[0] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_1_1_2 = if t > 2 {(d[0] as isize - 1) as f64 / (t - 2) as f64} else {0_f64};
-let p_1_1_3 = if t > 3 {(d[0] as isize - 1) as f64 / (t - 3) as f64} else {0_f64};
-let p_1_1_4 = if t > 4 {(d[0] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_1_1_5 = if t > 5 {(d[0] as isize - 1) as f64 / (t - 5) as f64} else {0_f64};
-let p_1_1_6 = if t > 6 {(d[0] as isize - 1) as f64 / (t - 6) as f64} else {0_f64};
-let p_1_1_7 = if t > 7 {(d[0] as isize - 1) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_1_8 = if t > 8 {(d[0] as isize - 1) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_1_9 = if t > 9 {(d[0] as isize - 1) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_2_2 = if t > 2 {(d[0] as isize - 2) as f64 / (t - 2) as f64} else {0_f64};
-let p_1_2_3 = if t > 3 {(d[0] as isize - 2) as f64 / (t - 3) as f64} else {0_f64};
-let p_1_2_4 = if t > 4 {(d[0] as isize - 2) as f64 / (t - 4) as f64} else {0_f64};
-let p_1_2_5 = if t > 5 {(d[0] as isize - 2) as f64 / (t - 5) as f64} else {0_f64};
-let p_1_2_6 = if t > 6 {(d[0] as isize - 2) as f64 / (t - 6) as f64} else {0_f64};
-let p_1_2_7 = if t > 7 {(d[0] as isize - 2) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_2_8 = if t > 8 {(d[0] as isize - 2) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_2_9 = if t > 9 {(d[0] as isize - 2) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_3_3 = if t > 3 {(d[0] as isize - 3) as f64 / (t - 3) as f64} else {0_f64};
-let p_1_3_4 = if t > 4 {(d[0] as isize - 3) as f64 / (t - 4) as f64} else {0_f64};
-let p_1_3_5 = if t > 5 {(d[0] as isize - 3) as f64 / (t - 5) as f64} else {0_f64};
-let p_1_3_6 = if t > 6 {(d[0] as isize - 3) as f64 / (t - 6) as f64} else {0_f64};
-let p_1_3_7 = if t > 7 {(d[0] as isize - 3) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_3_8 = if t > 8 {(d[0] as isize - 3) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_3_9 = if t > 9 {(d[0] as isize - 3) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_3_10 = if t > 10 {(d[0] as isize - 3) as f64 / (t - 10) as f64} else {0_f64};
-let p_1_4_4 = if t > 4 {(d[0] as isize - 4) as f64 / (t - 4) as f64} else {0_f64};
-let p_1_4_5 = if t > 5 {(d[0] as isize - 4) as f64 / (t - 5) as f64} else {0_f64};
-let p_1_4_6 = if t > 6 {(d[0] as isize - 4) as f64 / (t - 6) as f64} else {0_f64};
-let p_1_4_7 = if t > 7 {(d[0] as isize - 4) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_4_8 = if t > 8 {(d[0] as isize - 4) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_4_9 = if t > 9 {(d[0] as isize - 4) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_4_10 = if t > 10 {(d[0] as isize - 4) as f64 / (t - 10) as f64} else {0_f64};
-let p_1_5_5 = if t > 5 {(d[0] as isize - 5) as f64 / (t - 5) as f64} else {0_f64};
-let p_1_5_6 = if t > 6 {(d[0] as isize - 5) as f64 / (t - 6) as f64} else {0_f64};
-let p_1_5_7 = if t > 7 {(d[0] as isize - 5) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_5_8 = if t > 8 {(d[0] as isize - 5) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_5_9 = if t > 9 {(d[0] as isize - 5) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_5_10 = if t > 10 {(d[0] as isize - 5) as f64 / (t - 10) as f64} else {0_f64};
-let p_1_6_6 = if t > 6 {(d[0] as isize - 6) as f64 / (t - 6) as f64} else {0_f64};
-let p_1_6_7 = if t > 7 {(d[0] as isize - 6) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_6_8 = if t > 8 {(d[0] as isize - 6) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_6_9 = if t > 9 {(d[0] as isize - 6) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_7_7 = if t > 7 {(d[0] as isize - 7) as f64 / (t - 7) as f64} else {0_f64};
-let p_1_7_8 = if t > 8 {(d[0] as isize - 7) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_7_9 = if t > 9 {(d[0] as isize - 7) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_7_10 = if t > 10 {(d[0] as isize - 7) as f64 / (t - 10) as f64} else {0_f64};
-let p_1_8_8 = if t > 8 {(d[0] as isize - 8) as f64 / (t - 8) as f64} else {0_f64};
-let p_1_8_10 = if t > 10 {(d[0] as isize - 8) as f64 / (t - 10) as f64} else {0_f64};
-let p_1_9_9 = if t > 9 {(d[0] as isize - 9) as f64 / (t - 9) as f64} else {0_f64};
-let p_1_9_11 = if t > 11 {(d[0] as isize - 9) as f64 / (t - 11) as f64} else {0_f64};
-let p_1_10_11 = if t > 11 {(d[0] as isize - 10) as f64 / (t - 11) as f64} else {0_f64};
-let p_2_0_1 = if t > 1 {(d[1] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_2_0_2 = if t > 2 {(d[1] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_2_0_3 = if t > 3 {(d[1] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_2_0_4 = if t > 4 {(d[1] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_2_0_5 = if t > 5 {(d[1] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_2_0_6 = if t > 6 {(d[1] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_2_0_7 = if t > 7 {(d[1] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_2_0_9 = if t > 9 {(d[1] as isize - 0) as f64 / (t - 9) as f64} else {0_f64};
-let p_2_1_1 = if t > 1 {(d[1] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_2_1_2 = if t > 2 {(d[1] as isize - 1) as f64 / (t - 2) as f64} else {0_f64};
-let p_2_1_3 = if t > 3 {(d[1] as isize - 1) as f64 / (t - 3) as f64} else {0_f64};
-let p_2_1_4 = if t > 4 {(d[1] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_2_1_5 = if t > 5 {(d[1] as isize - 1) as f64 / (t - 5) as f64} else {0_f64};
-let p_2_1_6 = if t > 6 {(d[1] as isize - 1) as f64 / (t - 6) as f64} else {0_f64};
-let p_2_1_7 = if t > 7 {(d[1] as isize - 1) as f64 / (t - 7) as f64} else {0_f64};
-let p_2_1_8 = if t > 8 {(d[1] as isize - 1) as f64 / (t - 8) as f64} else {0_f64};
-let p_2_2_2 = if t > 2 {(d[1] as isize - 2) as f64 / (t - 2) as f64} else {0_f64};
-let p_2_2_3 = if t > 3 {(d[1] as isize - 2) as f64 / (t - 3) as f64} else {0_f64};
-let p_2_2_4 = if t > 4 {(d[1] as isize - 2) as f64 / (t - 4) as f64} else {0_f64};
-let p_2_2_5 = if t > 5 {(d[1] as isize - 2) as f64 / (t - 5) as f64} else {0_f64};
-let p_2_2_6 = if t > 6 {(d[1] as isize - 2) as f64 / (t - 6) as f64} else {0_f64};
-let p_2_2_7 = if t > 7 {(d[1] as isize - 2) as f64 / (t - 7) as f64} else {0_f64};
-let p_2_3_3 = if t > 3 {(d[1] as isize - 3) as f64 / (t - 3) as f64} else {0_f64};
-let p_2_3_4 = if t > 4 {(d[1] as isize - 3) as f64 / (t - 4) as f64} else {0_f64};
-let p_2_3_5 = if t > 5 {(d[1] as isize - 3) as f64 / (t - 5) as f64} else {0_f64};
-let p_2_3_6 = if t > 6 {(d[1] as isize - 3) as f64 / (t - 6) as f64} else {0_f64};
-let p_2_4_4 = if t > 4 {(d[1] as isize - 4) as f64 / (t - 4) as f64} else {0_f64};
-let p_2_4_5 = if t > 5 {(d[1] as isize - 4) as f64 / (t - 5) as f64} else {0_f64};
-let p_2_4_6 = if t > 6 {(d[1] as isize - 4) as f64 / (t - 6) as f64} else {0_f64};
-let p_2_4_7 = if t > 7 {(d[1] as isize - 4) as f64 / (t - 7) as f64} else {0_f64};
-let p_2_5_5 = if t > 5 {(d[1] as isize - 5) as f64 / (t - 5) as f64} else {0_f64};
-let p_2_5_6 = if t > 6 {(d[1] as isize - 5) as f64 / (t - 6) as f64} else {0_f64};
-let p_2_5_7 = if t > 7 {(d[1] as isize - 5) as f64 / (t - 7) as f64} else {0_f64};
-let p_2_6_6 = if t > 6 {(d[1] as isize - 6) as f64 / (t - 6) as f64} else {0_f64};
-let p_2_6_7 = if t > 7 {(d[1] as isize - 6) as f64 / (t - 7) as f64} else {0_f64};
-let p_2_7_7 = if t > 7 {(d[1] as isize - 7) as f64 / (t - 7) as f64} else {0_f64};
-let p_2_7_8 = if t > 8 {(d[1] as isize - 7) as f64 / (t - 8) as f64} else {0_f64};
-let p_2_8_8 = if t > 8 {(d[1] as isize - 8) as f64 / (t - 8) as f64} else {0_f64};
-let p_3_0_1 = if t > 1 {(d[2] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_3_0_2 = if t > 2 {(d[2] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_3_0_3 = if t > 3 {(d[2] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_3_0_4 = if t > 4 {(d[2] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_3_0_5 = if t > 5 {(d[2] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_3_0_6 = if t > 6 {(d[2] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_3_0_7 = if t > 7 {(d[2] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_3_0_8 = if t > 8 {(d[2] as isize - 0) as f64 / (t - 8) as f64} else {0_f64};
-let p_3_1_1 = if t > 1 {(d[2] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_3_1_2 = if t > 2 {(d[2] as isize - 1) as f64 / (t - 2) as f64} else {0_f64};
-let p_3_1_3 = if t > 3 {(d[2] as isize - 1) as f64 / (t - 3) as f64} else {0_f64};
-let p_3_1_4 = if t > 4 {(d[2] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_3_1_5 = if t > 5 {(d[2] as isize - 1) as f64 / (t - 5) as f64} else {0_f64};
-let p_3_1_6 = if t > 6 {(d[2] as isize - 1) as f64 / (t - 6) as f64} else {0_f64};
-let p_3_1_7 = if t > 7 {(d[2] as isize - 1) as f64 / (t - 7) as f64} else {0_f64};
-let p_3_2_2 = if t > 2 {(d[2] as isize - 2) as f64 / (t - 2) as f64} else {0_f64};
-let p_3_2_3 = if t > 3 {(d[2] as isize - 2) as f64 / (t - 3) as f64} else {0_f64};
-let p_3_2_4 = if t > 4 {(d[2] as isize - 2) as f64 / (t - 4) as f64} else {0_f64};
-let p_3_2_5 = if t > 5 {(d[2] as isize - 2) as f64 / (t - 5) as f64} else {0_f64};
-let p_3_2_6 = if t > 6 {(d[2] as isize - 2) as f64 / (t - 6) as f64} else {0_f64};
-let p_3_3_3 = if t > 3 {(d[2] as isize - 3) as f64 / (t - 3) as f64} else {0_f64};
-let p_3_3_4 = if t > 4 {(d[2] as isize - 3) as f64 / (t - 4) as f64} else {0_f64};
-let p_3_3_5 = if t > 5 {(d[2] as isize - 3) as f64 / (t - 5) as f64} else {0_f64};
-let p_3_4_4 = if t > 4 {(d[2] as isize - 4) as f64 / (t - 4) as f64} else {0_f64};
-let p_3_4_5 = if t > 5 {(d[2] as isize - 4) as f64 / (t - 5) as f64} else {0_f64};
-let p_3_5_5 = if t > 5 {(d[2] as isize - 5) as f64 / (t - 5) as f64} else {0_f64};
-let p_4_0_1 = if t > 1 {(d[3] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_4_0_2 = if t > 2 {(d[3] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_4_0_3 = if t > 3 {(d[3] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_4_0_4 = if t > 4 {(d[3] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_4_0_5 = if t > 5 {(d[3] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_4_0_6 = if t > 6 {(d[3] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_4_0_7 = if t > 7 {(d[3] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_4_1_1 = if t > 1 {(d[3] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_4_1_2 = if t > 2 {(d[3] as isize - 1) as f64 / (t - 2) as f64} else {0_f64};
-let p_4_1_3 = if t > 3 {(d[3] as isize - 1) as f64 / (t - 3) as f64} else {0_f64};
-let p_4_1_4 = if t > 4 {(d[3] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_4_1_5 = if t > 5 {(d[3] as isize - 1) as f64 / (t - 5) as f64} else {0_f64};
-let p_4_1_6 = if t > 6 {(d[3] as isize - 1) as f64 / (t - 6) as f64} else {0_f64};
-let p_4_2_2 = if t > 2 {(d[3] as isize - 2) as f64 / (t - 2) as f64} else {0_f64};
-let p_4_2_3 = if t > 3 {(d[3] as isize - 2) as f64 / (t - 3) as f64} else {0_f64};
-let p_4_3_3 = if t > 3 {(d[3] as isize - 3) as f64 / (t - 3) as f64} else {0_f64};
-let p_4_3_4 = if t > 4 {(d[3] as isize - 3) as f64 / (t - 4) as f64} else {0_f64};
-let p_4_4_4 = if t > 4 {(d[3] as isize - 4) as f64 / (t - 4) as f64} else {0_f64};
-let p_5_0_1 = if t > 1 {(d[4] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_5_0_2 = if t > 2 {(d[4] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_5_0_3 = if t > 3 {(d[4] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_5_0_4 = if t > 4 {(d[4] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_5_0_5 = if t > 5 {(d[4] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_5_0_6 = if t > 6 {(d[4] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_5_0_7 = if t > 7 {(d[4] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_5_1_1 = if t > 1 {(d[4] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_5_1_2 = if t > 2 {(d[4] as isize - 1) as f64 / (t - 2) as f64} else {0_f64};
-let p_5_1_3 = if t > 3 {(d[4] as isize - 1) as f64 / (t - 3) as f64} else {0_f64};
-let p_5_1_4 = if t > 4 {(d[4] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_5_1_5 = if t > 5 {(d[4] as isize - 1) as f64 / (t - 5) as f64} else {0_f64};
-let p_5_1_7 = if t > 7 {(d[4] as isize - 1) as f64 / (t - 7) as f64} else {0_f64};
-let p_5_2_2 = if t > 2 {(d[4] as isize - 2) as f64 / (t - 2) as f64} else {0_f64};
-let p_5_2_4 = if t > 4 {(d[4] as isize - 2) as f64 / (t - 4) as f64} else {0_f64};
-let p_5_3_3 = if t > 3 {(d[4] as isize - 3) as f64 / (t - 3) as f64} else {0_f64};
-let p_6_0_1 = if t > 1 {(d[5] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_6_0_2 = if t > 2 {(d[5] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_6_0_3 = if t > 3 {(d[5] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_6_0_4 = if t > 4 {(d[5] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_6_0_5 = if t > 5 {(d[5] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_6_0_6 = if t > 6 {(d[5] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_6_0_7 = if t > 7 {(d[5] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_6_0_8 = if t > 8 {(d[5] as isize - 0) as f64 / (t - 8) as f64} else {0_f64};
-let p_6_0_10 = if t > 10 {(d[5] as isize - 0) as f64 / (t - 10) as f64} else {0_f64};
-let p_6_1_1 = if t > 1 {(d[5] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_6_1_2 = if t > 2 {(d[5] as isize - 1) as f64 / (t - 2) as f64} else {0_f64};
-let p_6_1_3 = if t > 3 {(d[5] as isize - 1) as f64 / (t - 3) as f64} else {0_f64};
-let p_6_1_4 = if t > 4 {(d[5] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_6_1_5 = if t > 5 {(d[5] as isize - 1) as f64 / (t - 5) as f64} else {0_f64};
-let p_6_1_6 = if t > 6 {(d[5] as isize - 1) as f64 / (t - 6) as f64} else {0_f64};
-let p_6_2_2 = if t > 2 {(d[5] as isize - 2) as f64 / (t - 2) as f64} else {0_f64};
-let p_7_0_1 = if t > 1 {(d[6] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_7_0_2 = if t > 2 {(d[6] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_7_0_3 = if t > 3 {(d[6] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_7_0_4 = if t > 4 {(d[6] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_7_0_5 = if t > 5 {(d[6] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_7_0_6 = if t > 6 {(d[6] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_7_0_7 = if t > 7 {(d[6] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_7_0_8 = if t > 8 {(d[6] as isize - 0) as f64 / (t - 8) as f64} else {0_f64};
-let p_7_0_9 = if t > 9 {(d[6] as isize - 0) as f64 / (t - 9) as f64} else {0_f64};
-let p_7_0_10 = if t > 10 {(d[6] as isize - 0) as f64 / (t - 10) as f64} else {0_f64};
-let p_7_1_1 = if t > 1 {(d[6] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_7_1_3 = if t > 3 {(d[6] as isize - 1) as f64 / (t - 3) as f64} else {0_f64};
-let p_7_1_4 = if t > 4 {(d[6] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_7_1_6 = if t > 6 {(d[6] as isize - 1) as f64 / (t - 6) as f64} else {0_f64};
-let p_7_2_2 = if t > 2 {(d[6] as isize - 2) as f64 / (t - 2) as f64} else {0_f64};
-let p_8_0_1 = if t > 1 {(d[7] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_8_0_2 = if t > 2 {(d[7] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_8_0_3 = if t > 3 {(d[7] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_8_0_4 = if t > 4 {(d[7] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_8_0_5 = if t > 5 {(d[7] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_8_0_6 = if t > 6 {(d[7] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_8_0_7 = if t > 7 {(d[7] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_8_0_8 = if t > 8 {(d[7] as isize - 0) as f64 / (t - 8) as f64} else {0_f64};
-let p_8_0_9 = if t > 9 {(d[7] as isize - 0) as f64 / (t - 9) as f64} else {0_f64};
-let p_8_1_1 = if t > 1 {(d[7] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_8_1_4 = if t > 4 {(d[7] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_8_1_5 = if t > 5 {(d[7] as isize - 1) as f64 / (t - 5) as f64} else {0_f64};
-let p_9_0_1 = if t > 1 {(d[8] as isize - 0) as f64 / (t - 1) as f64} else {0_f64};
-let p_9_0_2 = if t > 2 {(d[8] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_9_0_3 = if t > 3 {(d[8] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_9_0_4 = if t > 4 {(d[8] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_9_0_5 = if t > 5 {(d[8] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_9_0_6 = if t > 6 {(d[8] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_9_0_7 = if t > 7 {(d[8] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_9_0_8 = if t > 8 {(d[8] as isize - 0) as f64 / (t - 8) as f64} else {0_f64};
-let p_9_1_1 = if t > 1 {(d[8] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_9_1_4 = if t > 4 {(d[8] as isize - 1) as f64 / (t - 4) as f64} else {0_f64};
-let p_10_0_2 = if t > 2 {(d[9] as isize - 0) as f64 / (t - 2) as f64} else {0_f64};
-let p_10_0_3 = if t > 3 {(d[9] as isize - 0) as f64 / (t - 3) as f64} else {0_f64};
-let p_10_0_4 = if t > 4 {(d[9] as isize - 0) as f64 / (t - 4) as f64} else {0_f64};
-let p_10_0_5 = if t > 5 {(d[9] as isize - 0) as f64 / (t - 5) as f64} else {0_f64};
-let p_10_0_6 = if t > 6 {(d[9] as isize - 0) as f64 / (t - 6) as f64} else {0_f64};
-let p_10_0_7 = if t > 7 {(d[9] as isize - 0) as f64 / (t - 7) as f64} else {0_f64};
-let p_10_1_1 = if t > 1 {(d[9] as isize - 1) as f64 / (t - 1) as f64} else {0_f64};
-let p_11 = d[0] as f64 * p_1_1_1;
-let p_12 = d[1] as f64 * p_1_0_1;
-let p_13 = d[1] as f64 * p_2_1_1;
-let p_14 = d[2] as f64 * p_1_0_1;
-let p_15 = d[2] as f64 * p_2_0_1;
-let p_16 = d[2] as f64 * p_3_1_1;
-let p_17 = d[3] as f64 * p_1_0_1;
-let p_18 = d[3] as f64 * p_4_1_1;
-let p_19 = d[4] as f64 * p_1_0_1;
-let p_20 = d[4] as f64 * p_3_0_1;
-let p_21 = d[4] as f64 * p_4_0_1;
-let p_22 = d[4] as f64 * p_5_1_1;
-let p_23 = d[5] as f64 * p_1_0_1;
-    prob[0][0] += p_23;
-    prob[5][0] += p_23;
-let p_24 = d[5] as f64 * p_2_0_1;
-let p_25 = d[5] as f64 * p_3_0_1;
-let p_26 = d[5] as f64 * p_4_0_1;
-let p_27 = d[5] as f64 * p_5_0_1;
-let p_28 = d[5] as f64 * p_6_1_1;
-let p_29 = d[6] as f64 * p_1_0_1;
-    prob[0][1] += p_29;
-    prob[6][1] += p_29;
-let p_30 = d[6] as f64 * p_2_0_1;
-let p_31 = d[6] as f64 * p_3_0_1;
-let p_32 = d[6] as f64 * p_4_0_1;
-let p_33 = d[6] as f64 * p_5_0_1;
-let p_34 = d[6] as f64 * p_6_0_1;
-let p_35 = d[6] as f64 * p_7_1_1;
-let p_36 = d[7] as f64 * p_1_0_1;
-    prob[0][2] += p_36;
-    prob[7][2] += p_36;
-let p_37 = d[7] as f64 * p_2_0_1;
-let p_38 = d[7] as f64 * p_3_0_1;
-let p_39 = d[7] as f64 * p_4_0_1;
-let p_40 = d[7] as f64 * p_8_1_1;
-let p_41 = d[8] as f64 * p_1_0_1;
-    prob[0][3] += p_41;
-    prob[8][3] += p_41;
-let p_42 = d[8] as f64 * p_2_0_1;
-let p_43 = d[8] as f64 * p_8_0_1;
-    prob[7][0] += p_43;
-    prob[8][0] += p_43;
-let p_44 = d[8] as f64 * p_9_1_1;
-    prob[8][1] += p_44;
-let p_45 = d[9] as f64 * p_2_0_1;
-let p_46 = d[9] as f64 * p_3_0_1;
-let p_47 = d[9] as f64 * p_7_0_1;
-    prob[6][0] += p_47;
-    prob[9][0] += p_47;
-let p_48 = d[9] as f64 * p_8_0_1;
-    prob[7][1] += p_48;
-    prob[9][1] += p_48;
-let p_49 = d[9] as f64 * p_9_0_1;
-    prob[8][2] += p_49;
-    prob[9][2] += p_49;
-let p_50 = d[9] as f64 * p_10_1_1;
-    prob[9][3] += p_50;
-let p_51 = p_11 * p_1_2_2;
-let p_52 = p_12 * p_1_1_2;
-let p_53 = p_13 * p_1_0_2;
-let p_54 = p_13 * p_2_2_2;
-let p_55 = p_14 * p_1_1_2;
-let p_56 = p_15 * p_1_0_2;
-let p_57 = p_16 * p_1_0_2;
-    prob[0][0] += p_57;
-    prob[2][0] += 2_f64 * p_57;
-let p_58 = p_16 * p_2_0_2;
-let p_59 = p_16 * p_3_2_2;
-let p_60 = p_17 * p_1_1_2;
-let p_61 = p_17 * p_2_0_2;
-    prob[0][0] += 2_f64 * p_61;
-    prob[1][0] += 2_f64 * p_61;
-    prob[3][0] += 2_f64 * p_61;
-let p_62 = p_13 * p_4_0_2;
-let p_63 = p_17 * p_3_0_2;
-    prob[0][1] += 2_f64 * p_63;
-    prob[2][1] += 2_f64 * p_63;
-    prob[3][1] += 2_f64 * p_63;
-let p_64 = p_15 * p_4_0_2;
-let p_65 = p_16 * p_4_0_2;
-let p_66 = p_18 * p_1_0_2;
-    prob[0][2] += p_66;
-    prob[3][2] += 2_f64 * p_66;
-let p_67 = p_18 * p_2_0_2;
-let p_68 = p_18 * p_3_0_2;
-let p_69 = p_18 * p_4_2_2;
-let p_70 = p_19 * p_1_1_2;
-    prob[0][0] += 2_f64 * p_70;
-    prob[4][0] += p_70;
-let p_71 = p_19 * p_2_0_2;
-    prob[0][1] += 2_f64 * p_71;
-    prob[1][1] += 2_f64 * p_71;
-    prob[4][1] += 2_f64 * p_71;
-let p_72 = p_13 * p_5_0_2;
-let p_73 = p_20 * p_1_0_2;
-    prob[0][2] += 2_f64 * p_73;
-    prob[2][2] += 2_f64 * p_73;
-    prob[4][2] += 2_f64 * p_73;
-let p_74 = p_20 * p_2_0_2;
-let p_75 = p_20 * p_3_1_2;
-let p_76 = p_21 * p_1_0_2;
-    prob[0][3] += 2_f64 * p_76;
-    prob[3][3] += 2_f64 * p_76;
-    prob[4][3] += 2_f64 * p_76;
-let p_77 = p_21 * p_2_0_2;
-let p_78 = p_21 * p_3_0_2;
-let p_79 = p_21 * p_4_1_2;
-let p_80 = p_22 * p_1_0_2;
-    prob[0][4] += p_80;
-    prob[4][4] += 2_f64 * p_80;
-let p_81 = p_22 * p_2_0_2;
-let p_82 = p_22 * p_3_0_2;
-let p_83 = p_22 * p_4_0_2;
-let p_84 = p_22 * p_5_2_2;
-let p_85 = p_23 * p_1_1_2;
-    prob[0][1] += p_85;
-let p_86 = p_24 * p_1_0_2;
-    prob[0][2] += p_86;
-    prob[1][2] += 2_f64 * p_86;
-    prob[5][2] += p_86;
-let p_87 = p_24 * p_2_1_2;
-let p_88 = p_25 * p_1_0_2;
-    prob[0][3] += p_88;
-    prob[2][3] += 2_f64 * p_88;
-    prob[5][3] += p_88;
-let p_89 = p_25 * p_2_0_2;
-let p_90 = p_25 * p_3_1_2;
-let p_91 = p_26 * p_1_0_2;
-    prob[0][4] += p_91;
-    prob[3][4] += 2_f64 * p_91;
-    prob[5][4] += p_91;
-let p_92 = p_26 * p_2_0_2;
-let p_93 = p_26 * p_3_0_2;
-let p_94 = p_26 * p_4_1_2;
-let p_95 = p_27 * p_1_0_2;
-let p_96 = p_27 * p_2_0_2;
-let p_97 = p_27 * p_3_0_2;
-let p_98 = p_28 * p_1_0_2;
-let p_99 = p_28 * p_2_0_2;
-let p_100 = p_28 * p_5_0_2;
-    prob[4][0] += p_100;
-    prob[5][0] += 2_f64 * p_100;
-let p_101 = p_28 * p_6_2_2;
-    prob[5][1] += p_101;
-let p_102 = p_29 * p_1_1_2;
-    prob[0][2] += p_102;
-let p_103 = p_30 * p_1_0_2;
-    prob[0][3] += p_103;
-    prob[1][3] += 2_f64 * p_103;
-    prob[6][3] += p_103;
-let p_104 = p_30 * p_2_1_2;
-let p_105 = p_31 * p_1_0_2;
-    prob[0][4] += p_105;
-    prob[2][4] += 2_f64 * p_105;
-    prob[6][4] += p_105;
-let p_106 = p_31 * p_2_0_2;
-let p_107 = p_31 * p_3_1_2;
-let p_108 = p_32 * p_1_0_2;
-let p_109 = p_32 * p_2_0_2;
-let p_110 = p_33 * p_1_0_2;
-let p_111 = p_33 * p_5_1_2;
-    prob[4][0] += 2_f64 * p_111;
-    prob[6][0] += p_111;
-let p_112 = p_34 * p_4_0_2;
-    prob[3][0] += 2_f64 * p_112;
-    prob[5][0] += 2_f64 * p_112;
-    prob[6][0] += 2_f64 * p_112;
-let p_113 = p_34 * p_5_0_2;
-    prob[4][1] += 2_f64 * p_113;
-    prob[5][1] += 2_f64 * p_113;
-    prob[6][1] += 2_f64 * p_113;
-let p_114 = p_34 * p_6_1_2;
-    prob[5][2] += 2_f64 * p_114;
-    prob[6][2] += p_114;
-let p_115 = p_35 * p_3_0_2;
-    prob[2][0] += p_115;
-    prob[6][0] += 2_f64 * p_115;
-let p_116 = p_35 * p_4_0_2;
-    prob[3][1] += p_116;
-    prob[6][1] += 2_f64 * p_116;
-let p_117 = p_35 * p_5_0_2;
-    prob[4][2] += p_117;
-    prob[6][2] += 2_f64 * p_117;
-let p_118 = p_35 * p_6_0_2;
-    prob[5][3] += p_118;
-    prob[6][3] += 2_f64 * p_118;
-let p_119 = p_35 * p_7_2_2;
-    prob[6][4] += p_119;
-let p_120 = p_36 * p_1_1_2;
-    prob[0][3] += p_120;
-let p_121 = p_37 * p_1_0_2;
-    prob[0][4] += p_121;
-    prob[1][4] += 2_f64 * p_121;
-    prob[7][4] += p_121;
-let p_122 = p_37 * p_2_1_2;
-let p_123 = p_38 * p_1_0_2;
-let p_124 = p_39 * p_5_0_2;
-    prob[3][0] += 2_f64 * p_124;
-    prob[4][0] += 2_f64 * p_124;
-    prob[7][0] += 2_f64 * p_124;
-let p_125 = p_22 * p_8_0_2;
-    prob[4][1] += 2_f64 * p_125;
-    prob[7][1] += p_125;
-let p_126 = p_38 * p_6_0_2;
-    prob[2][0] += 2_f64 * p_126;
-    prob[5][0] += 2_f64 * p_126;
-    prob[7][0] += 2_f64 * p_126;
-let p_127 = p_39 * p_6_0_2;
-    prob[3][1] += 2_f64 * p_127;
-    prob[5][1] += 2_f64 * p_127;
-    prob[7][1] += 2_f64 * p_127;
-let p_128 = p_27 * p_8_0_2;
-    prob[4][2] += 2_f64 * p_128;
-    prob[5][2] += 2_f64 * p_128;
-    prob[7][2] += 2_f64 * p_128;
-let p_129 = p_28 * p_8_0_2;
-    prob[5][3] += 2_f64 * p_129;
-    prob[7][3] += p_129;
-let p_130 = p_37 * p_7_0_2;
-    prob[1][0] += 2_f64 * p_130;
-    prob[6][0] += 2_f64 * p_130;
-    prob[7][0] += 2_f64 * p_130;
-let p_131 = p_38 * p_7_0_2;
-    prob[2][1] += 2_f64 * p_131;
-    prob[6][1] += 2_f64 * p_131;
-    prob[7][1] += 2_f64 * p_131;
-let p_132 = p_39 * p_7_0_2;
-    prob[3][2] += 2_f64 * p_132;
-    prob[6][2] += 2_f64 * p_132;
-    prob[7][2] += 2_f64 * p_132;
-let p_133 = p_33 * p_8_0_2;
-    prob[4][3] += 2_f64 * p_133;
-    prob[6][3] += 2_f64 * p_133;
-    prob[7][3] += 2_f64 * p_133;
-let p_134 = p_34 * p_8_0_2;
-    prob[5][4] += 2_f64 * p_134;
-    prob[6][4] += 2_f64 * p_134;
-    prob[7][4] += 2_f64 * p_134;
-let p_135 = p_40 * p_1_0_2;
-    prob[7][0] += p_135;
-let p_136 = p_40 * p_2_0_2;
-    prob[1][1] += p_136;
-    prob[7][1] += 2_f64 * p_136;
-let p_137 = p_40 * p_3_0_2;
-    prob[2][2] += p_137;
-    prob[7][2] += 2_f64 * p_137;
-let p_138 = p_40 * p_4_0_2;
-    prob[3][3] += p_138;
-    prob[7][3] += 2_f64 * p_138;
-let p_139 = p_40 * p_5_0_2;
-    prob[4][4] += p_139;
-    prob[7][4] += 2_f64 * p_139;
-let p_140 = p_41 * p_1_1_2;
-    prob[0][4] += p_140;
-let p_141 = p_18 * p_9_0_2;
-    prob[3][0] += 2_f64 * p_141;
-    prob[8][0] += p_141;
-let p_142 = p_20 * p_9_0_2;
-    prob[2][0] += 2_f64 * p_142;
-    prob[4][0] += 2_f64 * p_142;
-    prob[8][0] += 2_f64 * p_142;
-let p_143 = p_21 * p_9_0_2;
-    prob[3][1] += 2_f64 * p_143;
-    prob[4][1] += 2_f64 * p_143;
-    prob[8][1] += 2_f64 * p_143;
-let p_144 = p_22 * p_9_0_2;
-    prob[4][2] += 2_f64 * p_144;
-    prob[8][2] += p_144;
-let p_145 = p_42 * p_6_0_2;
-    prob[1][0] += 2_f64 * p_145;
-    prob[5][0] += 2_f64 * p_145;
-    prob[8][0] += 2_f64 * p_145;
-let p_146 = p_25 * p_9_0_2;
-    prob[2][1] += 2_f64 * p_146;
-    prob[5][1] += 2_f64 * p_146;
-    prob[8][1] += 2_f64 * p_146;
-let p_147 = p_26 * p_9_0_2;
-    prob[3][2] += 2_f64 * p_147;
-    prob[5][2] += 2_f64 * p_147;
-    prob[8][2] += 2_f64 * p_147;
-let p_148 = p_27 * p_9_0_2;
-    prob[4][3] += 2_f64 * p_148;
-    prob[5][3] += 2_f64 * p_148;
-    prob[8][3] += 2_f64 * p_148;
-let p_149 = p_28 * p_9_0_2;
-    prob[5][4] += 2_f64 * p_149;
-    prob[8][4] += p_149;
-let p_150 = p_41 * p_7_0_2;
-    prob[6][0] += p_150;
-    prob[8][0] += p_150;
-let p_151 = p_42 * p_7_0_2;
-    prob[1][1] += 2_f64 * p_151;
-    prob[6][1] += 2_f64 * p_151;
-    prob[8][1] += 2_f64 * p_151;
-let p_152 = p_31 * p_9_0_2;
-    prob[2][2] += 2_f64 * p_152;
-    prob[6][2] += 2_f64 * p_152;
-    prob[8][2] += 2_f64 * p_152;
-let p_153 = p_32 * p_9_0_2;
-    prob[3][3] += 2_f64 * p_153;
-    prob[6][3] += 2_f64 * p_153;
-    prob[8][3] += 2_f64 * p_153;
-let p_154 = p_33 * p_9_0_2;
-    prob[4][4] += 2_f64 * p_154;
-    prob[6][4] += 2_f64 * p_154;
-    prob[8][4] += 2_f64 * p_154;
-let p_155 = p_43 * p_2_0_2;
-    prob[1][2] += 2_f64 * p_155;
-    prob[7][2] += p_155;
-    prob[8][2] += p_155;
-let p_156 = p_43 * p_3_0_2;
-    prob[2][3] += 2_f64 * p_156;
-    prob[7][3] += p_156;
-    prob[8][3] += p_156;
-let p_157 = p_43 * p_4_0_2;
-    prob[3][4] += 2_f64 * p_157;
-    prob[7][4] += p_157;
-    prob[8][4] += p_157;
-let p_158 = p_44 * p_2_0_2;
-    prob[1][3] += p_158;
-    prob[8][3] += p_158;
-let p_159 = p_44 * p_3_0_2;
-    prob[2][4] += p_159;
-    prob[8][4] += p_159;
-let p_160 = p_45 * p_2_1_2;
-let p_161 = p_46 * p_4_0_2;
-    prob[2][0] += 2_f64 * p_161;
-    prob[3][0] += 2_f64 * p_161;
-    prob[9][0] += 2_f64 * p_161;
-let p_162 = p_18 * p_10_0_2;
-    prob[3][1] += 2_f64 * p_162;
-    prob[9][1] += p_162;
-let p_163 = p_45 * p_5_0_2;
-    prob[1][0] += 2_f64 * p_163;
-    prob[4][0] += 2_f64 * p_163;
-    prob[9][0] += 2_f64 * p_163;
-let p_164 = p_46 * p_5_0_2;
-    prob[2][1] += 2_f64 * p_164;
-    prob[4][1] += 2_f64 * p_164;
-    prob[9][1] += 2_f64 * p_164;
-let p_165 = p_21 * p_10_0_2;
-    prob[3][2] += 2_f64 * p_165;
-    prob[4][2] += 2_f64 * p_165;
-    prob[9][2] += 2_f64 * p_165;
-let p_166 = p_22 * p_10_0_2;
-    prob[4][3] += 2_f64 * p_166;
-    prob[9][3] += p_166;
-let p_167 = p_23 * p_10_0_2;
-    prob[5][0] += p_167;
-    prob[9][0] += p_167;
-let p_168 = p_45 * p_6_0_2;
-    prob[1][1] += 2_f64 * p_168;
-    prob[5][1] += 2_f64 * p_168;
-    prob[9][1] += 2_f64 * p_168;
-let p_169 = p_46 * p_6_0_2;
-    prob[2][2] += 2_f64 * p_169;
-    prob[5][2] += 2_f64 * p_169;
-    prob[9][2] += 2_f64 * p_169;
-let p_170 = p_26 * p_10_0_2;
-    prob[3][3] += 2_f64 * p_170;
-    prob[5][3] += 2_f64 * p_170;
-    prob[9][3] += 2_f64 * p_170;
-let p_171 = p_27 * p_10_0_2;
-    prob[4][4] += 2_f64 * p_171;
-    prob[5][4] += 2_f64 * p_171;
-    prob[9][4] += 2_f64 * p_171;
-let p_172 = p_47 * p_2_0_2;
-    prob[1][2] += 2_f64 * p_172;
-    prob[6][2] += p_172;
-    prob[9][2] += p_172;
-let p_173 = p_47 * p_3_0_2;
-    prob[2][3] += 2_f64 * p_173;
-    prob[6][3] += p_173;
-    prob[9][3] += p_173;
-let p_174 = p_47 * p_4_0_2;
-    prob[3][4] += 2_f64 * p_174;
-    prob[6][4] += p_174;
-    prob[9][4] += p_174;
-let p_175 = p_48 * p_2_0_2;
-    prob[1][3] += 2_f64 * p_175;
-    prob[7][3] += p_175;
-    prob[9][3] += p_175;
-let p_176 = p_48 * p_3_0_2;
-    prob[2][4] += 2_f64 * p_176;
-    prob[7][4] += p_176;
-    prob[9][4] += p_176;
-let p_177 = p_49 * p_2_0_2;
-    prob[1][4] += 2_f64 * p_177;
-    prob[8][4] += p_177;
-    prob[9][4] += p_177;
-let p_178 = p_51 * p_1_3_3;
-let p_179 = p_52 * p_1_2_3;
-let p_180 = p_53 * p_1_1_3;
-let p_181 = p_54 * p_1_0_3;
-    prob[0][0] += p_181;
-    prob[1][0] += 3_f64 * p_181;
-let p_182 = p_54 * p_2_3_3;
-let p_183 = p_55 * p_1_2_3;
-let p_184 = p_56 * p_1_1_3;
-    prob[0][0] += 6_f64 * p_184;
-    prob[1][0] += 3_f64 * p_184;
-    prob[2][0] += 3_f64 * p_184;
-let p_185 = p_56 * p_2_1_3;
-    prob[0][1] += 3_f64 * p_185;
-    prob[1][1] += 6_f64 * p_185;
-    prob[2][1] += 3_f64 * p_185;
-let p_186 = p_54 * p_3_0_3;
-let p_187 = p_57 * p_1_1_3;
-    prob[0][1] += 2_f64 * p_187;
-    prob[2][1] += p_187;
-let p_188 = p_58 * p_1_0_3;
-    prob[0][2] += 2_f64 * p_188;
-    prob[1][2] += 3_f64 * p_188;
-    prob[2][2] += 4_f64 * p_188;
-let p_189 = p_58 * p_2_1_3;
-let p_190 = p_59 * p_1_0_3;
-    prob[2][3] += p_190;
-let p_191 = p_59 * p_2_0_3;
-let p_192 = p_59 * p_3_3_3;
-let p_193 = p_60 * p_1_2_3;
-    prob[0][0] += 3_f64 * p_193;
-    prob[3][0] += p_193;
-let p_194 = p_61 * p_1_1_3;
-    prob[0][1] += 4_f64 * p_194;
-    prob[1][1] += p_194;
-    prob[3][1] += p_194;
-let p_195 = p_62 * p_1_0_3;
-    prob[0][2] += p_195;
-    prob[1][2] += 4_f64 * p_195;
-    prob[3][2] += p_195;
-let p_196 = p_62 * p_2_2_3;
-let p_197 = p_63 * p_1_1_3;
-    prob[0][2] += 4_f64 * p_197;
-    prob[2][2] += p_197;
-    prob[3][2] += p_197;
-let p_198 = p_64 * p_1_0_3;
-    prob[0][3] += 2_f64 * p_198;
-    prob[1][3] += 4_f64 * p_198;
-    prob[2][3] += 4_f64 * p_198;
-    prob[3][3] += 2_f64 * p_198;
-let p_199 = p_64 * p_2_1_3;
-let p_200 = p_65 * p_1_0_3;
-    prob[2][4] += 2_f64 * p_200;
-    prob[3][4] += p_200;
-let p_201 = p_65 * p_2_0_3;
-let p_202 = p_65 * p_3_2_3;
-let p_203 = p_66 * p_1_1_3;
-    prob[0][3] += 2_f64 * p_203;
-    prob[3][3] += p_203;
-let p_204 = p_67 * p_1_0_3;
-    prob[1][4] += p_204;
-    prob[3][4] += 2_f64 * p_204;
-let p_205 = p_67 * p_2_1_3;
-let p_206 = p_68 * p_1_0_3;
-let p_207 = p_68 * p_2_0_3;
-let p_208 = p_68 * p_3_1_3;
-let p_209 = p_69 * p_1_0_3;
-let p_210 = p_69 * p_2_0_3;
-let p_211 = p_69 * p_3_0_3;
-let p_212 = p_69 * p_4_3_3;
-let p_213 = p_70 * p_1_2_3;
-    prob[0][1] += p_213;
-let p_214 = p_71 * p_1_1_3;
-    prob[0][2] += 2_f64 * p_214;
-    prob[1][2] += p_214;
-let p_215 = p_72 * p_1_0_3;
-    prob[0][3] += p_215;
-    prob[1][3] += 4_f64 * p_215;
-    prob[4][3] += p_215;
-let p_216 = p_72 * p_2_2_3;
-let p_217 = p_73 * p_1_1_3;
-    prob[0][3] += 2_f64 * p_217;
-    prob[2][3] += p_217;
-let p_218 = p_74 * p_1_0_3;
-    prob[0][4] += 2_f64 * p_218;
-    prob[1][4] += 4_f64 * p_218;
-    prob[2][4] += 4_f64 * p_218;
-    prob[4][4] += 2_f64 * p_218;
-let p_219 = p_74 * p_2_1_3;
-let p_220 = p_75 * p_1_0_3;
-let p_221 = p_75 * p_2_0_3;
-let p_222 = p_75 * p_3_2_3;
-let p_223 = p_76 * p_1_1_3;
-    prob[0][4] += 2_f64 * p_223;
-    prob[3][4] += p_223;
-let p_224 = p_77 * p_1_0_3;
-let p_225 = p_77 * p_2_1_3;
-let p_226 = p_78 * p_1_0_3;
-let p_227 = p_78 * p_2_0_3;
-let p_228 = p_78 * p_3_1_3;
-let p_229 = p_79 * p_1_0_3;
-let p_230 = p_79 * p_2_0_3;
-let p_231 = p_79 * p_4_2_3;
-    prob[3][0] += 3_f64 * p_231;
-    prob[4][0] += p_231;
-let p_232 = p_80 * p_1_1_3;
-let p_233 = p_81 * p_1_0_3;
-let p_234 = p_81 * p_2_1_3;
-let p_235 = p_82 * p_1_0_3;
-let p_236 = p_82 * p_2_0_3;
-let p_237 = p_83 * p_1_0_3;
-let p_238 = p_83 * p_3_0_3;
-    prob[2][0] += 3_f64 * p_238;
-    prob[3][0] += 3_f64 * p_238;
-    prob[4][0] += 6_f64 * p_238;
-let p_239 = p_83 * p_4_1_3;
-    prob[3][1] += 3_f64 * p_239;
-    prob[4][1] += 3_f64 * p_239;
-let p_240 = p_84 * p_2_0_3;
-    prob[1][0] += p_240;
-    prob[4][0] += 3_f64 * p_240;
-let p_241 = p_84 * p_3_0_3;
-    prob[2][1] += p_241;
-    prob[4][1] += 3_f64 * p_241;
-let p_242 = p_84 * p_4_0_3;
-    prob[3][2] += p_242;
-    prob[4][2] += 3_f64 * p_242;
-let p_243 = p_84 * p_5_3_3;
-    prob[4][3] += p_243;
-let p_244 = p_85 * p_1_2_3;
-    prob[0][2] += p_244;
-let p_245 = p_86 * p_1_1_3;
-    prob[0][3] += 2_f64 * p_245;
-    prob[1][3] += p_245;
-let p_246 = p_87 * p_1_0_3;
-    prob[0][4] += p_246;
-    prob[1][4] += 4_f64 * p_246;
-    prob[5][4] += p_246;
-let p_247 = p_87 * p_2_2_3;
-let p_248 = p_88 * p_1_1_3;
-    prob[0][4] += 2_f64 * p_248;
-    prob[2][4] += p_248;
-let p_249 = p_89 * p_1_0_3;
-let p_250 = p_89 * p_2_1_3;
-let p_251 = p_90 * p_1_0_3;
-let p_252 = p_90 * p_2_0_3;
-let p_253 = p_91 * p_1_1_3;
-let p_254 = p_92 * p_1_0_3;
-let p_255 = p_92 * p_2_1_3;
-let p_256 = p_93 * p_1_0_3;
-let p_257 = p_94 * p_3_0_3;
-    prob[2][0] += 3_f64 * p_257;
-    prob[3][0] += 6_f64 * p_257;
-    prob[5][0] += 3_f64 * p_257;
-let p_258 = p_94 * p_4_2_3;
-    prob[3][1] += 3_f64 * p_258;
-    prob[5][1] += p_258;
-let p_259 = p_95 * p_1_1_3;
-let p_260 = p_96 * p_1_0_3;
-let p_261 = p_97 * p_3_1_3;
-    prob[2][0] += 6_f64 * p_261;
-    prob[4][0] += 3_f64 * p_261;
-    prob[5][0] += 3_f64 * p_261;
-let p_262 = p_96 * p_4_0_3;
-    prob[1][0] += 6_f64 * p_262;
-    prob[3][0] += 6_f64 * p_262;
-    prob[4][0] += 6_f64 * p_262;
-    prob[5][0] += 6_f64 * p_262;
-let p_263 = p_97 * p_4_0_3;
-    prob[2][1] += 6_f64 * p_263;
-    prob[3][1] += 6_f64 * p_263;
-    prob[4][1] += 6_f64 * p_263;
-    prob[5][1] += 6_f64 * p_263;
-let p_264 = p_94 * p_5_0_3;
-    prob[3][2] += 6_f64 * p_264;
-    prob[4][2] += 3_f64 * p_264;
-    prob[5][2] += 3_f64 * p_264;
-let p_265 = p_95 * p_5_1_3;
-    prob[0][0] += p_265;
-    prob[4][0] += 4_f64 * p_265;
-    prob[5][0] += 2_f64 * p_265;
-let p_266 = p_96 * p_5_1_3;
-    prob[1][1] += 3_f64 * p_266;
-    prob[4][1] += 6_f64 * p_266;
-    prob[5][1] += 3_f64 * p_266;
-let p_267 = p_97 * p_5_1_3;
-    prob[2][2] += 3_f64 * p_267;
-    prob[4][2] += 6_f64 * p_267;
-    prob[5][2] += 3_f64 * p_267;
-let p_268 = p_83 * p_6_0_3;
-    prob[3][3] += 3_f64 * p_268;
-    prob[4][3] += 6_f64 * p_268;
-    prob[5][3] += 3_f64 * p_268;
-let p_269 = p_84 * p_6_0_3;
-    prob[4][4] += 3_f64 * p_269;
-    prob[5][4] += p_269;
-let p_270 = p_98 * p_1_1_3;
-let p_271 = p_99 * p_3_0_3;
-    prob[1][0] += 3_f64 * p_271;
-    prob[2][0] += 3_f64 * p_271;
-    prob[5][0] += 6_f64 * p_271;
-let p_272 = p_90 * p_6_1_3;
-    prob[2][1] += 3_f64 * p_272;
-    prob[5][1] += 3_f64 * p_272;
-let p_273 = p_98 * p_4_0_3;
-    prob[3][0] += p_273;
-    prob[5][0] += 3_f64 * p_273;
-let p_274 = p_99 * p_4_0_3;
-    prob[1][1] += 3_f64 * p_274;
-    prob[3][1] += 3_f64 * p_274;
-    prob[5][1] += 6_f64 * p_274;
-let p_275 = p_93 * p_6_1_3;
-    prob[2][2] += 3_f64 * p_275;
-    prob[3][2] += 3_f64 * p_275;
-    prob[5][2] += 6_f64 * p_275;
-let p_276 = p_94 * p_6_1_3;
-    prob[3][3] += 3_f64 * p_276;
-    prob[5][3] += 3_f64 * p_276;
-let p_277 = p_100 * p_1_0_3;
-    prob[0][1] += p_277;
-    prob[4][1] += 2_f64 * p_277;
-    prob[5][1] += 2_f64 * p_277;
-let p_278 = p_100 * p_2_0_3;
-    prob[1][2] += 3_f64 * p_278;
-    prob[4][2] += 2_f64 * p_278;
-    prob[5][2] += 4_f64 * p_278;
-let p_279 = p_100 * p_3_0_3;
-    prob[2][3] += 3_f64 * p_279;
-    prob[4][3] += 2_f64 * p_279;
-    prob[5][3] += 4_f64 * p_279;
-let p_280 = p_100 * p_4_0_3;
-    prob[3][4] += 3_f64 * p_280;
-    prob[4][4] += 2_f64 * p_280;
-    prob[5][4] += 4_f64 * p_280;
-let p_281 = p_101 * p_1_0_3;
-    prob[5][2] += p_281;
-let p_282 = p_101 * p_2_0_3;
-    prob[1][3] += p_282;
-    prob[5][3] += 2_f64 * p_282;
-let p_283 = p_101 * p_3_0_3;
-    prob[2][4] += p_283;
-    prob[5][4] += 2_f64 * p_283;
-let p_284 = p_102 * p_1_2_3;
-    prob[0][3] += p_284;
-let p_285 = p_103 * p_1_1_3;
-    prob[0][4] += 2_f64 * p_285;
-    prob[1][4] += p_285;
-let p_286 = p_104 * p_1_0_3;
-let p_287 = p_104 * p_2_2_3;
-let p_288 = p_105 * p_1_1_3;
-let p_289 = p_106 * p_1_0_3;
-let p_290 = p_108 * p_1_1_3;
-let p_291 = p_107 * p_4_0_3;
-    prob[2][0] += 6_f64 * p_291;
-    prob[3][0] += 3_f64 * p_291;
-    prob[6][0] += 3_f64 * p_291;
-let p_292 = p_109 * p_4_1_3;
-    prob[1][0] += 3_f64 * p_292;
-    prob[3][0] += 6_f64 * p_292;
-    prob[6][0] += 3_f64 * p_292;
-let p_293 = p_68 * p_7_0_3;
-    prob[2][1] += 3_f64 * p_293;
-    prob[3][1] += 6_f64 * p_293;
-    prob[6][1] += 3_f64 * p_293;
-let p_294 = p_69 * p_7_0_3;
-    prob[3][2] += 3_f64 * p_294;
-    prob[6][2] += p_294;
-let p_295 = p_106 * p_5_0_3;
-    prob[1][0] += 6_f64 * p_295;
-    prob[2][0] += 6_f64 * p_295;
-    prob[4][0] += 6_f64 * p_295;
-    prob[6][0] += 6_f64 * p_295;
-let p_296 = p_107 * p_5_0_3;
-    prob[2][1] += 6_f64 * p_296;
-    prob[4][1] += 3_f64 * p_296;
-    prob[6][1] += 3_f64 * p_296;
-let p_297 = p_110 * p_4_0_3;
-    prob[0][0] += 2_f64 * p_297;
-    prob[3][0] += 4_f64 * p_297;
-    prob[4][0] += 4_f64 * p_297;
-    prob[6][0] += 4_f64 * p_297;
-let p_298 = p_109 * p_5_0_3;
-    prob[1][1] += 6_f64 * p_298;
-    prob[3][1] += 6_f64 * p_298;
-    prob[4][1] += 6_f64 * p_298;
-    prob[6][1] += 6_f64 * p_298;
-let p_299 = p_78 * p_7_0_3;
-    prob[2][2] += 6_f64 * p_299;
-    prob[3][2] += 6_f64 * p_299;
-    prob[4][2] += 6_f64 * p_299;
-    prob[6][2] += 6_f64 * p_299;
-let p_300 = p_79 * p_7_0_3;
-    prob[3][3] += 6_f64 * p_300;
-    prob[4][3] += 3_f64 * p_300;
-    prob[6][3] += 3_f64 * p_300;
-let p_301 = p_111 * p_1_0_3;
-    prob[0][1] += p_301;
-    prob[4][1] += 2_f64 * p_301;
-    prob[6][1] += p_301;
-let p_302 = p_111 * p_2_0_3;
-    prob[1][2] += 3_f64 * p_302;
-    prob[4][2] += 4_f64 * p_302;
-    prob[6][2] += 2_f64 * p_302;
-let p_303 = p_111 * p_3_0_3;
-    prob[2][3] += 3_f64 * p_303;
-    prob[4][3] += 4_f64 * p_303;
-    prob[6][3] += 2_f64 * p_303;
-let p_304 = p_111 * p_4_0_3;
-    prob[3][4] += 3_f64 * p_304;
-    prob[4][4] += 4_f64 * p_304;
-    prob[6][4] += 2_f64 * p_304;
-let p_305 = p_104 * p_6_0_3;
-    prob[1][0] += 6_f64 * p_305;
-    prob[5][0] += 3_f64 * p_305;
-    prob[6][0] += 3_f64 * p_305;
-let p_306 = p_105 * p_6_0_3;
-    prob[2][0] += 2_f64 * p_306;
-    prob[5][0] += 3_f64 * p_306;
-    prob[6][0] += 3_f64 * p_306;
-let p_307 = p_106 * p_6_0_3;
-    prob[1][1] += 6_f64 * p_307;
-    prob[2][1] += 6_f64 * p_307;
-    prob[5][1] += 6_f64 * p_307;
-    prob[6][1] += 6_f64 * p_307;
-let p_308 = p_107 * p_6_0_3;
-    prob[2][2] += 6_f64 * p_308;
-    prob[5][2] += 3_f64 * p_308;
-    prob[6][2] += 3_f64 * p_308;
-let p_309 = p_112 * p_1_0_3;
-    prob[0][1] += p_309;
-    prob[3][1] += 2_f64 * p_309;
-    prob[5][1] += p_309;
-    prob[6][1] += 2_f64 * p_309;
-let p_310 = p_112 * p_2_0_3;
-    prob[1][2] += 6_f64 * p_310;
-    prob[3][2] += 4_f64 * p_310;
-    prob[5][2] += 4_f64 * p_310;
-    prob[6][2] += 4_f64 * p_310;
-let p_311 = p_112 * p_3_0_3;
-    prob[2][3] += 6_f64 * p_311;
-    prob[3][3] += 4_f64 * p_311;
-    prob[5][3] += 4_f64 * p_311;
-    prob[6][3] += 4_f64 * p_311;
-let p_312 = p_112 * p_4_1_3;
-    prob[3][4] += 4_f64 * p_312;
-    prob[5][4] += p_312;
-    prob[6][4] += p_312;
-let p_313 = p_113 * p_1_0_3;
-    prob[0][2] += 2_f64 * p_313;
-    prob[4][2] += 4_f64 * p_313;
-    prob[5][2] += 2_f64 * p_313;
-    prob[6][2] += 2_f64 * p_313;
-let p_314 = p_113 * p_2_0_3;
-    prob[1][3] += 6_f64 * p_314;
-    prob[4][3] += 4_f64 * p_314;
-    prob[5][3] += 4_f64 * p_314;
-    prob[6][3] += 4_f64 * p_314;
-let p_315 = p_113 * p_3_0_3;
-    prob[2][4] += 6_f64 * p_315;
-    prob[4][4] += 4_f64 * p_315;
-    prob[5][4] += 4_f64 * p_315;
-    prob[6][4] += 4_f64 * p_315;
-let p_316 = p_114 * p_1_0_3;
-    prob[5][3] += 2_f64 * p_316;
-    prob[6][3] += p_316;
-let p_317 = p_114 * p_2_0_3;
-    prob[1][4] += 3_f64 * p_317;
-    prob[5][4] += 4_f64 * p_317;
-    prob[6][4] += 2_f64 * p_317;
-let p_318 = p_103 * p_7_1_3;
-    prob[1][0] += p_318;
-    prob[6][0] += 3_f64 * p_318;
-let p_319 = p_104 * p_7_1_3;
-    prob[1][1] += 3_f64 * p_319;
-    prob[6][1] += 3_f64 * p_319;
-let p_320 = p_115 * p_1_0_3;
-    prob[6][1] += p_320;
-let p_321 = p_115 * p_2_0_3;
-    prob[1][2] += 3_f64 * p_321;
-    prob[2][2] += 2_f64 * p_321;
-    prob[6][2] += 4_f64 * p_321;
-let p_322 = p_115 * p_3_1_3;
-    prob[2][3] += 2_f64 * p_322;
-    prob[6][3] += p_322;
-let p_323 = p_116 * p_1_0_3;
-    prob[0][2] += p_323;
-    prob[3][2] += 2_f64 * p_323;
-    prob[6][2] += 2_f64 * p_323;
-let p_324 = p_116 * p_2_0_3;
-    prob[1][3] += 3_f64 * p_324;
-    prob[3][3] += 2_f64 * p_324;
-    prob[6][3] += 4_f64 * p_324;
-let p_325 = p_116 * p_3_0_3;
-    prob[2][4] += 2_f64 * p_325;
-    prob[3][4] += 2_f64 * p_325;
-    prob[6][4] += 2_f64 * p_325;
-let p_326 = p_117 * p_1_0_3;
-    prob[0][3] += p_326;
-    prob[4][3] += 2_f64 * p_326;
-    prob[6][3] += 2_f64 * p_326;
-let p_327 = p_117 * p_2_0_3;
-    prob[1][4] += 3_f64 * p_327;
-    prob[4][4] += 2_f64 * p_327;
-    prob[6][4] += 4_f64 * p_327;
-let p_328 = p_118 * p_1_0_3;
-    prob[5][4] += p_328;
-    prob[6][4] += 2_f64 * p_328;
-let p_329 = p_120 * p_1_2_3;
-    prob[0][4] += p_329;
-let p_330 = p_121 * p_1_1_3;
-let p_331 = p_59 * p_8_0_3;
-    prob[2][0] += 3_f64 * p_331;
-    prob[7][0] += p_331;
-let p_332 = p_64 * p_8_0_3;
-    prob[1][0] += 6_f64 * p_332;
-    prob[2][0] += 6_f64 * p_332;
-    prob[3][0] += 6_f64 * p_332;
-    prob[7][0] += 6_f64 * p_332;
-let p_333 = p_65 * p_8_0_3;
-    prob[2][1] += 6_f64 * p_333;
-    prob[3][1] += 3_f64 * p_333;
-    prob[7][1] += 3_f64 * p_333;
-let p_334 = p_66 * p_8_0_3;
-    prob[0][0] += p_334;
-    prob[3][0] += 4_f64 * p_334;
-    prob[7][0] += 2_f64 * p_334;
-let p_335 = p_67 * p_8_0_3;
-    prob[1][1] += 3_f64 * p_335;
-    prob[3][1] += 6_f64 * p_335;
-    prob[7][1] += 3_f64 * p_335;
-let p_336 = p_68 * p_8_0_3;
-    prob[2][2] += 3_f64 * p_336;
-    prob[3][2] += 6_f64 * p_336;
-    prob[7][2] += 3_f64 * p_336;
-let p_337 = p_69 * p_8_0_3;
-    prob[3][3] += 3_f64 * p_337;
-    prob[7][3] += p_337;
-let p_338 = p_122 * p_5_0_3;
-    prob[1][0] += 6_f64 * p_338;
-    prob[4][0] += 3_f64 * p_338;
-    prob[7][0] += 3_f64 * p_338;
-let p_339 = p_123 * p_5_0_3;
-    prob[0][0] += 2_f64 * p_339;
-    prob[2][0] += 4_f64 * p_339;
-    prob[4][0] += 4_f64 * p_339;
-    prob[7][0] += 4_f64 * p_339;
-let p_340 = p_74 * p_8_0_3;
-    prob[1][1] += 6_f64 * p_340;
-    prob[2][1] += 6_f64 * p_340;
-    prob[4][1] += 6_f64 * p_340;
-    prob[7][1] += 6_f64 * p_340;
-let p_341 = p_75 * p_8_0_3;
-    prob[2][2] += 6_f64 * p_341;
-    prob[4][2] += 3_f64 * p_341;
-    prob[7][2] += 3_f64 * p_341;
-let p_342 = p_124 * p_1_0_3;
-    prob[0][1] += 2_f64 * p_342;
-    prob[3][1] += 2_f64 * p_342;
-    prob[4][1] += 2_f64 * p_342;
-    prob[7][1] += 2_f64 * p_342;
-let p_343 = p_124 * p_2_0_3;
-    prob[1][2] += 6_f64 * p_343;
-    prob[3][2] += 4_f64 * p_343;
-    prob[4][2] += 4_f64 * p_343;
-    prob[7][2] += 4_f64 * p_343;
-let p_344 = p_124 * p_3_0_3;
-    prob[2][3] += 6_f64 * p_344;
-    prob[3][3] += 4_f64 * p_344;
-    prob[4][3] += 4_f64 * p_344;
-    prob[7][3] += 4_f64 * p_344;
-let p_345 = p_124 * p_4_1_3;
-    prob[3][4] += 4_f64 * p_345;
-    prob[4][4] += p_345;
-    prob[7][4] += p_345;
-let p_346 = p_125 * p_1_0_3;
-    prob[0][2] += p_346;
-    prob[4][2] += 2_f64 * p_346;
-    prob[7][2] += p_346;
-let p_347 = p_125 * p_2_0_3;
-    prob[1][3] += 3_f64 * p_347;
-    prob[4][3] += 4_f64 * p_347;
-    prob[7][3] += 2_f64 * p_347;
-let p_348 = p_125 * p_3_0_3;
-    prob[2][4] += 3_f64 * p_348;
-    prob[4][4] += 4_f64 * p_348;
-    prob[7][4] += 2_f64 * p_348;
-let p_349 = p_121 * p_6_0_3;
-    prob[1][0] += 2_f64 * p_349;
-    prob[5][0] += 3_f64 * p_349;
-    prob[7][0] += 3_f64 * p_349;
-let p_350 = p_122 * p_6_0_3;
-    prob[1][1] += 6_f64 * p_350;
-    prob[5][1] += 3_f64 * p_350;
-    prob[7][1] += 3_f64 * p_350;
-let p_351 = p_126 * p_1_0_3;
-    prob[0][1] += p_351;
-    prob[2][1] += 2_f64 * p_351;
-    prob[5][1] += p_351;
-    prob[7][1] += 2_f64 * p_351;
-let p_352 = p_126 * p_2_0_3;
-    prob[1][2] += 6_f64 * p_352;
-    prob[2][2] += 4_f64 * p_352;
-    prob[5][2] += 4_f64 * p_352;
-    prob[7][2] += 4_f64 * p_352;
-let p_353 = p_126 * p_3_1_3;
-    prob[2][3] += 4_f64 * p_353;
-    prob[5][3] += p_353;
-    prob[7][3] += p_353;
-let p_354 = p_127 * p_1_0_3;
-    prob[0][2] += p_354;
-    prob[3][2] += 2_f64 * p_354;
-    prob[5][2] += p_354;
-    prob[7][2] += 2_f64 * p_354;
-let p_355 = p_127 * p_2_0_3;
-    prob[1][3] += 6_f64 * p_355;
-    prob[3][3] += 4_f64 * p_355;
-    prob[5][3] += 4_f64 * p_355;
-    prob[7][3] += 4_f64 * p_355;
-let p_356 = p_127 * p_3_0_3;
-    prob[2][4] += 4_f64 * p_356;
-    prob[3][4] += 4_f64 * p_356;
-    prob[5][4] += 2_f64 * p_356;
-    prob[7][4] += 2_f64 * p_356;
-let p_357 = p_128 * p_1_0_3;
-    prob[0][3] += 2_f64 * p_357;
-    prob[4][3] += 4_f64 * p_357;
-    prob[5][3] += 2_f64 * p_357;
-    prob[7][3] += 2_f64 * p_357;
-let p_358 = p_128 * p_2_0_3;
-    prob[1][4] += 6_f64 * p_358;
-    prob[4][4] += 4_f64 * p_358;
-    prob[5][4] += 4_f64 * p_358;
-    prob[7][4] += 4_f64 * p_358;
-let p_359 = p_129 * p_1_0_3;
-    prob[5][4] += 2_f64 * p_359;
-    prob[7][4] += p_359;
-let p_360 = p_120 * p_7_0_3;
-    prob[6][0] += p_360;
-    prob[7][0] += p_360;
-let p_361 = p_130 * p_1_0_3;
-    prob[6][1] += p_361;
-    prob[7][1] += p_361;
-let p_362 = p_130 * p_2_1_3;
-    prob[1][2] += 4_f64 * p_362;
-    prob[6][2] += p_362;
-    prob[7][2] += p_362;
-let p_363 = p_131 * p_1_0_3;
-    prob[0][2] += p_363;
-    prob[2][2] += 2_f64 * p_363;
-    prob[6][2] += p_363;
-    prob[7][2] += 2_f64 * p_363;
-let p_364 = p_131 * p_2_0_3;
-    prob[1][3] += 4_f64 * p_364;
-    prob[2][3] += 4_f64 * p_364;
-    prob[6][3] += 2_f64 * p_364;
-    prob[7][3] += 2_f64 * p_364;
-let p_365 = p_131 * p_3_1_3;
-    prob[2][4] += 4_f64 * p_365;
-    prob[6][4] += p_365;
-    prob[7][4] += p_365;
-let p_366 = p_132 * p_1_0_3;
-    prob[0][3] += 2_f64 * p_366;
-    prob[3][3] += 4_f64 * p_366;
-    prob[6][3] += 2_f64 * p_366;
-    prob[7][3] += 2_f64 * p_366;
-let p_367 = p_132 * p_2_0_3;
-    prob[1][4] += 4_f64 * p_367;
-    prob[3][4] += 4_f64 * p_367;
-    prob[6][4] += 2_f64 * p_367;
-    prob[7][4] += 2_f64 * p_367;
-let p_368 = p_133 * p_1_0_3;
-    prob[0][4] += 2_f64 * p_368;
-    prob[4][4] += 4_f64 * p_368;
-    prob[6][4] += 2_f64 * p_368;
-    prob[7][4] += 2_f64 * p_368;
-let p_369 = p_136 * p_2_1_3;
-    prob[1][3] += 2_f64 * p_369;
-    prob[7][3] += p_369;
-let p_370 = p_137 * p_1_0_3;
-    prob[0][3] += p_370;
-    prob[2][3] += 2_f64 * p_370;
-    prob[7][3] += p_370;
-let p_371 = p_137 * p_2_0_3;
-    prob[1][4] += 2_f64 * p_371;
-    prob[2][4] += 2_f64 * p_371;
-    prob[7][4] += 2_f64 * p_371;
-let p_372 = p_138 * p_1_0_3;
-    prob[0][4] += p_372;
-    prob[3][4] += 2_f64 * p_372;
-    prob[7][4] += p_372;
-let p_373 = p_140 * p_1_2_3;
-let p_374 = p_58 * p_9_0_3;
-    prob[1][0] += 3_f64 * p_374;
-    prob[2][0] += 6_f64 * p_374;
-    prob[8][0] += 3_f64 * p_374;
-let p_375 = p_59 * p_9_0_3;
-    prob[2][1] += 3_f64 * p_375;
-    prob[8][1] += p_375;
-let p_376 = p_62 * p_9_0_3;
-    prob[1][0] += 6_f64 * p_376;
-    prob[3][0] += 3_f64 * p_376;
-    prob[8][0] += 3_f64 * p_376;
-let p_377 = p_63 * p_9_0_3;
-    prob[0][0] += 2_f64 * p_377;
-    prob[2][0] += 4_f64 * p_377;
-    prob[3][0] += 4_f64 * p_377;
-    prob[8][0] += 4_f64 * p_377;
-let p_378 = p_64 * p_9_0_3;
-    prob[1][1] += 6_f64 * p_378;
-    prob[2][1] += 6_f64 * p_378;
-    prob[3][1] += 6_f64 * p_378;
-    prob[8][1] += 6_f64 * p_378;
-let p_379 = p_65 * p_9_0_3;
-    prob[2][2] += 6_f64 * p_379;
-    prob[3][2] += 3_f64 * p_379;
-    prob[8][2] += 3_f64 * p_379;
-let p_380 = p_141 * p_1_0_3;
-    prob[0][1] += p_380;
-    prob[3][1] += 2_f64 * p_380;
-    prob[8][1] += p_380;
-let p_381 = p_141 * p_2_0_3;
-    prob[1][2] += 3_f64 * p_381;
-    prob[3][2] += 4_f64 * p_381;
-    prob[8][2] += 2_f64 * p_381;
-let p_382 = p_141 * p_3_0_3;
-    prob[2][3] += 3_f64 * p_382;
-    prob[3][3] += 4_f64 * p_382;
-    prob[8][3] += 2_f64 * p_382;
-let p_383 = p_141 * p_4_2_3;
-    prob[3][4] += p_383;
-let p_384 = p_71 * p_9_0_3;
-    prob[0][0] += 2_f64 * p_384;
-    prob[1][0] += 4_f64 * p_384;
-    prob[4][0] += 4_f64 * p_384;
-    prob[8][0] += 4_f64 * p_384;
-let p_385 = p_72 * p_9_0_3;
-    prob[1][1] += 6_f64 * p_385;
-    prob[4][1] += 3_f64 * p_385;
-    prob[8][1] += 3_f64 * p_385;
-let p_386 = p_142 * p_1_0_3;
-    prob[0][1] += 2_f64 * p_386;
-    prob[2][1] += 2_f64 * p_386;
-    prob[4][1] += 2_f64 * p_386;
-    prob[8][1] += 2_f64 * p_386;
-let p_387 = p_142 * p_2_0_3;
-    prob[1][2] += 6_f64 * p_387;
-    prob[2][2] += 4_f64 * p_387;
-    prob[4][2] += 4_f64 * p_387;
-    prob[8][2] += 4_f64 * p_387;
-let p_388 = p_142 * p_3_1_3;
-    prob[2][3] += 4_f64 * p_388;
-    prob[4][3] += p_388;
-    prob[8][3] += p_388;
-let p_389 = p_143 * p_1_0_3;
-    prob[0][2] += 2_f64 * p_389;
-    prob[3][2] += 2_f64 * p_389;
-    prob[4][2] += 2_f64 * p_389;
-    prob[8][2] += 2_f64 * p_389;
-let p_390 = p_143 * p_2_0_3;
-    prob[1][3] += 6_f64 * p_390;
-    prob[3][3] += 4_f64 * p_390;
-    prob[4][3] += 4_f64 * p_390;
-    prob[8][3] += 4_f64 * p_390;
-let p_391 = p_143 * p_3_0_3;
-    prob[2][4] += 4_f64 * p_391;
-    prob[3][4] += 4_f64 * p_391;
-    prob[4][4] += 2_f64 * p_391;
-    prob[8][4] += 2_f64 * p_391;
-let p_392 = p_144 * p_1_0_3;
-    prob[0][3] += p_392;
-    prob[4][3] += 2_f64 * p_392;
-    prob[8][3] += p_392;
-let p_393 = p_144 * p_2_0_3;
-    prob[1][4] += 3_f64 * p_393;
-    prob[4][4] += 4_f64 * p_393;
-    prob[8][4] += 2_f64 * p_393;
-let p_394 = p_140 * p_6_0_3;
-    prob[5][0] += p_394;
-    prob[8][0] += p_394;
-let p_395 = p_145 * p_1_0_3;
-    prob[0][1] += p_395;
-    prob[1][1] += 2_f64 * p_395;
-    prob[5][1] += p_395;
-    prob[8][1] += 2_f64 * p_395;
-let p_396 = p_145 * p_2_1_3;
-    prob[1][2] += 4_f64 * p_396;
-    prob[5][2] += p_396;
-    prob[8][2] += p_396;
-let p_397 = p_146 * p_1_0_3;
-    prob[0][2] += p_397;
-    prob[2][2] += 2_f64 * p_397;
-    prob[5][2] += p_397;
-    prob[8][2] += 2_f64 * p_397;
-let p_398 = p_146 * p_2_0_3;
-    prob[1][3] += 4_f64 * p_398;
-    prob[2][3] += 4_f64 * p_398;
-    prob[5][3] += 2_f64 * p_398;
-    prob[8][3] += 2_f64 * p_398;
-let p_399 = p_146 * p_3_1_3;
-    prob[2][4] += 4_f64 * p_399;
-    prob[5][4] += p_399;
-    prob[8][4] += p_399;
-let p_400 = p_147 * p_1_0_3;
-    prob[0][3] += p_400;
-    prob[3][3] += 2_f64 * p_400;
-    prob[5][3] += p_400;
-    prob[8][3] += 2_f64 * p_400;
-let p_401 = p_147 * p_2_0_3;
-    prob[1][4] += 4_f64 * p_401;
-    prob[3][4] += 4_f64 * p_401;
-    prob[5][4] += 2_f64 * p_401;
-    prob[8][4] += 2_f64 * p_401;
-let p_402 = p_148 * p_1_0_3;
-    prob[0][4] += 2_f64 * p_402;
-    prob[4][4] += 4_f64 * p_402;
-    prob[5][4] += 2_f64 * p_402;
-    prob[8][4] += 2_f64 * p_402;
-let p_403 = p_151 * p_1_0_3;
-    prob[0][2] += p_403;
-    prob[1][2] += 2_f64 * p_403;
-    prob[8][2] += p_403;
-let p_404 = p_151 * p_2_1_3;
-    prob[1][3] += 4_f64 * p_404;
-    prob[6][3] += p_404;
-    prob[8][3] += p_404;
-let p_405 = p_152 * p_1_0_3;
-    prob[0][3] += p_405;
-    prob[2][3] += 2_f64 * p_405;
-    prob[8][3] += p_405;
-let p_406 = p_152 * p_2_0_3;
-    prob[1][4] += 4_f64 * p_406;
-    prob[2][4] += 4_f64 * p_406;
-    prob[6][4] += 2_f64 * p_406;
-    prob[8][4] += 2_f64 * p_406;
-let p_407 = p_153 * p_1_0_3;
-    prob[0][4] += 2_f64 * p_407;
-    prob[3][4] += 4_f64 * p_407;
-    prob[6][4] += p_407;
-    prob[8][4] += p_407;
-let p_408 = p_155 * p_1_0_3;
-    prob[0][3] += p_408;
-    prob[1][3] += 2_f64 * p_408;
-    prob[8][3] += p_408;
-let p_409 = p_155 * p_2_1_3;
-    prob[1][4] += 4_f64 * p_409;
-    prob[7][4] += p_409;
-    prob[8][4] += p_409;
-let p_410 = p_156 * p_1_0_3;
-    prob[0][4] += 2_f64 * p_410;
-    prob[2][4] += 4_f64 * p_410;
-    prob[7][4] += p_410;
-    prob[8][4] += p_410;
-let p_411 = p_158 * p_1_0_3;
-    prob[0][4] += p_411;
-    prob[1][4] += 2_f64 * p_411;
-    prob[8][4] += p_411;
-let p_412 = p_160 * p_3_0_3;
-    prob[1][0] += 6_f64 * p_412;
-    prob[2][0] += 3_f64 * p_412;
-    prob[9][0] += 3_f64 * p_412;
-let p_413 = p_57 * p_10_0_3;
-    prob[0][0] += p_413;
-    prob[2][0] += 4_f64 * p_413;
-    prob[9][0] += 2_f64 * p_413;
-let p_414 = p_58 * p_10_0_3;
-    prob[1][1] += 3_f64 * p_414;
-    prob[2][1] += 6_f64 * p_414;
-    prob[9][1] += 3_f64 * p_414;
-let p_415 = p_59 * p_10_0_3;
-    prob[2][2] += 3_f64 * p_415;
-    prob[9][2] += p_415;
-let p_416 = p_61 * p_10_0_3;
-    prob[0][0] += 2_f64 * p_416;
-    prob[1][0] += 4_f64 * p_416;
-    prob[3][0] += 4_f64 * p_416;
-    prob[9][0] += 4_f64 * p_416;
-let p_417 = p_160 * p_4_0_3;
-    prob[1][1] += 6_f64 * p_417;
-    prob[3][1] += 3_f64 * p_417;
-    prob[9][1] += 3_f64 * p_417;
-let p_418 = p_161 * p_1_0_3;
-    prob[0][1] += 2_f64 * p_418;
-    prob[2][1] += 2_f64 * p_418;
-    prob[3][1] += 2_f64 * p_418;
-    prob[9][1] += 2_f64 * p_418;
-let p_419 = p_161 * p_2_0_3;
-    prob[1][2] += 6_f64 * p_419;
-    prob[2][2] += 4_f64 * p_419;
-    prob[3][2] += 4_f64 * p_419;
-    prob[9][2] += 4_f64 * p_419;
-let p_420 = p_161 * p_3_1_3;
-    prob[2][3] += 4_f64 * p_420;
-    prob[3][3] += p_420;
-    prob[9][3] += p_420;
-let p_421 = p_162 * p_1_0_3;
-    prob[0][2] += p_421;
-    prob[3][2] += 2_f64 * p_421;
-    prob[9][2] += p_421;
-let p_422 = p_162 * p_2_0_3;
-    prob[1][3] += 3_f64 * p_422;
-    prob[3][3] += 4_f64 * p_422;
-    prob[9][3] += 2_f64 * p_422;
-let p_423 = p_162 * p_3_0_3;
-    prob[2][4] += p_423;
-    prob[3][4] += 2_f64 * p_423;
-let p_424 = p_70 * p_10_0_3;
-    prob[0][0] += 2_f64 * p_424;
-    prob[4][0] += 2_f64 * p_424;
-    prob[9][0] += p_424;
-let p_425 = p_163 * p_1_0_3;
-    prob[0][1] += 2_f64 * p_425;
-    prob[1][1] += 2_f64 * p_425;
-    prob[4][1] += 2_f64 * p_425;
-    prob[9][1] += 2_f64 * p_425;
-let p_426 = p_163 * p_2_1_3;
-    prob[1][2] += 4_f64 * p_426;
-    prob[4][2] += p_426;
-    prob[9][2] += p_426;
-let p_427 = p_164 * p_1_0_3;
-    prob[0][2] += 2_f64 * p_427;
-    prob[2][2] += 2_f64 * p_427;
-    prob[4][2] += 2_f64 * p_427;
-    prob[9][2] += 2_f64 * p_427;
-let p_428 = p_164 * p_2_0_3;
-    prob[1][3] += 4_f64 * p_428;
-    prob[2][3] += 4_f64 * p_428;
-    prob[4][3] += 2_f64 * p_428;
-    prob[9][3] += 2_f64 * p_428;
-let p_429 = p_164 * p_3_1_3;
-    prob[2][4] += 4_f64 * p_429;
-    prob[4][4] += p_429;
-    prob[9][4] += p_429;
-let p_430 = p_165 * p_1_0_3;
-    prob[0][3] += 2_f64 * p_430;
-    prob[3][3] += 2_f64 * p_430;
-    prob[4][3] += 2_f64 * p_430;
-    prob[9][3] += 2_f64 * p_430;
-let p_431 = p_165 * p_2_0_3;
-    prob[1][4] += 4_f64 * p_431;
-    prob[3][4] += 4_f64 * p_431;
-    prob[4][4] += 2_f64 * p_431;
-    prob[9][4] += 2_f64 * p_431;
-let p_432 = p_166 * p_1_0_3;
-    prob[0][4] += p_432;
-    prob[4][4] += 2_f64 * p_432;
-    prob[9][4] += p_432;
-let p_433 = p_167 * p_1_1_3;
-    prob[0][1] += p_433;
-let p_434 = p_168 * p_1_0_3;
-    prob[0][2] += p_434;
-    prob[1][2] += 2_f64 * p_434;
-    prob[9][2] += p_434;
-let p_435 = p_168 * p_2_1_3;
-    prob[1][3] += 4_f64 * p_435;
-    prob[5][3] += p_435;
-    prob[9][3] += p_435;
-let p_436 = p_169 * p_1_0_3;
-    prob[0][3] += p_436;
-    prob[2][3] += 2_f64 * p_436;
-    prob[9][3] += p_436;
-let p_437 = p_169 * p_2_0_3;
-    prob[1][4] += 4_f64 * p_437;
-    prob[2][4] += 4_f64 * p_437;
-    prob[5][4] += 2_f64 * p_437;
-    prob[9][4] += 2_f64 * p_437;
-let p_438 = p_170 * p_1_0_3;
-    prob[0][4] += p_438;
-    prob[3][4] += 2_f64 * p_438;
-    prob[9][4] += p_438;
-let p_439 = p_102 * p_10_0_3;
-    prob[0][2] += p_439;
-let p_440 = p_172 * p_1_0_3;
-    prob[0][3] += p_440;
-    prob[1][3] += 2_f64 * p_440;
-    prob[9][3] += p_440;
-let p_441 = p_172 * p_2_1_3;
-    prob[1][4] += 4_f64 * p_441;
-    prob[6][4] += p_441;
-    prob[9][4] += p_441;
-let p_442 = p_173 * p_1_0_3;
-    prob[0][4] += p_442;
-    prob[2][4] += 2_f64 * p_442;
-    prob[9][4] += p_442;
-let p_443 = p_120 * p_10_0_3;
-    prob[0][3] += p_443;
-let p_444 = p_175 * p_1_0_3;
-    prob[0][4] += p_444;
-    prob[1][4] += 2_f64 * p_444;
-    prob[9][4] += p_444;
-let p_445 = p_140 * p_10_0_3;
-    prob[0][4] += p_445;
-let p_446 = p_178 * p_1_4_4;
-let p_447 = p_179 * p_1_3_4;
-let p_448 = p_180 * p_1_2_4;
-    prob[0][0] += 6_f64 * p_448;
-    prob[1][0] += 4_f64 * p_448;
-let p_449 = p_181 * p_1_1_4;
-    prob[0][1] += 3_f64 * p_449;
-    prob[1][1] += 3_f64 * p_449;
-let p_450 = p_182 * p_1_0_4;
-    prob[1][2] += p_450;
-let p_451 = p_182 * p_2_4_4;
-let p_452 = p_183 * p_1_3_4;
-    prob[0][0] += 4_f64 * p_452;
-    prob[2][0] += p_452;
-let p_453 = p_184 * p_1_2_4;
-    prob[0][1] += 6_f64 * p_453;
-    prob[1][1] += p_453;
-    prob[2][1] += p_453;
-let p_454 = p_185 * p_1_1_4;
-    prob[0][2] += 3_f64 * p_454;
-    prob[1][2] += 3_f64 * p_454;
-let p_455 = p_186 * p_1_0_4;
-    prob[1][3] += 3_f64 * p_455;
-    prob[2][3] += p_455;
-let p_456 = p_186 * p_2_3_4;
-let p_457 = p_187 * p_1_2_4;
-    prob[0][2] += 3_f64 * p_457;
-    prob[2][2] += p_457;
-let p_458 = p_188 * p_1_1_4;
-let p_459 = p_189 * p_1_0_4;
-    prob[1][4] += 3_f64 * p_459;
-    prob[2][4] += 3_f64 * p_459;
-let p_460 = p_189 * p_2_2_4;
-let p_461 = p_191 * p_1_0_4;
-let p_462 = p_191 * p_2_1_4;
-let p_463 = p_192 * p_1_0_4;
-let p_464 = p_192 * p_2_0_4;
-let p_465 = p_192 * p_3_4_4;
-let p_466 = p_193 * p_1_3_4;
-    prob[0][1] += p_466;
-let p_467 = p_194 * p_1_2_4;
-    prob[0][2] += 3_f64 * p_467;
-    prob[1][2] += p_467;
-let p_468 = p_195 * p_1_1_4;
-    prob[0][3] += 3_f64 * p_468;
-    prob[1][3] += 3_f64 * p_468;
-let p_469 = p_196 * p_1_0_4;
-    prob[1][4] += 3_f64 * p_469;
-    prob[3][4] += p_469;
-let p_470 = p_196 * p_2_3_4;
-let p_471 = p_197 * p_1_2_4;
-    prob[0][3] += 3_f64 * p_471;
-    prob[2][3] += p_471;
-let p_472 = p_199 * p_1_0_4;
-let p_473 = p_199 * p_2_2_4;
-let p_474 = p_201 * p_1_0_4;
-let p_475 = p_201 * p_2_1_4;
-let p_476 = p_202 * p_1_0_4;
-let p_477 = p_202 * p_2_0_4;
-let p_478 = p_202 * p_3_3_4;
-let p_479 = p_205 * p_1_0_4;
-let p_480 = p_205 * p_2_2_4;
-let p_481 = p_206 * p_1_1_4;
-let p_482 = p_207 * p_1_0_4;
-let p_483 = p_207 * p_2_1_4;
-let p_484 = p_208 * p_1_0_4;
-let p_485 = p_208 * p_2_0_4;
-let p_486 = p_208 * p_3_2_4;
-    prob[2][0] += 6_f64 * p_486;
-    prob[3][0] += 4_f64 * p_486;
-let p_487 = p_209 * p_1_1_4;
-let p_488 = p_210 * p_1_0_4;
-let p_489 = p_210 * p_2_1_4;
-let p_490 = p_211 * p_1_0_4;
-let p_491 = p_211 * p_2_0_4;
-    prob[1][0] += 4_f64 * p_491;
-    prob[2][0] += 4_f64 * p_491;
-    prob[3][0] += 12_f64 * p_491;
-let p_492 = p_211 * p_3_1_4;
-    prob[2][1] += 4_f64 * p_492;
-    prob[3][1] += 6_f64 * p_492;
-let p_493 = p_212 * p_1_0_4;
-    prob[3][0] += 2_f64 * p_493;
-let p_494 = p_212 * p_2_0_4;
-    prob[1][1] += p_494;
-    prob[3][1] += 4_f64 * p_494;
-let p_495 = p_212 * p_3_0_4;
-    prob[2][2] += p_495;
-    prob[3][2] += 4_f64 * p_495;
-let p_496 = p_212 * p_4_4_4;
-    prob[3][3] += p_496;
-let p_497 = p_213 * p_1_3_4;
-    prob[0][2] += p_497;
-let p_498 = p_214 * p_1_2_4;
-    prob[0][3] += 3_f64 * p_498;
-    prob[1][3] += p_498;
-let p_499 = p_215 * p_1_1_4;
-    prob[0][4] += 3_f64 * p_499;
-    prob[1][4] += 3_f64 * p_499;
-let p_500 = p_216 * p_1_0_4;
-let p_501 = p_216 * p_2_3_4;
-let p_502 = p_217 * p_1_2_4;
-    prob[0][4] += 3_f64 * p_502;
-    prob[2][4] += p_502;
-let p_503 = p_219 * p_1_0_4;
-let p_504 = p_219 * p_2_2_4;
-let p_505 = p_220 * p_1_1_4;
-let p_506 = p_221 * p_1_0_4;
-let p_507 = p_221 * p_2_1_4;
-let p_508 = p_222 * p_1_0_4;
-let p_509 = p_222 * p_3_3_4;
-    prob[2][0] += 4_f64 * p_509;
-    prob[4][0] += p_509;
-let p_510 = p_223 * p_1_2_4;
-let p_511 = p_224 * p_1_1_4;
-let p_512 = p_225 * p_1_0_4;
-let p_513 = p_225 * p_2_2_4;
-let p_514 = p_226 * p_1_1_4;
-let p_515 = p_227 * p_1_0_4;
-let p_516 = p_228 * p_2_0_4;
-    prob[1][0] += 12_f64 * p_516;
-    prob[2][0] += 24_f64 * p_516;
-    prob[3][0] += 12_f64 * p_516;
-    prob[4][0] += 12_f64 * p_516;
-let p_517 = p_228 * p_3_2_4;
-    prob[2][1] += 12_f64 * p_517;
-    prob[3][1] += 4_f64 * p_517;
-    prob[4][1] += 4_f64 * p_517;
-let p_518 = p_229 * p_1_1_4;
-let p_519 = p_230 * p_2_1_4;
-    prob[1][0] += 12_f64 * p_519;
-    prob[3][0] += 12_f64 * p_519;
-    prob[4][0] += 6_f64 * p_519;
-let p_520 = p_229 * p_3_0_4;
-    prob[2][0] += 6_f64 * p_520;
-    prob[3][0] += 12_f64 * p_520;
-    prob[4][0] += 6_f64 * p_520;
-let p_521 = p_230 * p_3_0_4;
-    prob[1][1] += 12_f64 * p_521;
-    prob[2][1] += 12_f64 * p_521;
-    prob[3][1] += 24_f64 * p_521;
-    prob[4][1] += 12_f64 * p_521;
-let p_522 = p_228 * p_4_1_4;
-    prob[2][2] += 12_f64 * p_522;
-    prob[3][2] += 12_f64 * p_522;
-    prob[4][2] += 6_f64 * p_522;
-let p_523 = p_231 * p_1_0_4;
-    prob[3][1] += 3_f64 * p_523;
-    prob[4][1] += p_523;
-let p_524 = p_231 * p_2_0_4;
-    prob[1][2] += 4_f64 * p_524;
-    prob[3][2] += 9_f64 * p_524;
-    prob[4][2] += 3_f64 * p_524;
-let p_525 = p_231 * p_3_0_4;
-    prob[2][3] += 4_f64 * p_525;
-    prob[3][3] += 9_f64 * p_525;
-    prob[4][3] += 3_f64 * p_525;
-let p_526 = p_231 * p_4_3_4;
-    prob[3][4] += p_526;
-let p_527 = p_232 * p_1_2_4;
-let p_528 = p_233 * p_1_1_4;
-let p_529 = p_234 * p_1_0_4;
-let p_530 = p_235 * p_1_1_4;
-let p_531 = p_236 * p_2_1_4;
-    prob[1][0] += 12_f64 * p_531;
-    prob[2][0] += 6_f64 * p_531;
-    prob[4][0] += 12_f64 * p_531;
-let p_532 = p_235 * p_3_1_4;
-    prob[2][0] += 6_f64 * p_532;
-    prob[4][0] += 6_f64 * p_532;
-let p_533 = p_236 * p_3_1_4;
-    prob[1][1] += 6_f64 * p_533;
-    prob[2][1] += 12_f64 * p_533;
-    prob[4][1] += 12_f64 * p_533;
-let p_534 = p_222 * p_5_1_4;
-    prob[2][2] += 6_f64 * p_534;
-    prob[4][2] += 4_f64 * p_534;
-let p_535 = p_237 * p_2_0_4;
-    prob[1][0] += 6_f64 * p_535;
-    prob[3][0] += 6_f64 * p_535;
-    prob[4][0] += 12_f64 * p_535;
-let p_536 = p_234 * p_4_0_4;
-    prob[1][1] += 12_f64 * p_536;
-    prob[3][1] += 6_f64 * p_536;
-    prob[4][1] += 12_f64 * p_536;
-let p_537 = p_238 * p_1_0_4;
-    prob[2][1] += 3_f64 * p_537;
-    prob[3][1] += 3_f64 * p_537;
-    prob[4][1] += 6_f64 * p_537;
-let p_538 = p_238 * p_2_0_4;
-    prob[1][2] += 12_f64 * p_538;
-    prob[2][2] += 9_f64 * p_538;
-    prob[3][2] += 9_f64 * p_538;
-    prob[4][2] += 18_f64 * p_538;
-let p_539 = p_238 * p_3_1_4;
-    prob[2][3] += 9_f64 * p_539;
-    prob[3][3] += 3_f64 * p_539;
-    prob[4][3] += 6_f64 * p_539;
-let p_540 = p_239 * p_1_0_4;
-    prob[3][2] += 3_f64 * p_540;
-    prob[4][2] += 3_f64 * p_540;
-let p_541 = p_239 * p_2_0_4;
-    prob[1][3] += 6_f64 * p_541;
-    prob[3][3] += 9_f64 * p_541;
-    prob[4][3] += 9_f64 * p_541;
-let p_542 = p_239 * p_3_0_4;
-    prob[2][4] += 3_f64 * p_542;
-    prob[3][4] += 6_f64 * p_542;
-    prob[4][4] += 3_f64 * p_542;
-let p_543 = p_232 * p_5_2_4;
-    prob[4][0] += p_543;
-let p_544 = p_240 * p_1_0_4;
-    prob[1][1] += p_544;
-    prob[4][1] += 3_f64 * p_544;
-let p_545 = p_240 * p_2_1_4;
-    prob[1][2] += 3_f64 * p_545;
-    prob[4][2] += 3_f64 * p_545;
-let p_546 = p_241 * p_1_0_4;
-    prob[2][2] += p_546;
-    prob[4][2] += 3_f64 * p_546;
-let p_547 = p_241 * p_2_0_4;
-    prob[1][3] += 3_f64 * p_547;
-    prob[2][3] += 3_f64 * p_547;
-    prob[4][3] += 6_f64 * p_547;
-let p_548 = p_241 * p_3_1_4;
-    prob[2][4] += 3_f64 * p_548;
-    prob[4][4] += 3_f64 * p_548;
-let p_549 = p_242 * p_1_0_4;
-    prob[3][3] += p_549;
-    prob[4][3] += 3_f64 * p_549;
-let p_550 = p_242 * p_2_0_4;
-    prob[1][4] += 3_f64 * p_550;
-    prob[3][4] += 3_f64 * p_550;
-    prob[4][4] += 6_f64 * p_550;
-let p_551 = p_243 * p_1_0_4;
-    prob[4][4] += p_551;
-let p_552 = p_244 * p_1_3_4;
-    prob[0][3] += p_552;
-let p_553 = p_245 * p_1_2_4;
-    prob[0][4] += 3_f64 * p_553;
-    prob[1][4] += p_553;
-let p_554 = p_246 * p_1_1_4;
-let p_555 = p_247 * p_1_0_4;
-let p_556 = p_247 * p_2_3_4;
-let p_557 = p_248 * p_1_2_4;
-let p_558 = p_249 * p_1_1_4;
-let p_559 = p_250 * p_1_0_4;
-let p_560 = p_251 * p_1_1_4;
-let p_561 = p_252 * p_3_2_4;
-    prob[1][0] += 4_f64 * p_561;
-    prob[2][0] += 12_f64 * p_561;
-    prob[5][0] += 4_f64 * p_561;
-let p_562 = p_192 * p_6_0_4;
-    prob[2][1] += 4_f64 * p_562;
-    prob[5][1] += p_562;
-let p_563 = p_253 * p_1_2_4;
-let p_564 = p_254 * p_1_1_4;
-let p_565 = p_255 * p_3_0_4;
-    prob[1][0] += 24_f64 * p_565;
-    prob[2][0] += 12_f64 * p_565;
-    prob[3][0] += 12_f64 * p_565;
-    prob[5][0] += 12_f64 * p_565;
-let p_566 = p_256 * p_3_1_4;
-    prob[2][0] += 10_f64 * p_566;
-    prob[3][0] += 5_f64 * p_566;
-    prob[5][0] += 6_f64 * p_566;
-let p_567 = p_252 * p_4_0_4;
-    prob[1][1] += 12_f64 * p_567;
-    prob[2][1] += 24_f64 * p_567;
-    prob[3][1] += 12_f64 * p_567;
-    prob[5][1] += 12_f64 * p_567;
-let p_568 = p_202 * p_6_0_4;
-    prob[2][2] += 12_f64 * p_568;
-    prob[3][2] += 4_f64 * p_568;
-    prob[5][2] += 4_f64 * p_568;
-let p_569 = p_254 * p_4_1_4;
-    prob[1][0] += 5_f64 * p_569;
-    prob[3][0] += 10_f64 * p_569;
-    prob[5][0] += 6_f64 * p_569;
-let p_570 = p_255 * p_4_1_4;
-    prob[1][1] += 12_f64 * p_570;
-    prob[3][1] += 12_f64 * p_570;
-    prob[5][1] += 6_f64 * p_570;
-let p_571 = p_257 * p_1_0_4;
-    prob[2][1] += 3_f64 * p_571;
-    prob[3][1] += 6_f64 * p_571;
-    prob[5][1] += 3_f64 * p_571;
-let p_572 = p_257 * p_2_0_4;
-    prob[1][2] += 12_f64 * p_572;
-    prob[2][2] += 9_f64 * p_572;
-    prob[3][2] += 18_f64 * p_572;
-    prob[5][2] += 9_f64 * p_572;
-let p_573 = p_257 * p_3_1_4;
-    prob[2][3] += 9_f64 * p_573;
-    prob[3][3] += 6_f64 * p_573;
-    prob[5][3] += 3_f64 * p_573;
-let p_574 = p_258 * p_1_0_4;
-    prob[3][2] += 3_f64 * p_574;
-    prob[5][2] += p_574;
-let p_575 = p_258 * p_2_0_4;
-    prob[1][3] += 4_f64 * p_575;
-    prob[3][3] += 9_f64 * p_575;
-    prob[5][3] += 3_f64 * p_575;
-let p_576 = p_258 * p_3_0_4;
-    prob[2][4] += p_576;
-    prob[3][4] += 3_f64 * p_576;
-let p_577 = p_259 * p_1_2_4;
-let p_578 = p_247 * p_5_0_4;
-    prob[1][0] += 12_f64 * p_578;
-    prob[4][0] += 4_f64 * p_578;
-    prob[5][0] += 4_f64 * p_578;
-let p_579 = p_260 * p_3_0_4;
-    prob[0][0] += 4_f64 * p_579;
-    prob[1][0] += 12_f64 * p_579;
-    prob[2][0] += 12_f64 * p_579;
-    prob[4][0] += 14_f64 * p_579;
-    prob[5][0] += 14_f64 * p_579;
-let p_580 = p_250 * p_5_0_4;
-    prob[1][1] += 24_f64 * p_580;
-    prob[2][1] += 12_f64 * p_580;
-    prob[4][1] += 12_f64 * p_580;
-    prob[5][1] += 12_f64 * p_580;
-let p_581 = p_261 * p_1_0_4;
-    prob[0][1] += p_581;
-    prob[2][1] += 6_f64 * p_581;
-    prob[4][1] += 5_f64 * p_581;
-    prob[5][1] += 4_f64 * p_581;
-let p_582 = p_261 * p_2_0_4;
-    prob[1][2] += 12_f64 * p_582;
-    prob[2][2] += 18_f64 * p_582;
-    prob[4][2] += 9_f64 * p_582;
-    prob[5][2] += 9_f64 * p_582;
-let p_583 = p_261 * p_3_2_4;
-    prob[2][3] += 6_f64 * p_583;
-    prob[4][3] += p_583;
-    prob[5][3] += p_583;
-let p_584 = p_259 * p_4_0_4;
-    prob[0][0] += 4_f64 * p_584;
-    prob[3][0] += 3_f64 * p_584;
-    prob[4][0] += 6_f64 * p_584;
-    prob[5][0] += 4_f64 * p_584;
-let p_585 = p_262 * p_1_0_4;
-    prob[0][1] += 2_f64 * p_585;
-    prob[1][1] += 6_f64 * p_585;
-    prob[3][1] += 6_f64 * p_585;
-    prob[4][1] += 10_f64 * p_585;
-    prob[5][1] += 8_f64 * p_585;
-let p_586 = p_262 * p_2_1_4;
-    prob[1][2] += 18_f64 * p_586;
-    prob[3][2] += 6_f64 * p_586;
-    prob[4][2] += 6_f64 * p_586;
-    prob[5][2] += 6_f64 * p_586;
-let p_587 = p_263 * p_1_0_4;
-    prob[0][2] += 2_f64 * p_587;
-    prob[2][2] += 6_f64 * p_587;
-    prob[3][2] += 6_f64 * p_587;
-    prob[4][2] += 10_f64 * p_587;
-    prob[5][2] += 8_f64 * p_587;
-let p_588 = p_263 * p_2_0_4;
-    prob[1][3] += 18_f64 * p_588;
-    prob[2][3] += 18_f64 * p_588;
-    prob[3][3] += 12_f64 * p_588;
-    prob[4][3] += 12_f64 * p_588;
-    prob[5][3] += 12_f64 * p_588;
-let p_589 = p_263 * p_3_1_4;
-    prob[2][4] += 12_f64 * p_589;
-    prob[3][4] += 6_f64 * p_589;
-    prob[4][4] += 3_f64 * p_589;
-    prob[5][4] += 3_f64 * p_589;
-let p_590 = p_264 * p_1_0_4;
-    prob[0][3] += p_590;
-    prob[3][3] += 6_f64 * p_590;
-    prob[4][3] += 5_f64 * p_590;
-    prob[5][3] += 4_f64 * p_590;
-let p_591 = p_264 * p_2_0_4;
-    prob[1][4] += 6_f64 * p_591;
-    prob[3][4] += 12_f64 * p_591;
-    prob[4][4] += 3_f64 * p_591;
-    prob[5][4] += 3_f64 * p_591;
-let p_592 = p_265 * p_1_1_4;
-    prob[0][1] += p_592;
-    prob[4][1] += 2_f64 * p_592;
-    prob[5][1] += p_592;
-let p_593 = p_266 * p_1_0_4;
-    prob[0][2] += p_593;
-    prob[1][2] += 3_f64 * p_593;
-    prob[4][2] += 6_f64 * p_593;
-    prob[5][2] += 3_f64 * p_593;
-let p_594 = p_266 * p_2_1_4;
-    prob[1][3] += 9_f64 * p_594;
-    prob[4][3] += 6_f64 * p_594;
-    prob[5][3] += 3_f64 * p_594;
-let p_595 = p_267 * p_1_0_4;
-    prob[0][3] += p_595;
-    prob[2][3] += 3_f64 * p_595;
-    prob[4][3] += 6_f64 * p_595;
-    prob[5][3] += 3_f64 * p_595;
-let p_596 = p_267 * p_2_0_4;
-    prob[1][4] += 9_f64 * p_596;
-    prob[2][4] += 9_f64 * p_596;
-    prob[4][4] += 12_f64 * p_596;
-    prob[5][4] += 6_f64 * p_596;
-let p_597 = p_268 * p_1_0_4;
-    prob[0][4] += p_597;
-    prob[3][4] += 3_f64 * p_597;
-    prob[4][4] += 6_f64 * p_597;
-    prob[5][4] += 3_f64 * p_597;
-let p_598 = p_246 * p_6_1_4;
-    prob[1][0] += 4_f64 * p_598;
-    prob[5][0] += 6_f64 * p_598;
-let p_599 = p_247 * p_6_1_4;
-    prob[1][1] += 6_f64 * p_599;
-    prob[5][1] += 4_f64 * p_599;
-let p_600 = p_270 * p_3_0_4;
-    prob[2][0] += p_600;
-    prob[5][0] += 4_f64 * p_600;
-let p_601 = p_271 * p_1_0_4;
-    prob[0][1] += 2_f64 * p_601;
-    prob[1][1] += 5_f64 * p_601;
-    prob[2][1] += 5_f64 * p_601;
-    prob[5][1] += 8_f64 * p_601;
-let p_602 = p_271 * p_2_1_4;
-    prob[1][2] += 9_f64 * p_602;
-    prob[2][2] += 3_f64 * p_602;
-    prob[5][2] += 6_f64 * p_602;
-let p_603 = p_272 * p_1_0_4;
-    prob[2][2] += 3_f64 * p_603;
-    prob[5][2] += 4_f64 * p_603;
-let p_604 = p_272 * p_2_0_4;
-    prob[1][3] += 3_f64 * p_604;
-    prob[2][3] += 6_f64 * p_604;
-    prob[5][3] += 3_f64 * p_604;
-let p_605 = p_272 * p_3_2_4;
-    prob[2][4] += 3_f64 * p_605;
-    prob[5][4] += p_605;
-let p_606 = p_273 * p_1_1_4;
-    prob[0][1] += 2_f64 * p_606;
-    prob[3][1] += p_606;
-    prob[5][1] += p_606;
-let p_607 = p_274 * p_1_0_4;
-    prob[1][2] += 3_f64 * p_607;
-    prob[3][2] += 2_f64 * p_607;
-    prob[5][2] += 5_f64 * p_607;
-let p_608 = p_274 * p_2_1_4;
-    prob[1][3] += 9_f64 * p_608;
-    prob[3][3] += 3_f64 * p_608;
-    prob[5][3] += 6_f64 * p_608;
-let p_609 = p_275 * p_1_0_4;
-    prob[2][3] += 3_f64 * p_609;
-    prob[3][3] += 2_f64 * p_609;
-    prob[5][3] += 5_f64 * p_609;
-let p_610 = p_275 * p_2_0_4;
-    prob[1][4] += 6_f64 * p_610;
-    prob[2][4] += 6_f64 * p_610;
-    prob[3][4] += 6_f64 * p_610;
-    prob[5][4] += 6_f64 * p_610;
-let p_611 = p_276 * p_1_0_4;
-    prob[3][4] += 2_f64 * p_611;
-    prob[5][4] += p_611;
-let p_612 = p_277 * p_1_1_4;
-    prob[0][2] += p_612;
-    prob[4][2] += 2_f64 * p_612;
-    prob[5][2] += 2_f64 * p_612;
-let p_613 = p_278 * p_1_0_4;
-    prob[0][3] += p_613;
-    prob[1][3] += 3_f64 * p_613;
-    prob[4][3] += 4_f64 * p_613;
-    prob[5][3] += 6_f64 * p_613;
-let p_614 = p_278 * p_2_1_4;
-    prob[1][4] += 9_f64 * p_614;
-    prob[4][4] += 3_f64 * p_614;
-    prob[5][4] += 6_f64 * p_614;
-let p_615 = p_279 * p_1_0_4;
-    prob[0][4] += p_615;
-    prob[2][4] += 3_f64 * p_615;
-    prob[4][4] += 4_f64 * p_615;
-    prob[5][4] += 6_f64 * p_615;
-let p_616 = p_281 * p_1_1_4;
-    prob[5][3] += p_616;
-let p_617 = p_282 * p_1_0_4;
-    prob[1][4] += p_617;
-    prob[5][4] += 3_f64 * p_617;
-let p_618 = p_284 * p_1_3_4;
-    prob[0][4] += p_618;
-let p_619 = p_285 * p_1_2_4;
-let p_620 = p_286 * p_1_1_4;
-let p_621 = p_288 * p_1_2_4;
-let p_622 = p_189 * p_7_0_4;
-    prob[1][0] += 12_f64 * p_622;
-    prob[2][0] += 12_f64 * p_622;
-    prob[6][0] += 6_f64 * p_622;
-let p_623 = p_190 * p_7_0_4;
-    prob[2][0] += 5_f64 * p_623;
-    prob[6][0] += 2_f64 * p_623;
-let p_624 = p_191 * p_7_0_4;
-    prob[1][1] += 4_f64 * p_624;
-    prob[2][1] += 12_f64 * p_624;
-    prob[6][1] += 4_f64 * p_624;
-let p_625 = p_192 * p_7_0_4;
-    prob[2][2] += 4_f64 * p_625;
-    prob[6][2] += p_625;
-let p_626 = p_287 * p_4_0_4;
-    prob[1][0] += 12_f64 * p_626;
-    prob[3][0] += 4_f64 * p_626;
-    prob[6][0] += 4_f64 * p_626;
-let p_627 = p_289 * p_4_0_4;
-    prob[0][0] += 4_f64 * p_627;
-    prob[1][0] += 12_f64 * p_627;
-    prob[2][0] += 12_f64 * p_627;
-    prob[3][0] += 14_f64 * p_627;
-    prob[6][0] += 14_f64 * p_627;
-let p_628 = p_199 * p_7_0_4;
-    prob[1][1] += 24_f64 * p_628;
-    prob[2][1] += 12_f64 * p_628;
-    prob[3][1] += 12_f64 * p_628;
-    prob[6][1] += 12_f64 * p_628;
-let p_629 = p_291 * p_1_0_4;
-    prob[0][1] += p_629;
-    prob[2][1] += 4_f64 * p_629;
-    prob[3][1] += 4_f64 * p_629;
-    prob[6][1] += 4_f64 * p_629;
-let p_630 = p_291 * p_2_0_4;
-    prob[1][2] += 12_f64 * p_630;
-    prob[2][2] += 18_f64 * p_630;
-    prob[3][2] += 9_f64 * p_630;
-    prob[6][2] += 9_f64 * p_630;
-let p_631 = p_291 * p_3_2_4;
-    prob[2][3] += 6_f64 * p_631;
-    prob[3][3] += p_631;
-    prob[6][3] += p_631;
-let p_632 = p_290 * p_4_1_4;
-    prob[0][0] += 4_f64 * p_632;
-    prob[3][0] += 7_f64 * p_632;
-    prob[6][0] += 3_f64 * p_632;
-let p_633 = p_292 * p_1_0_4;
-    prob[0][1] += 2_f64 * p_633;
-    prob[1][1] += 2_f64 * p_633;
-    prob[3][1] += 8_f64 * p_633;
-    prob[6][1] += 5_f64 * p_633;
-let p_634 = p_292 * p_2_1_4;
-    prob[1][2] += 9_f64 * p_634;
-    prob[3][2] += 6_f64 * p_634;
-    prob[6][2] += 3_f64 * p_634;
-let p_635 = p_293 * p_1_0_4;
-    prob[0][2] += 2_f64 * p_635;
-    prob[2][2] += 3_f64 * p_635;
-    prob[3][2] += 10_f64 * p_635;
-    prob[6][2] += 5_f64 * p_635;
-let p_636 = p_293 * p_2_0_4;
-    prob[1][3] += 9_f64 * p_636;
-    prob[2][3] += 9_f64 * p_636;
-    prob[3][3] += 12_f64 * p_636;
-    prob[6][3] += 6_f64 * p_636;
-let p_637 = p_293 * p_3_1_4;
-    prob[2][4] += 3_f64 * p_637;
-    prob[3][4] += 3_f64 * p_637;
-let p_638 = p_294 * p_1_0_4;
-    prob[0][3] += p_638;
-    prob[3][3] += 5_f64 * p_638;
-    prob[6][3] += 2_f64 * p_638;
-let p_639 = p_294 * p_2_0_4;
-    prob[1][4] += p_639;
-    prob[3][4] += 3_f64 * p_639;
-let p_640 = p_286 * p_5_0_4;
-    prob[0][0] += 2_f64 * p_640;
-    prob[1][0] += 12_f64 * p_640;
-    prob[4][0] += 7_f64 * p_640;
-    prob[6][0] += 7_f64 * p_640;
-let p_641 = p_287 * p_5_0_4;
-    prob[1][1] += 12_f64 * p_641;
-    prob[4][1] += 4_f64 * p_641;
-    prob[6][1] += 4_f64 * p_641;
-let p_642 = p_288 * p_5_0_4;
-    prob[0][0] += 4_f64 * p_642;
-    prob[2][0] += 3_f64 * p_642;
-    prob[4][0] += 6_f64 * p_642;
-    prob[6][0] += 4_f64 * p_642;
-let p_643 = p_295 * p_1_0_4;
-    prob[0][1] += 4_f64 * p_643;
-    prob[1][1] += 6_f64 * p_643;
-    prob[2][1] += 6_f64 * p_643;
-    prob[4][1] += 8_f64 * p_643;
-    prob[6][1] += 8_f64 * p_643;
-let p_644 = p_295 * p_2_1_4;
-    prob[1][2] += 18_f64 * p_644;
-    prob[2][2] += 6_f64 * p_644;
-    prob[4][2] += 6_f64 * p_644;
-    prob[6][2] += 6_f64 * p_644;
-let p_645 = p_296 * p_1_0_4;
-    prob[0][2] += p_645;
-    prob[2][2] += 6_f64 * p_645;
-    prob[4][2] += 5_f64 * p_645;
-    prob[6][2] += 4_f64 * p_645;
-let p_646 = p_296 * p_2_0_4;
-    prob[1][3] += 6_f64 * p_646;
-    prob[2][3] += 12_f64 * p_646;
-    prob[4][3] += 3_f64 * p_646;
-    prob[6][3] += 3_f64 * p_646;
-let p_647 = p_296 * p_3_2_4;
-    prob[2][4] += 6_f64 * p_647;
-    prob[4][4] += p_647;
-    prob[6][4] += p_647;
-let p_648 = p_297 * p_1_1_4;
-    prob[0][1] += 4_f64 * p_648;
-    prob[3][1] += 3_f64 * p_648;
-    prob[4][1] += 2_f64 * p_648;
-    prob[6][1] += 2_f64 * p_648;
-let p_649 = p_298 * p_1_0_4;
-    prob[0][2] += 2_f64 * p_649;
-    prob[1][2] += 6_f64 * p_649;
-    prob[3][2] += 6_f64 * p_649;
-    prob[4][2] += 6_f64 * p_649;
-    prob[6][2] += 6_f64 * p_649;
-let p_650 = p_298 * p_2_1_4;
-    prob[1][3] += 18_f64 * p_650;
-    prob[3][3] += 6_f64 * p_650;
-    prob[4][3] += 6_f64 * p_650;
-    prob[6][3] += 6_f64 * p_650;
-let p_651 = p_299 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_651;
-    prob[2][3] += 6_f64 * p_651;
-    prob[3][3] += 6_f64 * p_651;
-    prob[4][3] += 6_f64 * p_651;
-    prob[6][3] += 6_f64 * p_651;
-let p_652 = p_299 * p_2_0_4;
-    prob[1][4] += 12_f64 * p_652;
-    prob[2][4] += 12_f64 * p_652;
-    prob[3][4] += 12_f64 * p_652;
-    prob[4][4] += 6_f64 * p_652;
-    prob[6][4] += 6_f64 * p_652;
-let p_653 = p_300 * p_1_0_4;
-    prob[0][4] += p_653;
-    prob[3][4] += 6_f64 * p_653;
-    prob[4][4] += p_653;
-    prob[6][4] += 2_f64 * p_653;
-let p_654 = p_301 * p_1_1_4;
-    prob[0][2] += p_654;
-    prob[4][2] += 2_f64 * p_654;
-    prob[6][2] += p_654;
-let p_655 = p_302 * p_1_0_4;
-    prob[0][3] += p_655;
-    prob[1][3] += 3_f64 * p_655;
-    prob[4][3] += 6_f64 * p_655;
-    prob[6][3] += 3_f64 * p_655;
-let p_656 = p_302 * p_2_1_4;
-    prob[1][4] += 9_f64 * p_656;
-    prob[4][4] += 6_f64 * p_656;
-    prob[6][4] += 3_f64 * p_656;
-let p_657 = p_303 * p_1_0_4;
-    prob[0][4] += p_657;
-    prob[2][4] += 3_f64 * p_657;
-    prob[4][4] += 6_f64 * p_657;
-    prob[6][4] += 3_f64 * p_657;
-let p_658 = p_285 * p_6_0_4;
-    prob[1][0] += 2_f64 * p_658;
-    prob[5][0] += 4_f64 * p_658;
-    prob[6][0] += 4_f64 * p_658;
-let p_659 = p_305 * p_1_0_4;
-    prob[0][1] += p_659;
-    prob[1][1] += 6_f64 * p_659;
-    prob[5][1] += 3_f64 * p_659;
-    prob[6][1] += 4_f64 * p_659;
-let p_660 = p_305 * p_2_2_4;
-    prob[1][2] += 6_f64 * p_660;
-    prob[5][2] += p_660;
-    prob[6][2] += p_660;
-let p_661 = p_306 * p_1_1_4;
-    prob[0][1] += 2_f64 * p_661;
-    prob[2][1] += p_661;
-    prob[5][1] += p_661;
-    prob[6][1] += p_661;
-let p_662 = p_307 * p_1_0_4;
-    prob[0][2] += 4_f64 * p_662;
-    prob[1][2] += 10_f64 * p_662;
-    prob[2][2] += 8_f64 * p_662;
-    prob[5][2] += 5_f64 * p_662;
-    prob[6][2] += 5_f64 * p_662;
-let p_663 = p_307 * p_2_1_4;
-    prob[1][3] += 12_f64 * p_663;
-    prob[2][3] += 6_f64 * p_663;
-    prob[5][3] += 3_f64 * p_663;
-    prob[6][3] += 3_f64 * p_663;
-let p_664 = p_308 * p_1_0_4;
-    prob[2][3] += 4_f64 * p_664;
-    prob[5][3] += p_664;
-    prob[6][3] += p_664;
-let p_665 = p_308 * p_2_0_4;
-    prob[1][4] += 6_f64 * p_665;
-    prob[2][4] += 12_f64 * p_665;
-    prob[5][4] += 3_f64 * p_665;
-    prob[6][4] += 3_f64 * p_665;
-let p_666 = p_309 * p_1_1_4;
-    prob[0][2] += 5_f64 * p_666;
-    prob[3][2] += 4_f64 * p_666;
-    prob[5][2] += p_666;
-    prob[6][2] += 2_f64 * p_666;
-let p_667 = p_310 * p_1_0_4;
-    prob[0][3] += p_667;
-    prob[1][3] += 6_f64 * p_667;
-    prob[3][3] += 6_f64 * p_667;
-    prob[5][3] += 5_f64 * p_667;
-    prob[6][3] += 6_f64 * p_667;
-let p_668 = p_310 * p_2_1_4;
-    prob[1][4] += 12_f64 * p_668;
-    prob[3][4] += 6_f64 * p_668;
-    prob[5][4] += 3_f64 * p_668;
-    prob[6][4] += 3_f64 * p_668;
-let p_669 = p_311 * p_1_0_4;
-    prob[0][4] += p_669;
-    prob[2][4] += 4_f64 * p_669;
-    prob[3][4] += 6_f64 * p_669;
-    prob[5][4] += 2_f64 * p_669;
-    prob[6][4] += 3_f64 * p_669;
-let p_670 = p_313 * p_1_1_4;
-    prob[0][3] += 2_f64 * p_670;
-    prob[4][3] += 4_f64 * p_670;
-    prob[5][3] += 2_f64 * p_670;
-    prob[6][3] += 2_f64 * p_670;
-let p_671 = p_314 * p_1_0_4;
-    prob[0][4] += 2_f64 * p_671;
-    prob[1][4] += 6_f64 * p_671;
-    prob[4][4] += 8_f64 * p_671;
-    prob[5][4] += 6_f64 * p_671;
-    prob[6][4] += 6_f64 * p_671;
-let p_672 = p_316 * p_1_1_4;
-    prob[5][4] += 2_f64 * p_672;
-    prob[6][4] += p_672;
-let p_673 = p_284 * p_7_1_4;
-    prob[6][0] += p_673;
-let p_674 = p_318 * p_1_1_4;
-    prob[6][1] += p_674;
-let p_675 = p_319 * p_1_0_4;
-    prob[0][2] += p_675;
-    prob[1][2] += 4_f64 * p_675;
-    prob[6][2] += p_675;
-let p_676 = p_319 * p_2_2_4;
-    prob[1][3] += 3_f64 * p_676;
-    prob[6][3] += p_676;
-let p_677 = p_320 * p_1_1_4;
-    prob[0][2] += 2_f64 * p_677;
-    prob[2][2] += p_677;
-    prob[6][2] += p_677;
-let p_678 = p_321 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_678;
-    prob[1][3] += 4_f64 * p_678;
-    prob[2][3] += 4_f64 * p_678;
-    prob[6][3] += 2_f64 * p_678;
-let p_679 = p_321 * p_2_1_4;
-    prob[1][4] += 6_f64 * p_679;
-    prob[2][4] += 3_f64 * p_679;
-    prob[6][4] += 3_f64 * p_679;
-let p_680 = p_322 * p_1_0_4;
-    prob[2][4] += 2_f64 * p_680;
-    prob[6][4] += p_680;
-let p_681 = p_323 * p_1_1_4;
-    prob[0][3] += 3_f64 * p_681;
-    prob[3][3] += 3_f64 * p_681;
-    prob[6][3] += 2_f64 * p_681;
-let p_682 = p_324 * p_1_0_4;
-    prob[0][4] += p_682;
-    prob[1][4] += 2_f64 * p_682;
-    prob[3][4] += 4_f64 * p_682;
-    prob[6][4] += 3_f64 * p_682;
-let p_683 = p_326 * p_1_1_4;
-    prob[0][4] += p_683;
-    prob[4][4] += 2_f64 * p_683;
-    prob[6][4] += 2_f64 * p_683;
-let p_684 = p_329 * p_1_3_4;
-let p_685 = p_186 * p_8_0_4;
-    prob[1][0] += 12_f64 * p_685;
-    prob[2][0] += 4_f64 * p_685;
-    prob[7][0] += 4_f64 * p_685;
-let p_686 = p_188 * p_8_0_4;
-    prob[0][0] += 4_f64 * p_686;
-    prob[1][0] += 7_f64 * p_686;
-    prob[2][0] += 16_f64 * p_686;
-    prob[7][0] += 8_f64 * p_686;
-let p_687 = p_189 * p_8_0_4;
-    prob[1][1] += 12_f64 * p_687;
-    prob[2][1] += 12_f64 * p_687;
-    prob[7][1] += 6_f64 * p_687;
-let p_688 = p_331 * p_1_0_4;
-    prob[0][1] += p_688;
-    prob[2][1] += 4_f64 * p_688;
-    prob[7][1] += 2_f64 * p_688;
-let p_689 = p_331 * p_2_0_4;
-    prob[1][2] += 4_f64 * p_689;
-    prob[2][2] += 9_f64 * p_689;
-    prob[7][2] += 3_f64 * p_689;
-let p_690 = p_331 * p_3_3_4;
-    prob[2][3] += p_690;
-let p_691 = p_195 * p_8_0_4;
-    prob[0][0] += 2_f64 * p_691;
-    prob[1][0] += 12_f64 * p_691;
-    prob[3][0] += 7_f64 * p_691;
-    prob[7][0] += 7_f64 * p_691;
-let p_692 = p_196 * p_8_0_4;
-    prob[1][1] += 12_f64 * p_692;
-    prob[3][1] += 4_f64 * p_692;
-    prob[7][1] += 4_f64 * p_692;
-let p_693 = p_197 * p_8_0_4;
-    prob[0][0] += 8_f64 * p_693;
-    prob[2][0] += 7_f64 * p_693;
-    prob[3][0] += 7_f64 * p_693;
-    prob[7][0] += 6_f64 * p_693;
-let p_694 = p_332 * p_1_0_4;
-    prob[0][1] += 6_f64 * p_694;
-    prob[1][1] += 6_f64 * p_694;
-    prob[2][1] += 10_f64 * p_694;
-    prob[3][1] += 8_f64 * p_694;
-    prob[7][1] += 10_f64 * p_694;
-let p_695 = p_332 * p_2_1_4;
-    prob[1][2] += 18_f64 * p_695;
-    prob[2][2] += 6_f64 * p_695;
-    prob[3][2] += 6_f64 * p_695;
-    prob[7][2] += 6_f64 * p_695;
-let p_696 = p_333 * p_1_0_4;
-    prob[0][2] += 3_f64 * p_696;
-    prob[2][2] += 8_f64 * p_696;
-    prob[3][2] += 4_f64 * p_696;
-    prob[7][2] += 6_f64 * p_696;
-let p_697 = p_333 * p_2_0_4;
-    prob[1][3] += 6_f64 * p_697;
-    prob[2][3] += 12_f64 * p_697;
-    prob[3][3] += 3_f64 * p_697;
-    prob[7][3] += 3_f64 * p_697;
-let p_698 = p_333 * p_3_2_4;
-    prob[2][4] += 3_f64 * p_698;
-    prob[3][4] += p_698;
-let p_699 = p_334 * p_1_1_4;
-    prob[0][1] += 3_f64 * p_699;
-    prob[3][1] += 3_f64 * p_699;
-    prob[7][1] += p_699;
-let p_700 = p_335 * p_1_0_4;
-    prob[0][2] += p_700;
-    prob[1][2] += 2_f64 * p_700;
-    prob[3][2] += 4_f64 * p_700;
-    prob[7][2] += 3_f64 * p_700;
-let p_701 = p_335 * p_2_1_4;
-    prob[1][3] += 9_f64 * p_701;
-    prob[3][3] += 6_f64 * p_701;
-    prob[7][3] += 3_f64 * p_701;
-let p_702 = p_336 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_702;
-    prob[2][3] += 5_f64 * p_702;
-    prob[3][3] += 6_f64 * p_702;
-    prob[7][3] += 4_f64 * p_702;
-let p_703 = p_336 * p_2_0_4;
-    prob[1][4] += 3_f64 * p_703;
-    prob[2][4] += 3_f64 * p_703;
-    prob[3][4] += 6_f64 * p_703;
-let p_704 = p_337 * p_1_0_4;
-    prob[3][4] += p_704;
-let p_705 = p_330 * p_5_0_4;
-    prob[0][0] += 4_f64 * p_705;
-    prob[1][0] += 3_f64 * p_705;
-    prob[4][0] += 6_f64 * p_705;
-    prob[7][0] += 4_f64 * p_705;
-let p_706 = p_338 * p_1_0_4;
-    prob[0][1] += 2_f64 * p_706;
-    prob[1][1] += 6_f64 * p_706;
-    prob[4][1] += 4_f64 * p_706;
-    prob[7][1] += 4_f64 * p_706;
-let p_707 = p_338 * p_2_2_4;
-    prob[1][2] += 6_f64 * p_707;
-    prob[4][2] += p_707;
-    prob[7][2] += p_707;
-let p_708 = p_339 * p_1_1_4;
-    prob[0][1] += 4_f64 * p_708;
-    prob[2][1] += 3_f64 * p_708;
-    prob[4][1] += 2_f64 * p_708;
-    prob[7][1] += 2_f64 * p_708;
-let p_709 = p_340 * p_1_0_4;
-    prob[0][2] += 4_f64 * p_709;
-    prob[1][2] += 6_f64 * p_709;
-    prob[2][2] += 6_f64 * p_709;
-    prob[4][2] += 4_f64 * p_709;
-    prob[7][2] += 6_f64 * p_709;
-let p_710 = p_340 * p_2_1_4;
-    prob[1][3] += 12_f64 * p_710;
-    prob[2][3] += 6_f64 * p_710;
-    prob[4][3] += 3_f64 * p_710;
-    prob[7][3] += 3_f64 * p_710;
-let p_711 = p_341 * p_1_0_4;
-    prob[0][3] += p_711;
-    prob[2][3] += 6_f64 * p_711;
-    prob[4][3] += p_711;
-    prob[7][3] += 2_f64 * p_711;
-let p_712 = p_341 * p_2_0_4;
-    prob[1][4] += 6_f64 * p_712;
-    prob[2][4] += 12_f64 * p_712;
-    prob[4][4] += 3_f64 * p_712;
-    prob[7][4] += 3_f64 * p_712;
-let p_713 = p_342 * p_1_1_4;
-    prob[0][2] += 4_f64 * p_713;
-    prob[3][2] += 3_f64 * p_713;
-    prob[4][2] += 2_f64 * p_713;
-    prob[7][2] += 2_f64 * p_713;
-let p_714 = p_343 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_714;
-    prob[1][3] += 6_f64 * p_714;
-    prob[3][3] += 6_f64 * p_714;
-    prob[4][3] += 6_f64 * p_714;
-    prob[7][3] += 6_f64 * p_714;
-let p_715 = p_343 * p_2_1_4;
-    prob[1][4] += 12_f64 * p_715;
-    prob[3][4] += 6_f64 * p_715;
-    prob[4][4] += 3_f64 * p_715;
-    prob[7][4] += 3_f64 * p_715;
-let p_716 = p_344 * p_1_0_4;
-    prob[0][4] += 2_f64 * p_716;
-    prob[2][4] += 6_f64 * p_716;
-    prob[3][4] += 6_f64 * p_716;
-    prob[4][4] += 2_f64 * p_716;
-    prob[7][4] += 4_f64 * p_716;
-let p_717 = p_346 * p_1_1_4;
-    prob[0][3] += p_717;
-    prob[4][3] += 2_f64 * p_717;
-    prob[7][3] += p_717;
-let p_718 = p_347 * p_1_0_4;
-    prob[0][4] += p_718;
-    prob[1][4] += 3_f64 * p_718;
-    prob[4][4] += 6_f64 * p_718;
-    prob[7][4] += 3_f64 * p_718;
-let p_719 = p_329 * p_6_0_4;
-    prob[5][0] += p_719;
-    prob[7][0] += p_719;
-let p_720 = p_349 * p_1_1_4;
-    prob[0][1] += 2_f64 * p_720;
-    prob[1][1] += p_720;
-    prob[5][1] += p_720;
-    prob[7][1] += p_720;
-let p_721 = p_350 * p_1_0_4;
-    prob[0][2] += p_721;
-    prob[1][2] += 4_f64 * p_721;
-    prob[7][2] += p_721;
-let p_722 = p_350 * p_2_2_4;
-    prob[1][3] += 6_f64 * p_722;
-    prob[5][3] += p_722;
-    prob[7][3] += p_722;
-let p_723 = p_351 * p_1_1_4;
-    prob[0][2] += 3_f64 * p_723;
-    prob[2][2] += 3_f64 * p_723;
-    prob[5][2] += p_723;
-    prob[7][2] += 2_f64 * p_723;
-let p_724 = p_352 * p_1_0_4;
-    prob[0][3] += 5_f64 * p_724;
-    prob[1][3] += 8_f64 * p_724;
-    prob[2][3] += 10_f64 * p_724;
-    prob[5][3] += 2_f64 * p_724;
-    prob[7][3] += 3_f64 * p_724;
-let p_725 = p_352 * p_2_1_4;
-    prob[1][4] += 12_f64 * p_725;
-    prob[2][4] += 6_f64 * p_725;
-    prob[5][4] += 3_f64 * p_725;
-    prob[7][4] += 3_f64 * p_725;
-let p_726 = p_353 * p_1_0_4;
-    prob[0][4] += p_726;
-    prob[2][4] += 6_f64 * p_726;
-    prob[5][4] += p_726;
-    prob[7][4] += 2_f64 * p_726;
-let p_727 = p_354 * p_1_1_4;
-    prob[0][3] += 5_f64 * p_727;
-    prob[3][3] += 4_f64 * p_727;
-    prob[5][3] += p_727;
-    prob[7][3] += 2_f64 * p_727;
-let p_728 = p_355 * p_1_0_4;
-    prob[0][4] += p_728;
-    prob[1][4] += 4_f64 * p_728;
-    prob[3][4] += 6_f64 * p_728;
-    prob[5][4] += 2_f64 * p_728;
-    prob[7][4] += 3_f64 * p_728;
-let p_729 = p_357 * p_1_1_4;
-    prob[0][4] += 2_f64 * p_729;
-    prob[4][4] += 4_f64 * p_729;
-    prob[5][4] += 2_f64 * p_729;
-    prob[7][4] += 2_f64 * p_729;
-let p_730 = p_361 * p_1_1_4;
-    prob[0][2] += 2_f64 * p_730;
-    prob[1][2] += p_730;
-let p_731 = p_362 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_731;
-    prob[1][3] += 8_f64 * p_731;
-    prob[6][3] += p_731;
-    prob[7][3] += p_731;
-let p_732 = p_362 * p_2_2_4;
-    prob[1][4] += 6_f64 * p_732;
-    prob[6][4] += p_732;
-    prob[7][4] += p_732;
-let p_733 = p_363 * p_1_1_4;
-    prob[0][3] += 5_f64 * p_733;
-    prob[2][3] += 4_f64 * p_733;
-    prob[7][3] += p_733;
-let p_734 = p_364 * p_1_0_4;
-    prob[0][4] += 5_f64 * p_734;
-    prob[1][4] += 8_f64 * p_734;
-    prob[2][4] += 10_f64 * p_734;
-    prob[6][4] += 2_f64 * p_734;
-    prob[7][4] += 3_f64 * p_734;
-let p_735 = p_366 * p_1_1_4;
-    prob[0][4] += 6_f64 * p_735;
-    prob[3][4] += 6_f64 * p_735;
-    prob[6][4] += p_735;
-    prob[7][4] += p_735;
-let p_736 = p_330 * p_8_1_4;
-    prob[0][3] += 2_f64 * p_736;
-    prob[1][3] += p_736;
-let p_737 = p_369 * p_1_0_4;
-    prob[0][4] += p_737;
-    prob[1][4] += 4_f64 * p_737;
-    prob[7][4] += p_737;
-let p_738 = p_370 * p_1_1_4;
-    prob[0][4] += 3_f64 * p_738;
-    prob[2][4] += 3_f64 * p_738;
-    prob[7][4] += p_738;
-let p_739 = p_182 * p_9_0_4;
-    prob[1][0] += 4_f64 * p_739;
-    prob[8][0] += p_739;
-let p_740 = p_185 * p_9_0_4;
-    prob[0][0] += 6_f64 * p_740;
-    prob[1][0] += 18_f64 * p_740;
-    prob[2][0] += 9_f64 * p_740;
-    prob[8][0] += 9_f64 * p_740;
-let p_741 = p_186 * p_9_0_4;
-    prob[1][1] += 12_f64 * p_741;
-    prob[2][1] += 4_f64 * p_741;
-    prob[8][1] += 4_f64 * p_741;
-let p_742 = p_187 * p_9_0_4;
-    prob[0][0] += 4_f64 * p_742;
-    prob[2][0] += 7_f64 * p_742;
-    prob[8][0] += 3_f64 * p_742;
-let p_743 = p_374 * p_1_0_4;
-    prob[0][1] += 5_f64 * p_743;
-    prob[1][1] += 6_f64 * p_743;
-    prob[2][1] += 10_f64 * p_743;
-    prob[8][1] += 6_f64 * p_743;
-let p_744 = p_374 * p_2_1_4;
-    prob[1][2] += 9_f64 * p_744;
-    prob[2][2] += 6_f64 * p_744;
-    prob[8][2] += 3_f64 * p_744;
-let p_745 = p_375 * p_1_0_4;
-    prob[0][2] += p_745;
-    prob[2][2] += 4_f64 * p_745;
-    prob[8][2] += 2_f64 * p_745;
-let p_746 = p_375 * p_2_0_4;
-    prob[1][3] += p_746;
-    prob[2][3] += 3_f64 * p_746;
-let p_747 = p_375 * p_3_3_4;
-    prob[2][4] += p_747;
-let p_748 = p_194 * p_9_0_4;
-    prob[0][0] += 8_f64 * p_748;
-    prob[1][0] += 7_f64 * p_748;
-    prob[3][0] += 7_f64 * p_748;
-    prob[8][0] += 6_f64 * p_748;
-let p_749 = p_376 * p_1_0_4;
-    prob[0][1] += 4_f64 * p_749;
-    prob[1][1] += 10_f64 * p_749;
-    prob[3][1] += 4_f64 * p_749;
-    prob[8][1] += 6_f64 * p_749;
-let p_750 = p_376 * p_2_2_4;
-    prob[1][2] += 6_f64 * p_750;
-    prob[3][2] += p_750;
-    prob[8][2] += p_750;
-let p_751 = p_377 * p_1_1_4;
-    prob[0][1] += 6_f64 * p_751;
-    prob[2][1] += 3_f64 * p_751;
-    prob[3][1] += 3_f64 * p_751;
-    prob[8][1] += 2_f64 * p_751;
-let p_752 = p_378 * p_1_0_4;
-    prob[0][2] += 6_f64 * p_752;
-    prob[1][2] += 10_f64 * p_752;
-    prob[2][2] += 6_f64 * p_752;
-    prob[3][2] += 4_f64 * p_752;
-    prob[8][2] += 8_f64 * p_752;
-let p_753 = p_378 * p_2_1_4;
-    prob[1][3] += 12_f64 * p_753;
-    prob[2][3] += 6_f64 * p_753;
-    prob[3][3] += 3_f64 * p_753;
-    prob[8][3] += 3_f64 * p_753;
-let p_754 = p_379 * p_1_0_4;
-    prob[0][3] += p_754;
-    prob[2][3] += 4_f64 * p_754;
-    prob[8][3] += 2_f64 * p_754;
-let p_755 = p_379 * p_2_0_4;
-    prob[1][4] += 3_f64 * p_755;
-    prob[2][4] += 6_f64 * p_755;
-    prob[3][4] += 3_f64 * p_755;
-let p_756 = p_380 * p_1_1_4;
-    prob[0][2] += 3_f64 * p_756;
-    prob[3][2] += 3_f64 * p_756;
-    prob[8][2] += p_756;
-let p_757 = p_381 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_757;
-    prob[1][3] += 4_f64 * p_757;
-    prob[3][3] += 4_f64 * p_757;
-    prob[8][3] += 4_f64 * p_757;
-let p_758 = p_381 * p_2_1_4;
-    prob[1][4] += 3_f64 * p_758;
-    prob[3][4] += 3_f64 * p_758;
-let p_759 = p_382 * p_1_0_4;
-    prob[2][4] += p_759;
-    prob[3][4] += 2_f64 * p_759;
-let p_760 = p_373 * p_5_0_4;
-    prob[0][0] += 2_f64 * p_760;
-    prob[4][0] += 2_f64 * p_760;
-    prob[8][0] += p_760;
-let p_761 = p_384 * p_1_1_4;
-    prob[0][1] += 4_f64 * p_761;
-    prob[1][1] += 3_f64 * p_761;
-    prob[4][1] += 2_f64 * p_761;
-    prob[8][1] += 2_f64 * p_761;
-let p_762 = p_385 * p_1_0_4;
-    prob[0][2] += 2_f64 * p_762;
-    prob[1][2] += 6_f64 * p_762;
-    prob[8][2] += 2_f64 * p_762;
-let p_763 = p_385 * p_2_2_4;
-    prob[1][3] += 6_f64 * p_763;
-    prob[4][3] += p_763;
-    prob[8][3] += p_763;
-let p_764 = p_386 * p_1_1_4;
-    prob[0][2] += 4_f64 * p_764;
-    prob[2][2] += 3_f64 * p_764;
-    prob[4][2] += 2_f64 * p_764;
-    prob[8][2] += 2_f64 * p_764;
-let p_765 = p_387 * p_1_0_4;
-    prob[0][3] += 4_f64 * p_765;
-    prob[1][3] += 6_f64 * p_765;
-    prob[2][3] += 6_f64 * p_765;
-    prob[8][3] += 4_f64 * p_765;
-let p_766 = p_387 * p_2_1_4;
-    prob[1][4] += 12_f64 * p_766;
-    prob[2][4] += 6_f64 * p_766;
-    prob[4][4] += 3_f64 * p_766;
-    prob[8][4] += 3_f64 * p_766;
-let p_767 = p_388 * p_1_0_4;
-    prob[0][4] += p_767;
-    prob[2][4] += 6_f64 * p_767;
-    prob[4][4] += p_767;
-    prob[8][4] += 2_f64 * p_767;
-let p_768 = p_389 * p_1_1_4;
-    prob[0][3] += 4_f64 * p_768;
-    prob[3][3] += 3_f64 * p_768;
-    prob[4][3] += 2_f64 * p_768;
-    prob[8][3] += 2_f64 * p_768;
-let p_769 = p_390 * p_1_0_4;
-    prob[0][4] += 2_f64 * p_769;
-    prob[1][4] += 6_f64 * p_769;
-    prob[3][4] += 6_f64 * p_769;
-    prob[4][4] += 2_f64 * p_769;
-    prob[8][4] += 4_f64 * p_769;
-let p_770 = p_392 * p_1_1_4;
-    prob[0][4] += p_770;
-    prob[4][4] += 2_f64 * p_770;
-    prob[8][4] += p_770;
-let p_771 = p_394 * p_1_2_4;
-    prob[0][1] += p_771;
-let p_772 = p_395 * p_1_1_4;
-    prob[0][2] += 3_f64 * p_772;
-    prob[1][2] += 3_f64 * p_772;
-    prob[8][2] += p_772;
-let p_773 = p_396 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_773;
-    prob[1][3] += 6_f64 * p_773;
-    prob[8][3] += 2_f64 * p_773;
-let p_774 = p_396 * p_2_2_4;
-    prob[1][4] += 6_f64 * p_774;
-    prob[5][4] += p_774;
-    prob[8][4] += p_774;
-let p_775 = p_397 * p_1_1_4;
-    prob[0][3] += 3_f64 * p_775;
-    prob[2][3] += 3_f64 * p_775;
-    prob[8][3] += p_775;
-let p_776 = p_398 * p_1_0_4;
-    prob[0][4] += 6_f64 * p_776;
-    prob[1][4] += 10_f64 * p_776;
-    prob[2][4] += 10_f64 * p_776;
-    prob[5][4] += 2_f64 * p_776;
-    prob[8][4] += 4_f64 * p_776;
-let p_777 = p_400 * p_1_1_4;
-    prob[0][4] += 5_f64 * p_777;
-    prob[3][4] += 4_f64 * p_777;
-    prob[8][4] += p_777;
-let p_778 = p_373 * p_7_0_4;
-    prob[0][2] += p_778;
-let p_779 = p_403 * p_1_1_4;
-    prob[0][3] += 3_f64 * p_779;
-    prob[1][3] += 3_f64 * p_779;
-    prob[8][3] += p_779;
-let p_780 = p_404 * p_1_0_4;
-    prob[0][4] += 3_f64 * p_780;
-    prob[1][4] += 10_f64 * p_780;
-    prob[6][4] += p_780;
-    prob[8][4] += 2_f64 * p_780;
-let p_781 = p_405 * p_1_1_4;
-    prob[0][4] += 5_f64 * p_781;
-    prob[2][4] += 4_f64 * p_781;
-    prob[8][4] += p_781;
-let p_782 = p_373 * p_8_0_4;
-    prob[0][3] += p_782;
-let p_783 = p_408 * p_1_1_4;
-    prob[0][4] += 5_f64 * p_783;
-    prob[1][4] += 4_f64 * p_783;
-    prob[8][4] += p_783;
-let p_784 = p_373 * p_9_1_4;
-    prob[0][4] += p_784;
-let p_785 = p_181 * p_10_0_4;
-    prob[0][0] += 2_f64 * p_785;
-    prob[1][0] += 9_f64 * p_785;
-    prob[9][0] += 3_f64 * p_785;
-let p_786 = p_182 * p_10_0_4;
-    prob[1][1] += 4_f64 * p_786;
-    prob[9][1] += p_786;
-let p_787 = p_184 * p_10_0_4;
-    prob[0][0] += 12_f64 * p_787;
-    prob[1][0] += 9_f64 * p_787;
-    prob[2][0] += 9_f64 * p_787;
-    prob[9][0] += 6_f64 * p_787;
-let p_788 = p_412 * p_1_0_4;
-    prob[0][1] += 6_f64 * p_788;
-    prob[1][1] += 12_f64 * p_788;
-    prob[2][1] += 6_f64 * p_788;
-    prob[9][1] += 6_f64 * p_788;
-let p_789 = p_412 * p_2_2_4;
-    prob[1][2] += 6_f64 * p_789;
-    prob[2][2] += p_789;
-    prob[9][2] += p_789;
-let p_790 = p_413 * p_1_1_4;
-    prob[0][1] += 4_f64 * p_790;
-    prob[2][1] += 3_f64 * p_790;
-    prob[9][1] += p_790;
-let p_791 = p_414 * p_1_0_4;
-    prob[0][2] += 4_f64 * p_791;
-    prob[1][2] += 6_f64 * p_791;
-    prob[2][2] += 6_f64 * p_791;
-    prob[9][2] += 4_f64 * p_791;
-let p_792 = p_414 * p_2_1_4;
-    prob[1][3] += 3_f64 * p_792;
-    prob[2][3] += 3_f64 * p_792;
-let p_793 = p_415 * p_2_0_4;
-    prob[1][4] += p_793;
-    prob[2][4] += 3_f64 * p_793;
-let p_794 = p_193 * p_10_0_4;
-    prob[0][0] += 6_f64 * p_794;
-    prob[3][0] += 3_f64 * p_794;
-    prob[9][0] += p_794;
-let p_795 = p_416 * p_1_1_4;
-    prob[0][1] += 8_f64 * p_795;
-    prob[1][1] += 3_f64 * p_795;
-    prob[3][1] += 3_f64 * p_795;
-    prob[9][1] += 2_f64 * p_795;
-let p_796 = p_417 * p_1_0_4;
-    prob[0][2] += 2_f64 * p_796;
-    prob[1][2] += 6_f64 * p_796;
-    prob[9][2] += 2_f64 * p_796;
-let p_797 = p_417 * p_2_2_4;
-    prob[1][3] += 6_f64 * p_797;
-    prob[3][3] += p_797;
-    prob[9][3] += p_797;
-let p_798 = p_418 * p_1_1_4;
-    prob[0][2] += 8_f64 * p_798;
-    prob[2][2] += 3_f64 * p_798;
-    prob[3][2] += 3_f64 * p_798;
-    prob[9][2] += 2_f64 * p_798;
-let p_799 = p_419 * p_1_0_4;
-    prob[0][3] += 4_f64 * p_799;
-    prob[1][3] += 6_f64 * p_799;
-    prob[2][3] += 6_f64 * p_799;
-    prob[9][3] += 4_f64 * p_799;
-let p_800 = p_419 * p_2_1_4;
-    prob[1][4] += 6_f64 * p_800;
-    prob[2][4] += 3_f64 * p_800;
-    prob[3][4] += 3_f64 * p_800;
-let p_801 = p_421 * p_1_1_4;
-    prob[0][3] += 4_f64 * p_801;
-    prob[3][3] += 3_f64 * p_801;
-    prob[9][3] += p_801;
-let p_802 = p_424 * p_1_2_4;
-    prob[0][1] += 2_f64 * p_802;
-let p_803 = p_425 * p_1_1_4;
-    prob[0][2] += 4_f64 * p_803;
-    prob[1][2] += 3_f64 * p_803;
-    prob[9][2] += p_803;
-let p_804 = p_426 * p_1_0_4;
-    prob[0][3] += 2_f64 * p_804;
-    prob[1][3] += 6_f64 * p_804;
-    prob[9][3] += 2_f64 * p_804;
-let p_805 = p_426 * p_2_2_4;
-    prob[1][4] += 6_f64 * p_805;
-    prob[4][4] += p_805;
-    prob[9][4] += p_805;
-let p_806 = p_427 * p_1_1_4;
-    prob[0][3] += 4_f64 * p_806;
-    prob[2][3] += 3_f64 * p_806;
-    prob[9][3] += p_806;
-let p_807 = p_428 * p_1_0_4;
-    prob[0][4] += 4_f64 * p_807;
-    prob[1][4] += 6_f64 * p_807;
-    prob[2][4] += 6_f64 * p_807;
-    prob[9][4] += 4_f64 * p_807;
-let p_808 = p_430 * p_1_1_4;
-    prob[0][4] += 4_f64 * p_808;
-    prob[3][4] += 3_f64 * p_808;
-    prob[9][4] += p_808;
-let p_809 = p_433 * p_1_2_4;
-    prob[0][2] += 2_f64 * p_809;
-let p_810 = p_434 * p_1_1_4;
-    prob[0][3] += 4_f64 * p_810;
-    prob[1][3] += 3_f64 * p_810;
-    prob[9][3] += p_810;
-let p_811 = p_435 * p_1_0_4;
-    prob[0][4] += 2_f64 * p_811;
-    prob[1][4] += 6_f64 * p_811;
-    prob[9][4] += 2_f64 * p_811;
-let p_812 = p_436 * p_1_1_4;
-    prob[0][4] += 4_f64 * p_812;
-    prob[2][4] += 3_f64 * p_812;
-    prob[9][4] += p_812;
-let p_813 = p_439 * p_1_2_4;
-    prob[0][3] += 2_f64 * p_813;
-let p_814 = p_440 * p_1_1_4;
-    prob[0][4] += 4_f64 * p_814;
-    prob[1][4] += 3_f64 * p_814;
-    prob[9][4] += p_814;
-let p_815 = p_443 * p_1_2_4;
-    prob[0][4] += 2_f64 * p_815;
-let p_816 = p_446 * p_1_5_5;
-let p_817 = p_447 * p_1_4_5;
-    prob[0][0] += 5_f64 * p_817;
-    prob[1][0] += p_817;
-let p_818 = p_448 * p_1_3_5;
-    prob[0][1] += 4_f64 * p_818;
-    prob[1][1] += p_818;
-let p_819 = p_449 * p_1_2_5;
-let p_820 = p_450 * p_1_1_5;
-let p_821 = p_451 * p_1_0_5;
-    prob[1][4] += p_821;
-let p_822 = p_451 * p_2_5_5;
-let p_823 = p_452 * p_1_4_5;
-    prob[0][1] += p_823;
-let p_824 = p_453 * p_1_3_5;
-    prob[0][2] += 4_f64 * p_824;
-    prob[1][2] += p_824;
-let p_825 = p_454 * p_1_2_5;
-let p_826 = p_456 * p_1_0_5;
-let p_827 = p_456 * p_2_4_5;
-let p_828 = p_457 * p_1_3_5;
-let p_829 = p_460 * p_1_0_5;
-let p_830 = p_460 * p_2_3_5;
-let p_831 = p_461 * p_1_1_5;
-let p_832 = p_462 * p_1_0_5;
-let p_833 = p_462 * p_2_2_5;
-let p_834 = p_463 * p_1_1_5;
-let p_835 = p_464 * p_1_0_5;
-let p_836 = p_464 * p_2_1_5;
-let p_837 = p_465 * p_1_0_5;
-let p_838 = p_465 * p_2_0_5;
-    prob[1][0] += p_838;
-    prob[2][0] += 5_f64 * p_838;
-let p_839 = p_465 * p_3_5_5;
-    prob[2][1] += p_839;
-let p_840 = p_466 * p_1_4_5;
-    prob[0][2] += p_840;
-let p_841 = p_467 * p_1_3_5;
-    prob[0][3] += 4_f64 * p_841;
-    prob[1][3] += p_841;
-let p_842 = p_470 * p_1_0_5;
-let p_843 = p_470 * p_2_4_5;
-let p_844 = p_472 * p_1_1_5;
-let p_845 = p_473 * p_1_0_5;
-let p_846 = p_473 * p_2_3_5;
-let p_847 = p_474 * p_1_1_5;
-let p_848 = p_475 * p_1_0_5;
-let p_849 = p_475 * p_2_2_5;
-let p_850 = p_476 * p_1_1_5;
-let p_851 = p_477 * p_1_0_5;
-let p_852 = p_477 * p_2_1_5;
-    prob[1][0] += 20_f64 * p_852;
-    prob[2][0] += 30_f64 * p_852;
-    prob[3][0] += 10_f64 * p_852;
-let p_853 = p_478 * p_1_0_5;
-    prob[2][0] += 8_f64 * p_853;
-    prob[3][0] += 2_f64 * p_853;
-let p_854 = p_478 * p_2_0_5;
-    prob[1][1] += 5_f64 * p_854;
-    prob[2][1] += 20_f64 * p_854;
-    prob[3][1] += 5_f64 * p_854;
-let p_855 = p_478 * p_3_4_5;
-    prob[2][2] += 5_f64 * p_855;
-    prob[3][2] += p_855;
-let p_856 = p_479 * p_1_1_5;
-let p_857 = p_480 * p_1_0_5;
-let p_858 = p_480 * p_2_3_5;
-let p_859 = p_481 * p_1_2_5;
-let p_860 = p_482 * p_1_1_5;
-let p_861 = p_483 * p_1_0_5;
-let p_862 = p_483 * p_2_2_5;
-    prob[1][0] += 30_f64 * p_862;
-    prob[2][0] += 10_f64 * p_862;
-    prob[3][0] += 20_f64 * p_862;
-let p_863 = p_484 * p_1_1_5;
-let p_864 = p_485 * p_1_0_5;
-    prob[1][0] += 12_f64 * p_864;
-    prob[2][0] += 26_f64 * p_864;
-    prob[3][0] += 28_f64 * p_864;
-let p_865 = p_485 * p_2_1_5;
-    prob[1][1] += 30_f64 * p_865;
-    prob[2][1] += 30_f64 * p_865;
-    prob[3][1] += 30_f64 * p_865;
-let p_866 = p_486 * p_1_0_5;
-    prob[2][1] += 7_f64 * p_866;
-    prob[3][1] += 6_f64 * p_866;
-let p_867 = p_486 * p_2_0_5;
-    prob[1][2] += 10_f64 * p_867;
-    prob[2][2] += 24_f64 * p_867;
-    prob[3][2] += 16_f64 * p_867;
-let p_868 = p_486 * p_3_3_5;
-    prob[2][3] += 4_f64 * p_868;
-    prob[3][3] += p_868;
-let p_869 = p_487 * p_1_2_5;
-let p_870 = p_488 * p_1_1_5;
-let p_871 = p_489 * p_1_0_5;
-    prob[1][0] += 8_f64 * p_871;
-    prob[3][0] += 13_f64 * p_871;
-let p_872 = p_489 * p_2_2_5;
-    prob[1][1] += 10_f64 * p_872;
-    prob[3][1] += 10_f64 * p_872;
-let p_873 = p_490 * p_1_1_5;
-    prob[2][0] += 3_f64 * p_873;
-    prob[3][0] += 9_f64 * p_873;
-let p_874 = p_491 * p_1_0_5;
-    prob[1][1] += 4_f64 * p_874;
-    prob[2][1] += 6_f64 * p_874;
-    prob[3][1] += 18_f64 * p_874;
-let p_875 = p_491 * p_2_1_5;
-    prob[1][2] += 16_f64 * p_875;
-    prob[2][2] += 6_f64 * p_875;
-    prob[3][2] += 18_f64 * p_875;
-let p_876 = p_492 * p_1_0_5;
-    prob[2][2] += 6_f64 * p_876;
-    prob[3][2] += 11_f64 * p_876;
-let p_877 = p_492 * p_2_0_5;
-    prob[1][3] += 6_f64 * p_877;
-    prob[2][3] += 12_f64 * p_877;
-    prob[3][3] += 12_f64 * p_877;
-let p_878 = p_493 * p_1_1_5;
-    prob[3][1] += p_878;
-let p_879 = p_494 * p_1_0_5;
-    prob[1][2] += p_879;
-    prob[3][2] += 4_f64 * p_879;
-let p_880 = p_494 * p_2_1_5;
-    prob[1][3] += 4_f64 * p_880;
-    prob[3][3] += 6_f64 * p_880;
-let p_881 = p_495 * p_1_0_5;
-    prob[2][3] += 2_f64 * p_881;
-    prob[3][3] += 6_f64 * p_881;
-let p_882 = p_497 * p_1_4_5;
-    prob[0][3] += p_882;
-let p_883 = p_498 * p_1_3_5;
-    prob[0][4] += 4_f64 * p_883;
-    prob[1][4] += p_883;
-let p_884 = p_500 * p_1_1_5;
-let p_885 = p_501 * p_1_0_5;
-let p_886 = p_501 * p_2_4_5;
-let p_887 = p_503 * p_1_1_5;
-let p_888 = p_504 * p_1_0_5;
-let p_889 = p_505 * p_1_2_5;
-let p_890 = p_506 * p_1_1_5;
-let p_891 = p_507 * p_2_2_5;
-    prob[1][0] += 30_f64 * p_891;
-    prob[2][0] += 20_f64 * p_891;
-    prob[4][0] += 10_f64 * p_891;
-let p_892 = p_508 * p_2_0_5;
-    prob[1][0] += 8_f64 * p_892;
-    prob[2][0] += 28_f64 * p_892;
-    prob[4][0] += 10_f64 * p_892;
-let p_893 = p_507 * p_3_2_5;
-    prob[1][1] += 20_f64 * p_893;
-    prob[2][1] += 30_f64 * p_893;
-    prob[4][1] += 10_f64 * p_893;
-let p_894 = p_509 * p_1_0_5;
-    prob[2][1] += 6_f64 * p_894;
-    prob[4][1] += 2_f64 * p_894;
-let p_895 = p_509 * p_2_0_5;
-    prob[1][2] += 5_f64 * p_895;
-    prob[2][2] += 16_f64 * p_895;
-    prob[4][2] += 4_f64 * p_895;
-let p_896 = p_509 * p_3_4_5;
-    prob[2][3] += p_896;
-let p_897 = p_510 * p_1_3_5;
-let p_898 = p_511 * p_1_2_5;
-let p_899 = p_512 * p_1_1_5;
-let p_900 = p_513 * p_2_3_5;
-    prob[1][0] += 20_f64 * p_900;
-    prob[3][0] += 5_f64 * p_900;
-    prob[4][0] += 5_f64 * p_900;
-let p_901 = p_514 * p_1_2_5;
-let p_902 = p_515 * p_2_1_5;
-    prob[1][0] += 52_f64 * p_902;
-    prob[2][0] += 26_f64 * p_902;
-    prob[3][0] += 30_f64 * p_902;
-    prob[4][0] += 30_f64 * p_902;
-let p_903 = p_513 * p_3_0_5;
-    prob[1][1] += 60_f64 * p_903;
-    prob[2][1] += 20_f64 * p_903;
-    prob[3][1] += 20_f64 * p_903;
-    prob[4][1] += 20_f64 * p_903;
-let p_904 = p_514 * p_3_1_5;
-    prob[2][0] += 14_f64 * p_904;
-    prob[3][0] += 7_f64 * p_904;
-    prob[4][0] += 9_f64 * p_904;
-let p_905 = p_516 * p_1_0_5;
-    prob[1][1] += 14_f64 * p_905;
-    prob[2][1] += 32_f64 * p_905;
-    prob[3][1] += 18_f64 * p_905;
-    prob[4][1] += 20_f64 * p_905;
-let p_906 = p_516 * p_2_1_5;
-    prob[1][2] += 48_f64 * p_906;
-    prob[2][2] += 36_f64 * p_906;
-    prob[3][2] += 18_f64 * p_906;
-    prob[4][2] += 18_f64 * p_906;
-let p_907 = p_517 * p_1_0_5;
-    prob[2][2] += 18_f64 * p_907;
-    prob[3][2] += 6_f64 * p_907;
-    prob[4][2] += 8_f64 * p_907;
-let p_908 = p_517 * p_2_0_5;
-    prob[1][3] += 8_f64 * p_908;
-    prob[2][3] += 24_f64 * p_908;
-    prob[3][3] += 4_f64 * p_908;
-    prob[4][3] += 4_f64 * p_908;
-let p_909 = p_517 * p_3_3_5;
-    prob[2][4] += 4_f64 * p_909;
-    prob[3][4] += p_909;
-let p_910 = p_518 * p_2_0_5;
-    prob[1][0] += 7_f64 * p_910;
-    prob[3][0] += 14_f64 * p_910;
-    prob[4][0] += 9_f64 * p_910;
-let p_911 = p_519 * p_1_0_5;
-    prob[1][1] += 16_f64 * p_911;
-    prob[3][1] += 18_f64 * p_911;
-    prob[4][1] += 11_f64 * p_911;
-let p_912 = p_519 * p_2_2_5;
-    prob[1][2] += 18_f64 * p_912;
-    prob[3][2] += 8_f64 * p_912;
-    prob[4][2] += 4_f64 * p_912;
-let p_913 = p_520 * p_1_1_5;
-    prob[2][1] += 3_f64 * p_913;
-    prob[3][1] += 6_f64 * p_913;
-    prob[4][1] += 3_f64 * p_913;
-let p_914 = p_521 * p_1_0_5;
-    prob[1][2] += 16_f64 * p_914;
-    prob[2][2] += 12_f64 * p_914;
-    prob[3][2] += 28_f64 * p_914;
-    prob[4][2] += 16_f64 * p_914;
-let p_915 = p_521 * p_2_1_5;
-    prob[1][3] += 36_f64 * p_915;
-    prob[2][3] += 18_f64 * p_915;
-    prob[3][3] += 24_f64 * p_915;
-    prob[4][3] += 12_f64 * p_915;
-let p_916 = p_522 * p_1_0_5;
-    prob[2][3] += 14_f64 * p_916;
-    prob[3][3] += 10_f64 * p_916;
-    prob[4][3] += 6_f64 * p_916;
-let p_917 = p_522 * p_2_0_5;
-    prob[1][4] += 6_f64 * p_917;
-    prob[2][4] += 12_f64 * p_917;
-    prob[3][4] += 12_f64 * p_917;
-let p_918 = p_523 * p_1_1_5;
-    prob[3][2] += 3_f64 * p_918;
-    prob[4][2] += p_918;
-let p_919 = p_524 * p_1_0_5;
-    prob[1][3] += 6_f64 * p_919;
-    prob[3][3] += 14_f64 * p_919;
-    prob[4][3] += 6_f64 * p_919;
-let p_920 = p_524 * p_2_1_5;
-    prob[1][4] += 4_f64 * p_920;
-    prob[3][4] += 6_f64 * p_920;
-let p_921 = p_525 * p_1_0_5;
-    prob[2][4] += 2_f64 * p_921;
-    prob[3][4] += 6_f64 * p_921;
-let p_922 = p_527 * p_1_3_5;
-let p_923 = p_528 * p_1_2_5;
-let p_924 = p_529 * p_2_2_5;
-    prob[1][0] += 13_f64 * p_924;
-    prob[4][0] += 10_f64 * p_924;
-let p_925 = p_501 * p_5_1_5;
-    prob[1][1] += 10_f64 * p_925;
-    prob[4][1] += 5_f64 * p_925;
-let p_926 = p_530 * p_2_0_5;
-    prob[1][0] += 5_f64 * p_926;
-    prob[2][0] += 5_f64 * p_926;
-    prob[4][0] += 14_f64 * p_926;
-let p_927 = p_531 * p_1_0_5;
-    prob[1][1] += 14_f64 * p_927;
-    prob[2][1] += 7_f64 * p_927;
-    prob[4][1] += 18_f64 * p_927;
-let p_928 = p_531 * p_2_2_5;
-    prob[1][2] += 18_f64 * p_928;
-    prob[2][2] += 4_f64 * p_928;
-    prob[4][2] += 8_f64 * p_928;
-let p_929 = p_532 * p_1_1_5;
-    prob[2][1] += 3_f64 * p_929;
-    prob[4][1] += 3_f64 * p_929;
-let p_930 = p_533 * p_1_0_5;
-    prob[1][2] += 7_f64 * p_930;
-    prob[2][2] += 12_f64 * p_930;
-    prob[4][2] += 14_f64 * p_930;
-let p_931 = p_533 * p_2_1_5;
-    prob[1][3] += 12_f64 * p_931;
-    prob[2][3] += 12_f64 * p_931;
-    prob[4][3] += 6_f64 * p_931;
-let p_932 = p_534 * p_1_0_5;
-    prob[2][3] += 5_f64 * p_932;
-    prob[4][3] += 2_f64 * p_932;
-let p_933 = p_534 * p_2_0_5;
-    prob[1][4] += 4_f64 * p_933;
-    prob[2][4] += 12_f64 * p_933;
-    prob[4][4] += 4_f64 * p_933;
-let p_934 = p_527 * p_4_0_5;
-    prob[3][0] += p_934;
-    prob[4][0] += 2_f64 * p_934;
-let p_935 = p_535 * p_1_1_5;
-    prob[1][1] += 3_f64 * p_935;
-    prob[3][1] += 3_f64 * p_935;
-    prob[4][1] += 6_f64 * p_935;
-let p_936 = p_536 * p_1_0_5;
-    prob[1][2] += 12_f64 * p_936;
-    prob[3][2] += 5_f64 * p_936;
-    prob[4][2] += 10_f64 * p_936;
-let p_937 = p_536 * p_2_2_5;
-    prob[1][3] += 18_f64 * p_937;
-    prob[3][3] += 4_f64 * p_937;
-    prob[4][3] += 8_f64 * p_937;
-let p_938 = p_537 * p_1_1_5;
-    prob[2][2] += 3_f64 * p_938;
-    prob[3][2] += 3_f64 * p_938;
-    prob[4][2] += 6_f64 * p_938;
-let p_939 = p_538 * p_1_0_5;
-    prob[1][3] += 12_f64 * p_939;
-    prob[2][3] += 12_f64 * p_939;
-    prob[3][3] += 10_f64 * p_939;
-    prob[4][3] += 20_f64 * p_939;
-let p_940 = p_538 * p_2_1_5;
-    prob[1][4] += 24_f64 * p_940;
-    prob[2][4] += 12_f64 * p_940;
-    prob[3][4] += 12_f64 * p_940;
-    prob[4][4] += 12_f64 * p_940;
-let p_941 = p_539 * p_1_0_5;
-    prob[2][4] += 10_f64 * p_941;
-    prob[3][4] += 5_f64 * p_941;
-    prob[4][4] += 6_f64 * p_941;
-let p_942 = p_540 * p_1_1_5;
-    prob[3][3] += 3_f64 * p_942;
-    prob[4][3] += 3_f64 * p_942;
-let p_943 = p_541 * p_1_0_5;
-    prob[1][4] += 5_f64 * p_943;
-    prob[3][4] += 10_f64 * p_943;
-    prob[4][4] += 6_f64 * p_943;
-let p_944 = p_544 * p_1_1_5;
-    prob[1][2] += p_944;
-    prob[4][2] += 2_f64 * p_944;
-let p_945 = p_545 * p_1_0_5;
-    prob[1][3] += 4_f64 * p_945;
-    prob[4][3] += 5_f64 * p_945;
-let p_946 = p_545 * p_2_2_5;
-    prob[1][4] += 6_f64 * p_946;
-    prob[4][4] += 4_f64 * p_946;
-let p_947 = p_546 * p_1_1_5;
-    prob[2][3] += p_947;
-    prob[4][3] += 2_f64 * p_947;
-let p_948 = p_547 * p_1_0_5;
-    prob[1][4] += 4_f64 * p_948;
-    prob[2][4] += 4_f64 * p_948;
-    prob[4][4] += 10_f64 * p_948;
-let p_949 = p_549 * p_1_1_5;
-    prob[3][4] += p_949;
-    prob[4][4] += 2_f64 * p_949;
-let p_950 = p_552 * p_1_4_5;
-    prob[0][4] += p_950;
-let p_951 = p_553 * p_1_3_5;
-let p_952 = p_554 * p_1_2_5;
-let p_953 = p_555 * p_1_1_5;
-let p_954 = p_557 * p_1_3_5;
-let p_955 = p_558 * p_1_2_5;
-let p_956 = p_556 * p_3_0_5;
-    prob[1][0] += 20_f64 * p_956;
-    prob[2][0] += 5_f64 * p_956;
-    prob[5][0] += 5_f64 * p_956;
-let p_957 = p_559 * p_3_1_5;
-    prob[0][0] += 4_f64 * p_957;
-    prob[1][0] += 29_f64 * p_957;
-    prob[2][0] += 31_f64 * p_957;
-    prob[5][0] += 17_f64 * p_957;
-let p_958 = p_460 * p_6_0_5;
-    prob[1][1] += 30_f64 * p_958;
-    prob[2][1] += 20_f64 * p_958;
-    prob[5][1] += 10_f64 * p_958;
-let p_959 = p_560 * p_3_2_5;
-    prob[2][0] += 7_f64 * p_959;
-    prob[5][0] += 3_f64 * p_959;
-let p_960 = p_561 * p_1_0_5;
-    prob[0][1] += 2_f64 * p_960;
-    prob[1][1] += 8_f64 * p_960;
-    prob[2][1] += 20_f64 * p_960;
-    prob[5][1] += 8_f64 * p_960;
-let p_961 = p_561 * p_2_1_5;
-    prob[1][2] += 16_f64 * p_961;
-    prob[2][2] += 18_f64 * p_961;
-    prob[5][2] += 6_f64 * p_961;
-let p_962 = p_562 * p_1_0_5;
-    prob[2][2] += 6_f64 * p_962;
-    prob[5][2] += 2_f64 * p_962;
-let p_963 = p_562 * p_2_0_5;
-    prob[1][3] += p_963;
-    prob[2][3] += 4_f64 * p_963;
-let p_964 = p_562 * p_3_4_5;
-    prob[2][4] += p_964;
-let p_965 = p_563 * p_1_3_5;
-let p_966 = p_555 * p_4_0_5;
-    prob[1][0] += 23_f64 * p_966;
-    prob[3][0] += 9_f64 * p_966;
-    prob[5][0] += 10_f64 * p_966;
-let p_967 = p_556 * p_4_0_5;
-    prob[1][1] += 20_f64 * p_967;
-    prob[3][1] += 5_f64 * p_967;
-    prob[5][1] += 5_f64 * p_967;
-let p_968 = p_564 * p_3_0_5;
-    prob[0][0] += 8_f64 * p_968;
-    prob[1][0] += 18_f64 * p_968;
-    prob[2][0] += 18_f64 * p_968;
-    prob[3][0] += 16_f64 * p_968;
-    prob[5][0] += 18_f64 * p_968;
-let p_969 = p_565 * p_1_0_5;
-    prob[0][1] += 4_f64 * p_969;
-    prob[1][1] += 36_f64 * p_969;
-    prob[2][1] += 22_f64 * p_969;
-    prob[3][1] += 18_f64 * p_969;
-    prob[5][1] += 22_f64 * p_969;
-let p_970 = p_565 * p_2_2_5;
-    prob[1][2] += 36_f64 * p_970;
-    prob[2][2] += 8_f64 * p_970;
-    prob[3][2] += 8_f64 * p_970;
-    prob[5][2] += 8_f64 * p_970;
-let p_971 = p_566 * p_1_1_5;
-    prob[0][1] += 2_f64 * p_971;
-    prob[2][1] += 4_f64 * p_971;
-    prob[3][1] += 3_f64 * p_971;
-    prob[5][1] += 3_f64 * p_971;
-let p_972 = p_567 * p_1_0_5;
-    prob[0][2] += 4_f64 * p_972;
-    prob[1][2] += 22_f64 * p_972;
-    prob[2][2] += 30_f64 * p_972;
-    prob[3][2] += 13_f64 * p_972;
-    prob[5][2] += 18_f64 * p_972;
-let p_973 = p_567 * p_2_1_5;
-    prob[1][3] += 24_f64 * p_973;
-    prob[2][3] += 24_f64 * p_973;
-    prob[3][3] += 6_f64 * p_973;
-    prob[5][3] += 6_f64 * p_973;
-let p_974 = p_568 * p_1_0_5;
-    prob[2][3] += 8_f64 * p_974;
-    prob[3][3] += p_974;
-    prob[5][3] += 2_f64 * p_974;
-let p_975 = p_568 * p_2_0_5;
-    prob[1][4] += 4_f64 * p_975;
-    prob[2][4] += 12_f64 * p_975;
-    prob[3][4] += 4_f64 * p_975;
-let p_976 = p_563 * p_4_1_5;
-    prob[0][0] += 4_f64 * p_976;
-    prob[3][0] += 4_f64 * p_976;
-    prob[5][0] += p_976;
-let p_977 = p_569 * p_1_1_5;
-    prob[0][1] += 4_f64 * p_977;
-    prob[1][1] += 2_f64 * p_977;
-    prob[3][1] += 6_f64 * p_977;
-    prob[5][1] += 3_f64 * p_977;
-let p_978 = p_570 * p_1_0_5;
-    prob[1][2] += 11_f64 * p_978;
-    prob[3][2] += 8_f64 * p_978;
-    prob[5][2] += 5_f64 * p_978;
-let p_979 = p_570 * p_2_2_5;
-    prob[1][3] += 18_f64 * p_979;
-    prob[3][3] += 8_f64 * p_979;
-    prob[5][3] += 4_f64 * p_979;
-let p_980 = p_571 * p_1_1_5;
-    prob[0][2] += 4_f64 * p_980;
-    prob[2][2] += 3_f64 * p_980;
-    prob[3][2] += 8_f64 * p_980;
-    prob[5][2] += 3_f64 * p_980;
-let p_981 = p_572 * p_1_0_5;
-    prob[0][3] += 2_f64 * p_981;
-    prob[1][3] += 15_f64 * p_981;
-    prob[2][3] += 16_f64 * p_981;
-    prob[3][3] += 18_f64 * p_981;
-    prob[5][3] += 12_f64 * p_981;
-let p_982 = p_572 * p_2_1_5;
-    prob[1][4] += 12_f64 * p_982;
-    prob[2][4] += 6_f64 * p_982;
-    prob[3][4] += 12_f64 * p_982;
-let p_983 = p_573 * p_1_0_5;
-    prob[2][4] += 4_f64 * p_983;
-    prob[3][4] += 5_f64 * p_983;
-let p_984 = p_574 * p_1_1_5;
-    prob[0][3] += 2_f64 * p_984;
-    prob[3][3] += 4_f64 * p_984;
-    prob[5][3] += p_984;
-let p_985 = p_575 * p_1_0_5;
-    prob[1][4] += p_985;
-    prob[3][4] += 4_f64 * p_985;
-let p_986 = p_554 * p_5_0_5;
-    prob[0][0] += 6_f64 * p_986;
-    prob[1][0] += 13_f64 * p_986;
-    prob[4][0] += 13_f64 * p_986;
-    prob[5][0] += 10_f64 * p_986;
-let p_987 = p_578 * p_1_0_5;
-    prob[0][1] += p_987;
-    prob[1][1] += 14_f64 * p_987;
-    prob[4][1] += 8_f64 * p_987;
-    prob[5][1] += 7_f64 * p_987;
-let p_988 = p_578 * p_2_3_5;
-    prob[1][2] += 8_f64 * p_988;
-    prob[4][2] += p_988;
-    prob[5][2] += p_988;
-let p_989 = p_577 * p_3_0_5;
-    prob[0][0] += 6_f64 * p_989;
-    prob[2][0] += 3_f64 * p_989;
-    prob[4][0] += 8_f64 * p_989;
-    prob[5][0] += 5_f64 * p_989;
-let p_990 = p_579 * p_1_1_5;
-    prob[0][1] += 6_f64 * p_990;
-    prob[1][1] += 6_f64 * p_990;
-    prob[2][1] += 6_f64 * p_990;
-    prob[4][1] += 12_f64 * p_990;
-    prob[5][1] += 10_f64 * p_990;
-let p_991 = p_580 * p_1_0_5;
-    prob[0][2] += 3_f64 * p_991;
-    prob[1][2] += 24_f64 * p_991;
-    prob[2][2] += 10_f64 * p_991;
-    prob[4][2] += 10_f64 * p_991;
-    prob[5][2] += 11_f64 * p_991;
-let p_992 = p_580 * p_2_2_5;
-    prob[1][3] += 24_f64 * p_992;
-    prob[2][3] += 8_f64 * p_992;
-    prob[4][3] += 4_f64 * p_992;
-    prob[5][3] += 4_f64 * p_992;
-let p_993 = p_581 * p_1_1_5;
-    prob[0][2] += 2_f64 * p_993;
-    prob[2][2] += 6_f64 * p_993;
-    prob[4][2] += 7_f64 * p_993;
-    prob[5][2] += 5_f64 * p_993;
-let p_994 = p_582 * p_1_0_5;
-    prob[0][3] += 2_f64 * p_994;
-    prob[1][3] += 10_f64 * p_994;
-    prob[2][3] += 20_f64 * p_994;
-    prob[4][3] += 4_f64 * p_994;
-    prob[5][3] += 6_f64 * p_994;
-let p_995 = p_582 * p_2_1_5;
-    prob[1][4] += 24_f64 * p_995;
-    prob[2][4] += 24_f64 * p_995;
-    prob[4][4] += 6_f64 * p_995;
-    prob[5][4] += 6_f64 * p_995;
-let p_996 = p_583 * p_1_0_5;
-    prob[2][4] += 10_f64 * p_996;
-    prob[4][4] += 2_f64 * p_996;
-    prob[5][4] += 2_f64 * p_996;
-let p_997 = p_584 * p_1_2_5;
-    prob[0][1] += 3_f64 * p_997;
-    prob[3][1] += p_997;
-    prob[4][1] += 2_f64 * p_997;
-    prob[5][1] += p_997;
-let p_998 = p_585 * p_1_1_5;
-    prob[0][2] += 4_f64 * p_998;
-    prob[1][2] += 6_f64 * p_998;
-    prob[3][2] += 5_f64 * p_998;
-    prob[4][2] += 8_f64 * p_998;
-    prob[5][2] += 6_f64 * p_998;
-let p_999 = p_586 * p_1_0_5;
-    prob[0][3] += p_999;
-    prob[1][3] += 24_f64 * p_999;
-    prob[3][3] += 10_f64 * p_999;
-    prob[4][3] += 12_f64 * p_999;
-    prob[5][3] += 11_f64 * p_999;
-let p_1000 = p_586 * p_2_2_5;
-    prob[1][4] += 24_f64 * p_1000;
-    prob[3][4] += 8_f64 * p_1000;
-    prob[4][4] += 4_f64 * p_1000;
-    prob[5][4] += 4_f64 * p_1000;
-let p_1001 = p_587 * p_1_1_5;
-    prob[0][3] += 4_f64 * p_1001;
-    prob[2][3] += 6_f64 * p_1001;
-    prob[3][3] += 5_f64 * p_1001;
-    prob[4][3] += 8_f64 * p_1001;
-    prob[5][3] += 6_f64 * p_1001;
-let p_1002 = p_588 * p_1_0_5;
-    prob[0][4] += 2_f64 * p_1002;
-    prob[1][4] += 20_f64 * p_1002;
-    prob[2][4] += 20_f64 * p_1002;
-    prob[3][4] += 20_f64 * p_1002;
-    prob[4][4] += 10_f64 * p_1002;
-    prob[5][4] += 12_f64 * p_1002;
-let p_1003 = p_590 * p_1_1_5;
-    prob[0][4] += 2_f64 * p_1003;
-    prob[3][4] += 5_f64 * p_1003;
-    prob[4][4] += p_1003;
-    prob[5][4] += p_1003;
-let p_1004 = p_592 * p_1_2_5;
-    prob[0][2] += p_1004;
-    prob[4][2] += 2_f64 * p_1004;
-    prob[5][2] += p_1004;
-let p_1005 = p_593 * p_1_1_5;
-    prob[0][3] += 2_f64 * p_1005;
-    prob[1][3] += 3_f64 * p_1005;
-    prob[4][3] += 8_f64 * p_1005;
-    prob[5][3] += 4_f64 * p_1005;
-let p_1006 = p_594 * p_1_0_5;
-    prob[0][4] += p_1006;
-    prob[1][4] += 12_f64 * p_1006;
-    prob[4][4] += 12_f64 * p_1006;
-    prob[5][4] += 6_f64 * p_1006;
-let p_1007 = p_595 * p_1_1_5;
-    prob[0][4] += 2_f64 * p_1007;
-    prob[2][4] += 3_f64 * p_1007;
-    prob[4][4] += 8_f64 * p_1007;
-    prob[5][4] += 4_f64 * p_1007;
-let p_1008 = p_553 * p_6_1_5;
-    prob[1][0] += p_1008;
-    prob[5][0] += 5_f64 * p_1008;
-let p_1009 = p_598 * p_1_1_5;
-    prob[0][1] += 3_f64 * p_1009;
-    prob[1][1] += 4_f64 * p_1009;
-    prob[5][1] += 4_f64 * p_1009;
-let p_1010 = p_599 * p_1_0_5;
-    prob[1][2] += 3_f64 * p_1010;
-    prob[5][2] += p_1010;
-let p_1011 = p_599 * p_2_3_5;
-    prob[1][3] += 4_f64 * p_1011;
-    prob[5][3] += p_1011;
-let p_1012 = p_600 * p_1_2_5;
-    prob[0][1] += 3_f64 * p_1012;
-    prob[2][1] += p_1012;
-    prob[5][1] += p_1012;
-let p_1013 = p_601 * p_1_1_5;
-    prob[0][2] += 2_f64 * p_1013;
-    prob[1][2] += 5_f64 * p_1013;
-    prob[2][2] += 4_f64 * p_1013;
-    prob[5][2] += 6_f64 * p_1013;
-let p_1014 = p_602 * p_1_0_5;
-    prob[0][3] += 2_f64 * p_1014;
-    prob[1][3] += 10_f64 * p_1014;
-    prob[2][3] += 7_f64 * p_1014;
-    prob[5][3] += 5_f64 * p_1014;
-let p_1015 = p_602 * p_2_2_5;
-    prob[1][4] += 12_f64 * p_1015;
-    prob[2][4] += 4_f64 * p_1015;
-    prob[5][4] += 4_f64 * p_1015;
-let p_1016 = p_603 * p_1_1_5;
-    prob[2][3] += 2_f64 * p_1016;
-    prob[5][3] += p_1016;
-let p_1017 = p_604 * p_1_0_5;
-    prob[0][4] += 2_f64 * p_1017;
-    prob[1][4] += 7_f64 * p_1017;
-    prob[2][4] += 12_f64 * p_1017;
-    prob[5][4] += 6_f64 * p_1017;
-let p_1018 = p_606 * p_1_2_5;
-    prob[0][2] += 2_f64 * p_1018;
-    prob[3][2] += p_1018;
-    prob[5][2] += p_1018;
-let p_1019 = p_607 * p_1_1_5;
-    prob[0][3] += 2_f64 * p_1019;
-    prob[1][3] += 3_f64 * p_1019;
-    prob[3][3] += 3_f64 * p_1019;
-    prob[5][3] += 6_f64 * p_1019;
-let p_1020 = p_608 * p_1_0_5;
-    prob[1][4] += 8_f64 * p_1020;
-    prob[3][4] += 5_f64 * p_1020;
-    prob[5][4] += 5_f64 * p_1020;
-let p_1021 = p_609 * p_1_1_5;
-    prob[0][4] += 2_f64 * p_1021;
-    prob[2][4] += 2_f64 * p_1021;
-    prob[3][4] += 3_f64 * p_1021;
-    prob[5][4] += 2_f64 * p_1021;
-let p_1022 = p_612 * p_1_2_5;
-    prob[0][3] += p_1022;
-    prob[4][3] += 2_f64 * p_1022;
-    prob[5][3] += 2_f64 * p_1022;
-let p_1023 = p_613 * p_1_1_5;
-    prob[0][4] += 2_f64 * p_1023;
-    prob[1][4] += 3_f64 * p_1023;
-    prob[4][4] += 6_f64 * p_1023;
-    prob[5][4] += 8_f64 * p_1023;
-let p_1024 = p_616 * p_1_2_5;
-    prob[5][4] += p_1024;
-let p_1025 = p_618 * p_1_4_5;
-let p_1026 = p_619 * p_1_3_5;
-let p_1027 = p_451 * p_7_0_5;
-    prob[1][0] += 5_f64 * p_1027;
-    prob[6][0] += p_1027;
-let p_1028 = p_455 * p_7_0_5;
-    prob[0][0] += 4_f64 * p_1028;
-    prob[1][0] += 33_f64 * p_1028;
-    prob[2][0] += 11_f64 * p_1028;
-    prob[6][0] += 12_f64 * p_1028;
-let p_1029 = p_456 * p_7_0_5;
-    prob[1][1] += 20_f64 * p_1029;
-    prob[2][1] += 5_f64 * p_1029;
-    prob[6][1] += 5_f64 * p_1029;
-let p_1030 = p_458 * p_7_0_5;
-    prob[0][0] += 8_f64 * p_1030;
-    prob[1][0] += 11_f64 * p_1030;
-    prob[2][0] += 20_f64 * p_1030;
-    prob[6][0] += 9_f64 * p_1030;
-let p_1031 = p_622 * p_1_0_5;
-    prob[0][1] += 5_f64 * p_1031;
-    prob[1][1] += 21_f64 * p_1031;
-    prob[2][1] += 19_f64 * p_1031;
-    prob[6][1] += 12_f64 * p_1031;
-let p_1032 = p_622 * p_2_2_5;
-    prob[1][2] += 18_f64 * p_1032;
-    prob[2][2] += 8_f64 * p_1032;
-    prob[6][2] += 4_f64 * p_1032;
-let p_1033 = p_623 * p_1_1_5;
-    prob[0][1] += 2_f64 * p_1033;
-    prob[2][1] += 3_f64 * p_1033;
-    prob[6][1] += p_1033;
-let p_1034 = p_624 * p_1_0_5;
-    prob[0][2] += 2_f64 * p_1034;
-    prob[1][2] += 8_f64 * p_1034;
-    prob[2][2] += 15_f64 * p_1034;
-    prob[6][2] += 6_f64 * p_1034;
-let p_1035 = p_624 * p_2_1_5;
-    prob[1][3] += 4_f64 * p_1035;
-    prob[2][3] += 6_f64 * p_1035;
-let p_1036 = p_625 * p_1_0_5;
-    prob[2][3] += p_1036;
-let p_1037 = p_625 * p_2_0_5;
-    prob[1][4] += p_1037;
-    prob[2][4] += 4_f64 * p_1037;
-let p_1038 = p_620 * p_4_0_5;
-    prob[0][0] += 10_f64 * p_1038;
-    prob[1][0] += 21_f64 * p_1038;
-    prob[3][0] += 14_f64 * p_1038;
-    prob[6][0] += 12_f64 * p_1038;
-let p_1039 = p_626 * p_1_0_5;
-    prob[0][1] += 3_f64 * p_1039;
-    prob[1][1] += 19_f64 * p_1039;
-    prob[3][1] += 7_f64 * p_1039;
-    prob[6][1] += 9_f64 * p_1039;
-let p_1040 = p_626 * p_2_3_5;
-    prob[1][2] += 8_f64 * p_1040;
-    prob[3][2] += p_1040;
-    prob[6][2] += p_1040;
-let p_1041 = p_621 * p_4_0_5;
-    prob[0][0] += 14_f64 * p_1041;
-    prob[2][0] += 5_f64 * p_1041;
-    prob[3][0] += 10_f64 * p_1041;
-    prob[6][0] += 5_f64 * p_1041;
-let p_1042 = p_627 * p_1_1_5;
-    prob[0][1] += 14_f64 * p_1042;
-    prob[1][1] += 6_f64 * p_1042;
-    prob[2][1] += 8_f64 * p_1042;
-    prob[3][1] += 14_f64 * p_1042;
-    prob[6][1] += 10_f64 * p_1042;
-let p_1043 = p_628 * p_1_0_5;
-    prob[0][2] += 5_f64 * p_1043;
-    prob[1][2] += 32_f64 * p_1043;
-    prob[2][2] += 10_f64 * p_1043;
-    prob[3][2] += 10_f64 * p_1043;
-    prob[6][2] += 13_f64 * p_1043;
-let p_1044 = p_628 * p_2_2_5;
-    prob[1][3] += 24_f64 * p_1044;
-    prob[2][3] += 8_f64 * p_1044;
-    prob[3][3] += 4_f64 * p_1044;
-    prob[6][3] += 4_f64 * p_1044;
-let p_1045 = p_629 * p_1_1_5;
-    prob[0][2] += 8_f64 * p_1045;
-    prob[2][2] += 6_f64 * p_1045;
-    prob[3][2] += 7_f64 * p_1045;
-    prob[6][2] += 5_f64 * p_1045;
-let p_1046 = p_630 * p_1_0_5;
-    prob[0][3] += 2_f64 * p_1046;
-    prob[1][3] += 10_f64 * p_1046;
-    prob[2][3] += 18_f64 * p_1046;
-    prob[3][3] += 3_f64 * p_1046;
-    prob[6][3] += 6_f64 * p_1046;
-let p_1047 = p_630 * p_2_1_5;
-    prob[1][4] += 12_f64 * p_1047;
-    prob[2][4] += 12_f64 * p_1047;
-    prob[3][4] += 6_f64 * p_1047;
-let p_1048 = p_631 * p_1_0_5;
-    prob[2][4] += 3_f64 * p_1048;
-    prob[3][4] += p_1048;
-let p_1049 = p_632 * p_1_2_5;
-    prob[0][1] += 3_f64 * p_1049;
-    prob[3][1] += 3_f64 * p_1049;
-    prob[6][1] += p_1049;
-let p_1050 = p_633 * p_1_1_5;
-    prob[0][2] += 4_f64 * p_1050;
-    prob[1][2] += 2_f64 * p_1050;
-    prob[3][2] += 7_f64 * p_1050;
-    prob[6][2] += 4_f64 * p_1050;
-let p_1051 = p_634 * p_1_0_5;
-    prob[0][3] += 2_f64 * p_1051;
-    prob[1][3] += 15_f64 * p_1051;
-    prob[3][3] += 10_f64 * p_1051;
-    prob[6][3] += 7_f64 * p_1051;
-let p_1052 = p_634 * p_2_2_5;
-    prob[1][4] += 6_f64 * p_1052;
-    prob[3][4] += 4_f64 * p_1052;
-let p_1053 = p_635 * p_1_1_5;
-    prob[0][3] += 6_f64 * p_1053;
-    prob[2][3] += 4_f64 * p_1053;
-    prob[3][3] += 9_f64 * p_1053;
-    prob[6][3] += 4_f64 * p_1053;
-let p_1054 = p_636 * p_1_0_5;
-    prob[1][4] += 3_f64 * p_1054;
-    prob[2][4] += 4_f64 * p_1054;
-    prob[3][4] += 8_f64 * p_1054;
-let p_1055 = p_638 * p_1_1_5;
-    prob[3][4] += p_1055;
-let p_1056 = p_619 * p_5_0_5;
-    prob[0][0] += 6_f64 * p_1056;
-    prob[1][0] += 3_f64 * p_1056;
-    prob[4][0] += 8_f64 * p_1056;
-    prob[6][0] += 5_f64 * p_1056;
-let p_1057 = p_640 * p_1_1_5;
-    prob[0][1] += 6_f64 * p_1057;
-    prob[1][1] += 9_f64 * p_1057;
-    prob[4][1] += 6_f64 * p_1057;
-    prob[6][1] += 5_f64 * p_1057;
-let p_1058 = p_641 * p_1_0_5;
-    prob[0][2] += p_1058;
-    prob[1][2] += 10_f64 * p_1058;
-    prob[4][2] += p_1058;
-    prob[6][2] += 2_f64 * p_1058;
-let p_1059 = p_641 * p_2_3_5;
-    prob[1][3] += 8_f64 * p_1059;
-    prob[4][3] += p_1059;
-    prob[6][3] += p_1059;
-let p_1060 = p_642 * p_1_2_5;
-    prob[0][1] += 6_f64 * p_1060;
-    prob[2][1] += 2_f64 * p_1060;
-    prob[4][1] += 2_f64 * p_1060;
-    prob[6][1] += p_1060;
-let p_1061 = p_643 * p_1_1_5;
-    prob[0][2] += 6_f64 * p_1061;
-    prob[1][2] += 6_f64 * p_1061;
-    prob[2][2] += 5_f64 * p_1061;
-    prob[4][2] += 6_f64 * p_1061;
-    prob[6][2] += 6_f64 * p_1061;
-let p_1062 = p_644 * p_1_0_5;
-    prob[0][3] += 3_f64 * p_1062;
-    prob[1][3] += 20_f64 * p_1062;
-    prob[2][3] += 10_f64 * p_1062;
-    prob[4][3] += 3_f64 * p_1062;
-    prob[6][3] += 6_f64 * p_1062;
-let p_1063 = p_644 * p_2_2_5;
-    prob[1][4] += 24_f64 * p_1063;
-    prob[2][4] += 8_f64 * p_1063;
-    prob[4][4] += 4_f64 * p_1063;
-    prob[6][4] += 4_f64 * p_1063;
-let p_1064 = p_645 * p_1_1_5;
-    prob[0][3] += 2_f64 * p_1064;
-    prob[2][3] += 5_f64 * p_1064;
-    prob[4][3] += p_1064;
-    prob[6][3] += p_1064;
-let p_1065 = p_646 * p_1_0_5;
-    prob[0][4] += 2_f64 * p_1065;
-    prob[1][4] += 10_f64 * p_1065;
-    prob[2][4] += 20_f64 * p_1065;
-    prob[4][4] += 4_f64 * p_1065;
-    prob[6][4] += 6_f64 * p_1065;
-let p_1066 = p_648 * p_1_2_5;
-    prob[0][2] += 4_f64 * p_1066;
-    prob[3][2] += 3_f64 * p_1066;
-    prob[4][2] += 2_f64 * p_1066;
-    prob[6][2] += 2_f64 * p_1066;
-let p_1067 = p_649 * p_1_1_5;
-    prob[0][3] += 6_f64 * p_1067;
-    prob[1][3] += 6_f64 * p_1067;
-    prob[3][3] += 9_f64 * p_1067;
-    prob[4][3] += 8_f64 * p_1067;
-    prob[6][3] += 8_f64 * p_1067;
-let p_1068 = p_650 * p_1_0_5;
-    prob[0][4] += 2_f64 * p_1068;
-    prob[1][4] += 20_f64 * p_1068;
-    prob[3][4] += 12_f64 * p_1068;
-    prob[4][4] += 5_f64 * p_1068;
-    prob[6][4] += 7_f64 * p_1068;
-let p_1069 = p_651 * p_1_1_5;
-    prob[0][4] += 6_f64 * p_1069;
-    prob[2][4] += 5_f64 * p_1069;
-    prob[3][4] += 9_f64 * p_1069;
-    prob[4][4] += 2_f64 * p_1069;
-    prob[6][4] += 4_f64 * p_1069;
-let p_1070 = p_654 * p_1_2_5;
-    prob[0][3] += p_1070;
-    prob[4][3] += 2_f64 * p_1070;
-    prob[6][3] += p_1070;
-let p_1071 = p_655 * p_1_1_5;
-    prob[0][4] += 2_f64 * p_1071;
-    prob[1][4] += 3_f64 * p_1071;
-    prob[4][4] += 8_f64 * p_1071;
-    prob[6][4] += 4_f64 * p_1071;
-let p_1072 = p_618 * p_6_0_5;
-    prob[5][0] += p_1072;
-    prob[6][0] += p_1072;
-let p_1073 = p_658 * p_1_2_5;
-    prob[0][1] += 3_f64 * p_1073;
-    prob[1][1] += p_1073;
-    prob[5][1] += p_1073;
-    prob[6][1] += p_1073;
-let p_1074 = p_659 * p_1_1_5;
-    prob[0][2] += 7_f64 * p_1074;
-    prob[1][2] += 10_f64 * p_1074;
-    prob[6][2] += p_1074;
-let p_1075 = p_660 * p_1_0_5;
-    prob[0][3] += p_1075;
-    prob[1][3] += 10_f64 * p_1075;
-    prob[5][3] += p_1075;
-    prob[6][3] += 2_f64 * p_1075;
-let p_1076 = p_660 * p_2_3_5;
-    prob[1][4] += 8_f64 * p_1076;
-    prob[5][4] += p_1076;
-    prob[6][4] += p_1076;
-let p_1077 = p_661 * p_1_2_5;
-    prob[0][2] += 8_f64 * p_1077;
-    prob[2][2] += 3_f64 * p_1077;
-    prob[5][2] += p_1077;
-    prob[6][2] += p_1077;
-let p_1078 = p_662 * p_1_1_5;
-    prob[0][3] += 6_f64 * p_1078;
-    prob[1][3] += 8_f64 * p_1078;
-    prob[2][3] += 9_f64 * p_1078;
-    prob[5][3] += 2_f64 * p_1078;
-    prob[6][3] += 2_f64 * p_1078;
-let p_1079 = p_663 * p_1_0_5;
-    prob[0][4] += 5_f64 * p_1079;
-    prob[1][4] += 24_f64 * p_1079;
-    prob[2][4] += 14_f64 * p_1079;
-    prob[5][4] += 5_f64 * p_1079;
-    prob[6][4] += 6_f64 * p_1079;
-let p_1080 = p_664 * p_1_1_5;
-    prob[0][4] += 2_f64 * p_1080;
-    prob[2][4] += 5_f64 * p_1080;
-    prob[5][4] += p_1080;
-    prob[6][4] += p_1080;
-let p_1081 = p_666 * p_1_2_5;
-    prob[0][3] += 5_f64 * p_1081;
-    prob[3][3] += 4_f64 * p_1081;
-    prob[5][3] += p_1081;
-    prob[6][3] += 2_f64 * p_1081;
-let p_1082 = p_667 * p_1_1_5;
-    prob[0][4] += 6_f64 * p_1082;
-    prob[1][4] += 4_f64 * p_1082;
-    prob[3][4] += 10_f64 * p_1082;
-    prob[5][4] += 2_f64 * p_1082;
-    prob[6][4] += 4_f64 * p_1082;
-let p_1083 = p_670 * p_1_2_5;
-    prob[0][4] += 2_f64 * p_1083;
-    prob[4][4] += 4_f64 * p_1083;
-    prob[5][4] += 2_f64 * p_1083;
-    prob[6][4] += 2_f64 * p_1083;
-let p_1084 = p_674 * p_1_2_5;
-    prob[0][2] += 3_f64 * p_1084;
-    prob[1][2] += p_1084;
-let p_1085 = p_675 * p_1_1_5;
-    prob[0][3] += 4_f64 * p_1085;
-    prob[1][3] += 7_f64 * p_1085;
-    prob[6][3] += p_1085;
-let p_1086 = p_676 * p_1_0_5;
-    prob[0][4] += p_1086;
-    prob[1][4] += 7_f64 * p_1086;
-    prob[6][4] += 2_f64 * p_1086;
-let p_1087 = p_677 * p_1_2_5;
-    prob[0][3] += 5_f64 * p_1087;
-    prob[2][3] += 2_f64 * p_1087;
-let p_1088 = p_678 * p_1_1_5;
-    prob[0][4] += 4_f64 * p_1088;
-    prob[1][4] += 4_f64 * p_1088;
-    prob[2][4] += 5_f64 * p_1088;
-    prob[6][4] += 2_f64 * p_1088;
-let p_1089 = p_681 * p_1_2_5;
-    prob[0][4] += 3_f64 * p_1089;
-    prob[3][4] += 3_f64 * p_1089;
-    prob[6][4] += p_1089;
-let p_1090 = p_450 * p_8_0_5;
-    prob[0][0] += p_1090;
-    prob[1][0] += 11_f64 * p_1090;
-    prob[7][0] += 3_f64 * p_1090;
-let p_1091 = p_451 * p_8_0_5;
-    prob[1][1] += 5_f64 * p_1091;
-    prob[7][1] += p_1091;
-let p_1092 = p_454 * p_8_0_5;
-    prob[0][0] += 18_f64 * p_1092;
-    prob[1][0] += 27_f64 * p_1092;
-    prob[2][0] += 18_f64 * p_1092;
-    prob[7][0] += 12_f64 * p_1092;
-let p_1093 = p_685 * p_1_0_5;
-    prob[0][1] += 5_f64 * p_1093;
-    prob[1][1] += 21_f64 * p_1093;
-    prob[2][1] += 9_f64 * p_1093;
-    prob[7][1] += 9_f64 * p_1093;
-let p_1094 = p_685 * p_2_3_5;
-    prob[1][2] += 8_f64 * p_1094;
-    prob[2][2] += p_1094;
-    prob[7][2] += p_1094;
-let p_1095 = p_457 * p_8_0_5;
-    prob[0][0] += 10_f64 * p_1095;
-    prob[2][0] += 11_f64 * p_1095;
-    prob[7][0] += 4_f64 * p_1095;
-let p_1096 = p_686 * p_1_1_5;
-    prob[0][1] += 12_f64 * p_1096;
-    prob[1][1] += 5_f64 * p_1096;
-    prob[2][1] += 16_f64 * p_1096;
-    prob[7][1] += 7_f64 * p_1096;
-let p_1097 = p_687 * p_1_0_5;
-    prob[0][2] += 4_f64 * p_1097;
-    prob[1][2] += 14_f64 * p_1097;
-    prob[2][2] += 9_f64 * p_1097;
-    prob[7][2] += 7_f64 * p_1097;
-let p_1098 = p_687 * p_2_2_5;
-    prob[1][3] += 6_f64 * p_1098;
-    prob[2][3] += 4_f64 * p_1098;
-let p_1099 = p_688 * p_1_1_5;
-    prob[0][2] += 4_f64 * p_1099;
-    prob[2][2] += 7_f64 * p_1099;
-    prob[7][2] += 3_f64 * p_1099;
-let p_1100 = p_689 * p_1_0_5;
-    prob[1][3] += p_1100;
-    prob[2][3] += 3_f64 * p_1100;
-let p_1101 = p_689 * p_2_1_5;
-    prob[1][4] += 4_f64 * p_1101;
-    prob[2][4] += 6_f64 * p_1101;
-let p_1102 = p_690 * p_1_0_5;
-    prob[2][4] += p_1102;
-let p_1103 = p_467 * p_8_0_5;
-    prob[0][0] += 14_f64 * p_1103;
-    prob[1][0] += 5_f64 * p_1103;
-    prob[3][0] += 10_f64 * p_1103;
-    prob[7][0] += 5_f64 * p_1103;
-let p_1104 = p_691 * p_1_1_5;
-    prob[0][1] += 12_f64 * p_1104;
-    prob[1][1] += 11_f64 * p_1104;
-    prob[3][1] += 7_f64 * p_1104;
-    prob[7][1] += 5_f64 * p_1104;
-let p_1105 = p_692 * p_1_0_5;
-    prob[0][2] += p_1105;
-    prob[1][2] += 7_f64 * p_1105;
-    prob[7][2] += 2_f64 * p_1105;
-let p_1106 = p_692 * p_2_3_5;
-    prob[1][3] += 8_f64 * p_1106;
-    prob[3][3] += p_1106;
-    prob[7][3] += p_1106;
-let p_1107 = p_693 * p_1_2_5;
-    prob[0][1] += 9_f64 * p_1107;
-    prob[2][1] += 4_f64 * p_1107;
-    prob[3][1] += 3_f64 * p_1107;
-    prob[7][1] += 2_f64 * p_1107;
-let p_1108 = p_694 * p_1_1_5;
-    prob[0][2] += 14_f64 * p_1108;
-    prob[1][2] += 8_f64 * p_1108;
-    prob[2][2] += 9_f64 * p_1108;
-    prob[3][2] += 7_f64 * p_1108;
-    prob[7][2] += 8_f64 * p_1108;
-let p_1109 = p_695 * p_1_0_5;
-    prob[0][3] += 4_f64 * p_1109;
-    prob[1][3] += 20_f64 * p_1109;
-    prob[2][3] += 12_f64 * p_1109;
-    prob[3][3] += 3_f64 * p_1109;
-    prob[7][3] += 7_f64 * p_1109;
-let p_1110 = p_695 * p_2_2_5;
-    prob[1][4] += 12_f64 * p_1110;
-    prob[2][4] += 4_f64 * p_1110;
-    prob[3][4] += 4_f64 * p_1110;
-let p_1111 = p_696 * p_1_1_5;
-    prob[0][3] += 4_f64 * p_1111;
-    prob[2][3] += 7_f64 * p_1111;
-    prob[7][3] += 3_f64 * p_1111;
-let p_1112 = p_697 * p_1_0_5;
-    prob[1][4] += 3_f64 * p_1112;
-    prob[2][4] += 6_f64 * p_1112;
-    prob[3][4] += 3_f64 * p_1112;
-let p_1113 = p_699 * p_1_2_5;
-    prob[0][2] += 3_f64 * p_1113;
-    prob[3][2] += 3_f64 * p_1113;
-    prob[7][2] += p_1113;
-let p_1114 = p_700 * p_1_1_5;
-    prob[0][3] += 6_f64 * p_1114;
-    prob[1][3] += 3_f64 * p_1114;
-    prob[3][3] += 7_f64 * p_1114;
-    prob[7][3] += 4_f64 * p_1114;
-let p_1115 = p_701 * p_1_0_5;
-    prob[1][4] += 3_f64 * p_1115;
-    prob[3][4] += 3_f64 * p_1115;
-let p_1116 = p_702 * p_1_1_5;
-    prob[2][4] += p_1116;
-    prob[3][4] += 2_f64 * p_1116;
-let p_1117 = p_684 * p_5_0_5;
-    prob[0][0] += 2_f64 * p_1117;
-    prob[4][0] += 2_f64 * p_1117;
-    prob[7][0] += p_1117;
-let p_1118 = p_705 * p_1_2_5;
-    prob[0][1] += 6_f64 * p_1118;
-    prob[1][1] += 2_f64 * p_1118;
-    prob[4][1] += 2_f64 * p_1118;
-    prob[7][1] += p_1118;
-let p_1119 = p_706 * p_1_1_5;
-    prob[0][2] += 6_f64 * p_1119;
-    prob[1][2] += 8_f64 * p_1119;
-    prob[7][2] += p_1119;
-let p_1120 = p_707 * p_1_0_5;
-    prob[0][3] += p_1120;
-    prob[1][3] += 10_f64 * p_1120;
-    prob[4][3] += p_1120;
-    prob[7][3] += 2_f64 * p_1120;
-let p_1121 = p_707 * p_2_3_5;
-    prob[1][4] += 8_f64 * p_1121;
-    prob[4][4] += p_1121;
-    prob[7][4] += p_1121;
-let p_1122 = p_708 * p_1_2_5;
-    prob[0][2] += 7_f64 * p_1122;
-    prob[2][2] += 4_f64 * p_1122;
-    prob[4][2] += 2_f64 * p_1122;
-    prob[7][2] += 2_f64 * p_1122;
-let p_1123 = p_709 * p_1_1_5;
-    prob[0][3] += 8_f64 * p_1123;
-    prob[1][3] += 5_f64 * p_1123;
-    prob[2][3] += 9_f64 * p_1123;
-    prob[7][3] += 4_f64 * p_1123;
-let p_1124 = p_710 * p_1_0_5;
-    prob[0][4] += 4_f64 * p_1124;
-    prob[1][4] += 20_f64 * p_1124;
-    prob[2][4] += 12_f64 * p_1124;
-    prob[4][4] += 3_f64 * p_1124;
-    prob[7][4] += 7_f64 * p_1124;
-let p_1125 = p_711 * p_1_1_5;
-    prob[0][4] += 4_f64 * p_1125;
-    prob[2][4] += 9_f64 * p_1125;
-    prob[4][4] += p_1125;
-    prob[7][4] += 3_f64 * p_1125;
-let p_1126 = p_713 * p_1_2_5;
-    prob[0][3] += 4_f64 * p_1126;
-    prob[3][3] += 3_f64 * p_1126;
-    prob[4][3] += 2_f64 * p_1126;
-    prob[7][3] += 2_f64 * p_1126;
-let p_1127 = p_714 * p_1_1_5;
-    prob[0][4] += 6_f64 * p_1127;
-    prob[1][4] += 5_f64 * p_1127;
-    prob[3][4] += 9_f64 * p_1127;
-    prob[4][4] += 2_f64 * p_1127;
-    prob[7][4] += 4_f64 * p_1127;
-let p_1128 = p_717 * p_1_2_5;
-    prob[0][4] += p_1128;
-    prob[4][4] += 2_f64 * p_1128;
-    prob[7][4] += p_1128;
-let p_1129 = p_719 * p_1_3_5;
-    prob[0][1] += p_1129;
-let p_1130 = p_720 * p_1_2_5;
-    prob[0][2] += 5_f64 * p_1130;
-    prob[1][2] += 2_f64 * p_1130;
-let p_1131 = p_721 * p_1_1_5;
-    prob[0][3] += 9_f64 * p_1131;
-    prob[1][3] += 11_f64 * p_1131;
-    prob[7][3] += p_1131;
-let p_1132 = p_722 * p_1_0_5;
-    prob[0][4] += p_1132;
-    prob[1][4] += 10_f64 * p_1132;
-    prob[5][4] += p_1132;
-    prob[7][4] += 2_f64 * p_1132;
-let p_1133 = p_723 * p_1_2_5;
-    prob[0][3] += 9_f64 * p_1133;
-    prob[2][3] += 5_f64 * p_1133;
-    prob[7][3] += p_1133;
-let p_1134 = p_724 * p_1_1_5;
-    prob[0][4] += 10_f64 * p_1134;
-    prob[1][4] += 9_f64 * p_1134;
-    prob[2][4] += 13_f64 * p_1134;
-    prob[5][4] += 2_f64 * p_1134;
-    prob[7][4] += 4_f64 * p_1134;
-let p_1135 = p_727 * p_1_2_5;
-    prob[0][4] += 5_f64 * p_1135;
-    prob[3][4] += 4_f64 * p_1135;
-    prob[7][4] += p_1135;
-let p_1136 = p_684 * p_7_0_5;
-    prob[0][2] += p_1136;
-let p_1137 = p_730 * p_1_2_5;
-    prob[0][3] += 8_f64 * p_1137;
-    prob[1][3] += 3_f64 * p_1137;
-let p_1138 = p_731 * p_1_1_5;
-    prob[0][4] += 10_f64 * p_1138;
-    prob[1][4] += 15_f64 * p_1138;
-    prob[6][4] += p_1138;
-    prob[7][4] += p_1138;
-let p_1139 = p_733 * p_1_2_5;
-    prob[0][4] += 11_f64 * p_1139;
-    prob[2][4] += 6_f64 * p_1139;
-    prob[7][4] += p_1139;
-let p_1140 = p_684 * p_8_1_5;
-    prob[0][3] += p_1140;
-let p_1141 = p_736 * p_1_2_5;
-    prob[0][4] += 5_f64 * p_1141;
-    prob[1][4] += 2_f64 * p_1141;
-let p_1142 = p_449 * p_9_0_5;
-    prob[0][0] += 10_f64 * p_1142;
-    prob[1][0] += 21_f64 * p_1142;
-    prob[8][0] += 6_f64 * p_1142;
-let p_1143 = p_739 * p_1_0_5;
-    prob[0][1] += 2_f64 * p_1143;
-    prob[1][1] += 9_f64 * p_1143;
-    prob[8][1] += 3_f64 * p_1143;
-let p_1144 = p_739 * p_2_4_5;
-    prob[1][2] += p_1144;
-let p_1145 = p_453 * p_9_0_5;
-    prob[0][0] += 24_f64 * p_1145;
-    prob[1][0] += 13_f64 * p_1145;
-    prob[2][0] += 13_f64 * p_1145;
-    prob[8][0] += 8_f64 * p_1145;
-let p_1146 = p_740 * p_1_1_5;
-    prob[0][1] += 18_f64 * p_1146;
-    prob[1][1] += 21_f64 * p_1146;
-    prob[2][1] += 9_f64 * p_1146;
-    prob[8][1] += 9_f64 * p_1146;
-let p_1147 = p_741 * p_1_0_5;
-    prob[0][2] += 2_f64 * p_1147;
-    prob[1][2] += 9_f64 * p_1147;
-    prob[8][2] += 3_f64 * p_1147;
-let p_1148 = p_741 * p_2_3_5;
-    prob[1][3] += 4_f64 * p_1148;
-    prob[2][3] += p_1148;
-let p_1149 = p_742 * p_1_2_5;
-    prob[0][1] += 7_f64 * p_1149;
-    prob[2][1] += 4_f64 * p_1149;
-    prob[8][1] += p_1149;
-let p_1150 = p_743 * p_1_1_5;
-    prob[0][2] += 10_f64 * p_1150;
-    prob[1][2] += 9_f64 * p_1150;
-    prob[2][2] += 9_f64 * p_1150;
-    prob[8][2] += 6_f64 * p_1150;
-let p_1151 = p_744 * p_2_2_5;
-    prob[1][4] += 6_f64 * p_1151;
-    prob[2][4] += 4_f64 * p_1151;
-let p_1152 = p_746 * p_1_0_5;
-    prob[1][4] += p_1152;
-    prob[2][4] += 3_f64 * p_1152;
-let p_1153 = p_466 * p_9_0_5;
-    prob[0][0] += 6_f64 * p_1153;
-    prob[3][0] += 3_f64 * p_1153;
-    prob[8][0] += p_1153;
-let p_1154 = p_748 * p_1_2_5;
-    prob[0][1] += 11_f64 * p_1154;
-    prob[1][1] += 4_f64 * p_1154;
-    prob[3][1] += 3_f64 * p_1154;
-    prob[8][1] += 2_f64 * p_1154;
-let p_1155 = p_749 * p_1_1_5;
-    prob[0][2] += 8_f64 * p_1155;
-    prob[1][2] += 12_f64 * p_1155;
-    prob[8][2] += 3_f64 * p_1155;
-let p_1156 = p_750 * p_1_0_5;
-    prob[0][3] += 2_f64 * p_1156;
-    prob[1][3] += 9_f64 * p_1156;
-    prob[8][3] += 3_f64 * p_1156;
-let p_1157 = p_750 * p_2_3_5;
-    prob[1][4] += 4_f64 * p_1157;
-    prob[3][4] += p_1157;
-let p_1158 = p_751 * p_1_2_5;
-    prob[0][2] += 11_f64 * p_1158;
-    prob[2][2] += 4_f64 * p_1158;
-    prob[3][2] += 3_f64 * p_1158;
-    prob[8][2] += 2_f64 * p_1158;
-let p_1159 = p_752 * p_1_1_5;
-    prob[0][3] += 10_f64 * p_1159;
-    prob[1][3] += 9_f64 * p_1159;
-    prob[2][3] += 9_f64 * p_1159;
-    prob[8][3] += 6_f64 * p_1159;
-let p_1160 = p_753 * p_1_0_5;
-    prob[1][4] += 6_f64 * p_1160;
-    prob[2][4] += 3_f64 * p_1160;
-    prob[3][4] += 3_f64 * p_1160;
-let p_1161 = p_756 * p_1_2_5;
-    prob[0][3] += 4_f64 * p_1161;
-    prob[3][3] += 3_f64 * p_1161;
-    prob[8][3] += p_1161;
-let p_1162 = p_760 * p_1_3_5;
-    prob[0][1] += 2_f64 * p_1162;
-let p_1163 = p_761 * p_1_2_5;
-    prob[0][2] += 7_f64 * p_1163;
-    prob[1][2] += 4_f64 * p_1163;
-    prob[8][2] += p_1163;
-let p_1164 = p_762 * p_1_1_5;
-    prob[0][3] += 8_f64 * p_1164;
-    prob[1][3] += 12_f64 * p_1164;
-    prob[8][3] += 3_f64 * p_1164;
-let p_1165 = p_763 * p_1_0_5;
-    prob[0][4] += 2_f64 * p_1165;
-    prob[1][4] += 12_f64 * p_1165;
-    prob[4][4] += p_1165;
-    prob[8][4] += 3_f64 * p_1165;
-let p_1166 = p_764 * p_1_2_5;
-    prob[0][3] += 7_f64 * p_1166;
-    prob[2][3] += 4_f64 * p_1166;
-    prob[8][3] += p_1166;
-let p_1167 = p_765 * p_1_1_5;
-    prob[0][4] += 10_f64 * p_1167;
-    prob[1][4] += 9_f64 * p_1167;
-    prob[2][4] += 9_f64 * p_1167;
-    prob[8][4] += 6_f64 * p_1167;
-let p_1168 = p_768 * p_1_2_5;
-    prob[0][4] += 4_f64 * p_1168;
-    prob[3][4] += 3_f64 * p_1168;
-    prob[8][4] += p_1168;
-let p_1169 = p_771 * p_1_3_5;
-    prob[0][2] += 2_f64 * p_1169;
-let p_1170 = p_772 * p_1_2_5;
-    prob[0][3] += 7_f64 * p_1170;
-    prob[1][3] += 4_f64 * p_1170;
-    prob[8][3] += p_1170;
-let p_1171 = p_773 * p_1_1_5;
-    prob[0][4] += 11_f64 * p_1171;
-    prob[1][4] += 15_f64 * p_1171;
-    prob[8][4] += 3_f64 * p_1171;
-let p_1172 = p_775 * p_1_2_5;
-    prob[0][4] += 10_f64 * p_1172;
-    prob[2][4] += 5_f64 * p_1172;
-    prob[8][4] += p_1172;
-let p_1173 = p_778 * p_1_3_5;
-    prob[0][3] += 2_f64 * p_1173;
-let p_1174 = p_779 * p_1_2_5;
-    prob[0][4] += 10_f64 * p_1174;
-    prob[1][4] += 5_f64 * p_1174;
-    prob[8][4] += p_1174;
-let p_1175 = p_782 * p_1_3_5;
-    prob[0][4] += 3_f64 * p_1175;
-let p_1176 = p_448 * p_10_0_5;
-    prob[0][0] += 18_f64 * p_1176;
-    prob[1][0] += 16_f64 * p_1176;
-    prob[9][0] += 4_f64 * p_1176;
-let p_1177 = p_785 * p_1_1_5;
-    prob[0][1] += 9_f64 * p_1177;
-    prob[1][1] += 12_f64 * p_1177;
-    prob[9][1] += 3_f64 * p_1177;
-let p_1178 = p_786 * p_2_4_5;
-    prob[1][3] += p_1178;
-let p_1179 = p_452 * p_10_0_5;
-    prob[0][0] += 12_f64 * p_1179;
-    prob[2][0] += 4_f64 * p_1179;
-    prob[9][0] += p_1179;
-let p_1180 = p_787 * p_1_2_5;
-    prob[0][1] += 18_f64 * p_1180;
-    prob[1][1] += 4_f64 * p_1180;
-    prob[2][1] += 4_f64 * p_1180;
-    prob[9][1] += 2_f64 * p_1180;
-let p_1181 = p_788 * p_1_1_5;
-    prob[0][2] += 9_f64 * p_1181;
-    prob[1][2] += 12_f64 * p_1181;
-    prob[9][2] += 3_f64 * p_1181;
-let p_1182 = p_789 * p_2_3_5;
-    prob[1][4] += 4_f64 * p_1182;
-    prob[2][4] += p_1182;
-let p_1183 = p_790 * p_1_2_5;
-    prob[0][2] += 9_f64 * p_1183;
-    prob[2][2] += 4_f64 * p_1183;
-    prob[9][2] += p_1183;
-let p_1184 = p_794 * p_1_3_5;
-    prob[0][1] += 3_f64 * p_1184;
-let p_1185 = p_795 * p_1_2_5;
-    prob[0][2] += 9_f64 * p_1185;
-    prob[1][2] += 4_f64 * p_1185;
-    prob[9][2] += p_1185;
-let p_1186 = p_796 * p_1_1_5;
-    prob[0][3] += 9_f64 * p_1186;
-    prob[1][3] += 12_f64 * p_1186;
-    prob[9][3] += 3_f64 * p_1186;
-let p_1187 = p_798 * p_1_2_5;
-    prob[0][3] += 9_f64 * p_1187;
-    prob[2][3] += 4_f64 * p_1187;
-    prob[9][3] += p_1187;
-let p_1188 = p_802 * p_1_3_5;
-    prob[0][2] += 3_f64 * p_1188;
-let p_1189 = p_803 * p_1_2_5;
-    prob[0][3] += 9_f64 * p_1189;
-    prob[1][3] += 4_f64 * p_1189;
-    prob[9][3] += p_1189;
-let p_1190 = p_804 * p_1_1_5;
-    prob[0][4] += 9_f64 * p_1190;
-    prob[1][4] += 12_f64 * p_1190;
-    prob[9][4] += 3_f64 * p_1190;
-let p_1191 = p_806 * p_1_2_5;
-    prob[0][4] += 9_f64 * p_1191;
-    prob[2][4] += 4_f64 * p_1191;
-    prob[9][4] += p_1191;
-let p_1192 = p_809 * p_1_3_5;
-    prob[0][3] += 3_f64 * p_1192;
-let p_1193 = p_810 * p_1_2_5;
-    prob[0][4] += 9_f64 * p_1193;
-    prob[1][4] += 4_f64 * p_1193;
-    prob[9][4] += p_1193;
-let p_1194 = p_813 * p_1_3_5;
-    prob[0][4] += 3_f64 * p_1194;
-let p_1195 = p_816 * p_1_6_6;
-    prob[0][0] += p_1195;
-let p_1196 = p_817 * p_1_5_6;
-    prob[0][1] += p_1196;
-let p_1197 = p_818 * p_1_4_6;
-let p_1198 = p_819 * p_1_3_6;
-let p_1199 = p_820 * p_1_2_6;
-let p_1200 = p_822 * p_1_0_6;
-let p_1201 = p_822 * p_2_6_6;
-let p_1202 = p_823 * p_1_5_6;
-    prob[0][2] += p_1202;
-let p_1203 = p_824 * p_1_4_6;
-let p_1204 = p_826 * p_1_1_6;
-let p_1205 = p_827 * p_1_0_6;
-let p_1206 = p_827 * p_2_5_6;
-let p_1207 = p_829 * p_1_1_6;
-let p_1208 = p_830 * p_1_0_6;
-let p_1209 = p_830 * p_2_4_6;
-let p_1210 = p_831 * p_1_2_6;
-let p_1211 = p_832 * p_1_1_6;
-let p_1212 = p_833 * p_1_0_6;
-let p_1213 = p_833 * p_2_3_6;
-    prob[1][0] += 20_f64 * p_1213;
-    prob[2][0] += 15_f64 * p_1213;
-let p_1214 = p_834 * p_1_2_6;
-let p_1215 = p_835 * p_1_1_6;
-let p_1216 = p_836 * p_1_0_6;
-    prob[1][0] += 12_f64 * p_1216;
-    prob[2][0] += 27_f64 * p_1216;
-let p_1217 = p_836 * p_2_2_6;
-    prob[1][1] += 15_f64 * p_1217;
-    prob[2][1] += 20_f64 * p_1217;
-let p_1218 = p_837 * p_1_1_6;
-    prob[2][0] += 3_f64 * p_1218;
-let p_1219 = p_838 * p_1_0_6;
-    prob[1][1] += 2_f64 * p_1219;
-    prob[2][1] += 10_f64 * p_1219;
-let p_1220 = p_838 * p_2_1_6;
-    prob[1][2] += 5_f64 * p_1220;
-    prob[2][2] += 10_f64 * p_1220;
-let p_1221 = p_839 * p_1_0_6;
-    prob[2][2] += 2_f64 * p_1221;
-let p_1222 = p_840 * p_1_5_6;
-    prob[0][3] += p_1222;
-let p_1223 = p_842 * p_1_1_6;
-let p_1224 = p_843 * p_1_0_6;
-let p_1225 = p_843 * p_2_5_6;
-let p_1226 = p_844 * p_1_2_6;
-let p_1227 = p_845 * p_1_1_6;
-let p_1228 = p_846 * p_1_0_6;
-let p_1229 = p_846 * p_2_4_6;
-    prob[1][0] += 30_f64 * p_1229;
-    prob[2][0] += 6_f64 * p_1229;
-    prob[3][0] += 6_f64 * p_1229;
-let p_1230 = p_847 * p_1_2_6;
-let p_1231 = p_848 * p_1_1_6;
-let p_1232 = p_849 * p_1_0_6;
-    prob[1][0] += 75_f64 * p_1232;
-    prob[2][0] += 52_f64 * p_1232;
-    prob[3][0] += 29_f64 * p_1232;
-let p_1233 = p_849 * p_2_3_6;
-    prob[1][1] += 60_f64 * p_1233;
-    prob[2][1] += 30_f64 * p_1233;
-    prob[3][1] += 15_f64 * p_1233;
-let p_1234 = p_850 * p_1_2_6;
-let p_1235 = p_851 * p_1_1_6;
-    prob[1][0] += 12_f64 * p_1235;
-    prob[2][0] += 36_f64 * p_1235;
-    prob[3][0] += 12_f64 * p_1235;
-let p_1236 = p_852 * p_1_0_6;
-    prob[1][1] += 34_f64 * p_1236;
-    prob[2][1] += 54_f64 * p_1236;
-    prob[3][1] += 20_f64 * p_1236;
-let p_1237 = p_852 * p_2_2_6;
-    prob[1][2] += 40_f64 * p_1237;
-    prob[2][2] += 30_f64 * p_1237;
-    prob[3][2] += 10_f64 * p_1237;
-let p_1238 = p_853 * p_1_1_6;
-    prob[2][1] += 4_f64 * p_1238;
-    prob[3][1] += p_1238;
-let p_1239 = p_854 * p_1_0_6;
-    prob[1][2] += 10_f64 * p_1239;
-    prob[2][2] += 32_f64 * p_1239;
-    prob[3][2] += 8_f64 * p_1239;
-let p_1240 = p_854 * p_2_1_6;
-    prob[1][3] += 5_f64 * p_1240;
-    prob[2][3] += 10_f64 * p_1240;
-let p_1241 = p_855 * p_1_0_6;
-    prob[2][3] += 2_f64 * p_1241;
-let p_1242 = p_856 * p_1_2_6;
-let p_1243 = p_857 * p_1_1_6;
-let p_1244 = p_858 * p_1_0_6;
-    prob[1][0] += 23_f64 * p_1244;
-    prob[3][0] += 13_f64 * p_1244;
-let p_1245 = p_858 * p_2_4_6;
-    prob[1][1] += 15_f64 * p_1245;
-    prob[3][1] += 6_f64 * p_1245;
-let p_1246 = p_859 * p_1_3_6;
-let p_1247 = p_860 * p_1_2_6;
-let p_1248 = p_861 * p_1_1_6;
-    prob[1][0] += 36_f64 * p_1248;
-    prob[2][0] += 21_f64 * p_1248;
-    prob[3][0] += 42_f64 * p_1248;
-let p_1249 = p_862 * p_1_0_6;
-    prob[1][1] += 51_f64 * p_1249;
-    prob[2][1] += 20_f64 * p_1249;
-    prob[3][1] += 40_f64 * p_1249;
-let p_1250 = p_862 * p_2_3_6;
-    prob[1][2] += 30_f64 * p_1250;
-    prob[2][2] += 5_f64 * p_1250;
-    prob[3][2] += 10_f64 * p_1250;
-let p_1251 = p_863 * p_1_2_6;
-    prob[2][0] += 6_f64 * p_1251;
-    prob[3][0] += 9_f64 * p_1251;
-let p_1252 = p_864 * p_1_1_6;
-    prob[1][1] += 6_f64 * p_1252;
-    prob[2][1] += 16_f64 * p_1252;
-    prob[3][1] += 20_f64 * p_1252;
-let p_1253 = p_865 * p_1_0_6;
-    prob[1][2] += 45_f64 * p_1253;
-    prob[2][2] += 34_f64 * p_1253;
-    prob[3][2] += 38_f64 * p_1253;
-let p_1254 = p_865 * p_2_2_6;
-    prob[1][3] += 30_f64 * p_1254;
-    prob[2][3] += 20_f64 * p_1254;
-    prob[3][3] += 10_f64 * p_1254;
-let p_1255 = p_866 * p_1_1_6;
-    prob[2][2] += 8_f64 * p_1255;
-    prob[3][2] += 8_f64 * p_1255;
-let p_1256 = p_867 * p_1_0_6;
-    prob[1][3] += 8_f64 * p_1256;
-    prob[2][3] += 24_f64 * p_1256;
-    prob[3][3] += 8_f64 * p_1256;
-let p_1257 = p_869 * p_1_3_6;
-let p_1258 = p_870 * p_1_2_6;
-    prob[1][0] += p_1258;
-    prob[3][0] += 6_f64 * p_1258;
-let p_1259 = p_871 * p_1_1_6;
-    prob[1][1] += 4_f64 * p_1259;
-    prob[3][1] += 8_f64 * p_1259;
-let p_1260 = p_872 * p_1_0_6;
-    prob[1][2] += 9_f64 * p_1260;
-    prob[3][2] += 7_f64 * p_1260;
-let p_1261 = p_872 * p_2_3_6;
-    prob[1][3] += 10_f64 * p_1261;
-    prob[3][3] += 5_f64 * p_1261;
-let p_1262 = p_873 * p_1_2_6;
-    prob[2][1] += p_1262;
-    prob[3][1] += 3_f64 * p_1262;
-let p_1263 = p_874 * p_1_1_6;
-    prob[1][2] += 4_f64 * p_1263;
-    prob[2][2] += 5_f64 * p_1263;
-    prob[3][2] += 15_f64 * p_1263;
-let p_1264 = p_875 * p_1_0_6;
-    prob[1][3] += 24_f64 * p_1264;
-    prob[2][3] += 13_f64 * p_1264;
-    prob[3][3] += 26_f64 * p_1264;
-let p_1265 = p_876 * p_1_1_6;
-    prob[2][3] += 5_f64 * p_1265;
-    prob[3][3] += 7_f64 * p_1265;
-let p_1266 = p_878 * p_1_2_6;
-    prob[3][2] += p_1266;
-let p_1267 = p_879 * p_1_1_6;
-    prob[1][3] += p_1267;
-    prob[3][3] += 5_f64 * p_1267;
-let p_1268 = p_882 * p_1_5_6;
-    prob[0][4] += p_1268;
-let p_1269 = p_883 * p_1_4_6;
-let p_1270 = p_884 * p_1_2_6;
-let p_1271 = p_885 * p_1_1_6;
-let p_1272 = p_886 * p_2_5_6;
-    prob[1][0] += 6_f64 * p_1272;
-    prob[4][0] += p_1272;
-let p_1273 = p_887 * p_1_2_6;
-let p_1274 = p_888 * p_2_3_6;
-    prob[1][0] += 52_f64 * p_1274;
-    prob[2][0] += 13_f64 * p_1274;
-    prob[4][0] += 15_f64 * p_1274;
-let p_1275 = p_886 * p_3_0_6;
-    prob[1][1] += 30_f64 * p_1275;
-    prob[2][1] += 6_f64 * p_1275;
-    prob[4][1] += 6_f64 * p_1275;
-let p_1276 = p_889 * p_1_3_6;
-let p_1277 = p_890 * p_2_1_6;
-    prob[1][0] += 30_f64 * p_1277;
-    prob[2][0] += 36_f64 * p_1277;
-    prob[4][0] += 21_f64 * p_1277;
-let p_1278 = p_891 * p_1_0_6;
-    prob[1][1] += 48_f64 * p_1278;
-    prob[2][1] += 34_f64 * p_1278;
-    prob[4][1] += 21_f64 * p_1278;
-let p_1279 = p_891 * p_2_3_6;
-    prob[1][2] += 30_f64 * p_1279;
-    prob[2][2] += 10_f64 * p_1279;
-    prob[4][2] += 5_f64 * p_1279;
-let p_1280 = p_889 * p_3_2_6;
-    prob[2][0] += 9_f64 * p_1280;
-    prob[4][0] += 4_f64 * p_1280;
-let p_1281 = p_892 * p_1_1_6;
-    prob[1][1] += 4_f64 * p_1281;
-    prob[2][1] += 20_f64 * p_1281;
-    prob[4][1] += 8_f64 * p_1281;
-let p_1282 = p_893 * p_1_0_6;
-    prob[1][2] += 26_f64 * p_1282;
-    prob[2][2] += 32_f64 * p_1282;
-    prob[4][2] += 13_f64 * p_1282;
-let p_1283 = p_893 * p_2_2_6;
-    prob[1][3] += 10_f64 * p_1283;
-    prob[2][3] += 10_f64 * p_1283;
-let p_1284 = p_894 * p_1_1_6;
-    prob[2][2] += 8_f64 * p_1284;
-    prob[4][2] += 3_f64 * p_1284;
-let p_1285 = p_895 * p_1_0_6;
-    prob[1][3] += 2_f64 * p_1285;
-    prob[2][3] += 8_f64 * p_1285;
-let p_1286 = p_895 * p_2_1_6;
-    prob[1][4] += 5_f64 * p_1286;
-    prob[2][4] += 10_f64 * p_1286;
-let p_1287 = p_896 * p_1_0_6;
-    prob[2][4] += 2_f64 * p_1287;
-let p_1288 = p_897 * p_1_4_6;
-let p_1289 = p_898 * p_1_3_6;
-let p_1290 = p_899 * p_2_2_6;
-    prob[1][0] += 36_f64 * p_1290;
-    prob[3][0] += 16_f64 * p_1290;
-    prob[4][0] += 18_f64 * p_1290;
-let p_1291 = p_900 * p_1_0_6;
-    prob[1][1] += 34_f64 * p_1291;
-    prob[3][1] += 10_f64 * p_1291;
-    prob[4][1] += 12_f64 * p_1291;
-let p_1292 = p_900 * p_2_4_6;
-    prob[1][2] += 10_f64 * p_1292;
-    prob[3][2] += p_1292;
-    prob[4][2] += p_1292;
-let p_1293 = p_901 * p_2_0_6;
-    prob[1][0] += 12_f64 * p_1293;
-    prob[2][0] += 12_f64 * p_1293;
-    prob[3][0] += 18_f64 * p_1293;
-    prob[4][0] += 18_f64 * p_1293;
-let p_1294 = p_902 * p_1_1_6;
-    prob[1][1] += 32_f64 * p_1294;
-    prob[2][1] += 16_f64 * p_1294;
-    prob[3][1] += 24_f64 * p_1294;
-    prob[4][1] += 24_f64 * p_1294;
-let p_1295 = p_903 * p_1_0_6;
-    prob[1][2] += 62_f64 * p_1295;
-    prob[2][2] += 14_f64 * p_1295;
-    prob[3][2] += 16_f64 * p_1295;
-    prob[4][2] += 18_f64 * p_1295;
-let p_1296 = p_903 * p_2_3_6;
-    prob[1][3] += 40_f64 * p_1296;
-    prob[2][3] += 10_f64 * p_1296;
-    prob[3][3] += 5_f64 * p_1296;
-    prob[4][3] += 5_f64 * p_1296;
-let p_1297 = p_904 * p_1_2_6;
-    prob[2][1] += 4_f64 * p_1297;
-    prob[3][1] += 2_f64 * p_1297;
-    prob[4][1] += 3_f64 * p_1297;
-let p_1298 = p_905 * p_1_1_6;
-    prob[1][2] += 16_f64 * p_1298;
-    prob[2][2] += 26_f64 * p_1298;
-    prob[3][2] += 17_f64 * p_1298;
-    prob[4][2] += 19_f64 * p_1298;
-let p_1299 = p_906 * p_1_0_6;
-    prob[1][3] += 42_f64 * p_1299;
-    prob[2][3] += 44_f64 * p_1299;
-    prob[3][3] += 12_f64 * p_1299;
-    prob[4][3] += 13_f64 * p_1299;
-let p_1300 = p_906 * p_2_2_6;
-    prob[1][4] += 30_f64 * p_1300;
-    prob[2][4] += 20_f64 * p_1300;
-    prob[3][4] += 10_f64 * p_1300;
-let p_1301 = p_907 * p_1_1_6;
-    prob[2][3] += 10_f64 * p_1301;
-    prob[3][3] += p_1301;
-    prob[4][3] += 3_f64 * p_1301;
-let p_1302 = p_908 * p_1_0_6;
-    prob[1][4] += 8_f64 * p_1302;
-    prob[2][4] += 24_f64 * p_1302;
-    prob[3][4] += 8_f64 * p_1302;
-let p_1303 = p_897 * p_4_1_6;
-    prob[3][0] += 2_f64 * p_1303;
-    prob[4][0] += p_1303;
-let p_1304 = p_910 * p_1_2_6;
-    prob[1][1] += 2_f64 * p_1304;
-    prob[3][1] += 4_f64 * p_1304;
-    prob[4][1] += 3_f64 * p_1304;
-let p_1305 = p_911 * p_1_1_6;
-    prob[1][2] += 13_f64 * p_1305;
-    prob[3][2] += 10_f64 * p_1305;
-    prob[4][2] += 7_f64 * p_1305;
-let p_1306 = p_912 * p_1_0_6;
-    prob[1][3] += 32_f64 * p_1306;
-    prob[3][3] += 16_f64 * p_1306;
-    prob[4][3] += 10_f64 * p_1306;
-let p_1307 = p_912 * p_2_3_6;
-    prob[1][4] += 10_f64 * p_1307;
-    prob[3][4] += 5_f64 * p_1307;
-let p_1308 = p_913 * p_1_2_6;
-    prob[2][2] += 3_f64 * p_1308;
-    prob[3][2] += 6_f64 * p_1308;
-    prob[4][2] += 3_f64 * p_1308;
-let p_1309 = p_914 * p_1_1_6;
-    prob[1][3] += 13_f64 * p_1309;
-    prob[2][3] += 15_f64 * p_1309;
-    prob[3][3] += 24_f64 * p_1309;
-    prob[4][3] += 14_f64 * p_1309;
-let p_1310 = p_915 * p_1_0_6;
-    prob[1][4] += 24_f64 * p_1310;
-    prob[2][4] += 13_f64 * p_1310;
-    prob[3][4] += 26_f64 * p_1310;
-let p_1311 = p_916 * p_1_1_6;
-    prob[2][4] += 5_f64 * p_1311;
-    prob[3][4] += 7_f64 * p_1311;
-let p_1312 = p_918 * p_1_2_6;
-    prob[3][3] += 3_f64 * p_1312;
-    prob[4][3] += p_1312;
-let p_1313 = p_919 * p_1_1_6;
-    prob[1][4] += p_1313;
-    prob[3][4] += 5_f64 * p_1313;
-let p_1314 = p_923 * p_2_1_6;
-    prob[1][0] += 6_f64 * p_1314;
-    prob[4][0] += 9_f64 * p_1314;
-let p_1315 = p_924 * p_1_1_6;
-    prob[1][1] += 8_f64 * p_1315;
-    prob[4][1] += 8_f64 * p_1315;
-let p_1316 = p_925 * p_1_0_6;
-    prob[1][2] += 7_f64 * p_1316;
-    prob[4][2] += 2_f64 * p_1316;
-let p_1317 = p_925 * p_2_4_6;
-    prob[1][3] += 5_f64 * p_1317;
-    prob[4][3] += p_1317;
-let p_1318 = p_922 * p_3_0_6;
-    prob[2][0] += p_1318;
-    prob[4][0] += 2_f64 * p_1318;
-let p_1319 = p_926 * p_1_2_6;
-    prob[1][1] += p_1319;
-    prob[2][1] += p_1319;
-    prob[4][1] += 4_f64 * p_1319;
-let p_1320 = p_927 * p_1_1_6;
-    prob[1][2] += 11_f64 * p_1320;
-    prob[2][2] += 3_f64 * p_1320;
-    prob[4][2] += 10_f64 * p_1320;
-let p_1321 = p_928 * p_1_0_6;
-    prob[1][3] += 21_f64 * p_1321;
-    prob[2][3] += 7_f64 * p_1321;
-    prob[4][3] += 8_f64 * p_1321;
-let p_1322 = p_928 * p_2_3_6;
-    prob[1][4] += 20_f64 * p_1322;
-    prob[2][4] += 5_f64 * p_1322;
-    prob[4][4] += 5_f64 * p_1322;
-let p_1323 = p_929 * p_1_2_6;
-    prob[2][2] += 3_f64 * p_1323;
-    prob[4][2] += 3_f64 * p_1323;
-let p_1324 = p_930 * p_1_1_6;
-    prob[1][3] += 3_f64 * p_1324;
-    prob[2][3] += 10_f64 * p_1324;
-    prob[4][3] += 5_f64 * p_1324;
-let p_1325 = p_931 * p_1_0_6;
-    prob[1][4] += 21_f64 * p_1325;
-    prob[2][4] += 23_f64 * p_1325;
-    prob[4][4] += 13_f64 * p_1325;
-let p_1326 = p_932 * p_1_1_6;
-    prob[2][4] += 7_f64 * p_1326;
-    prob[4][4] += 3_f64 * p_1326;
-let p_1327 = p_935 * p_1_2_6;
-    prob[1][2] += 3_f64 * p_1327;
-    prob[3][2] += 2_f64 * p_1327;
-    prob[4][2] += 4_f64 * p_1327;
-let p_1328 = p_936 * p_1_1_6;
-    prob[1][3] += 15_f64 * p_1328;
-    prob[3][3] += 7_f64 * p_1328;
-    prob[4][3] += 14_f64 * p_1328;
-let p_1329 = p_937 * p_1_0_6;
-    prob[1][4] += 23_f64 * p_1329;
-    prob[3][4] += 9_f64 * p_1329;
-    prob[4][4] += 10_f64 * p_1329;
-let p_1330 = p_938 * p_1_2_6;
-    prob[2][3] += 3_f64 * p_1330;
-    prob[3][3] += 2_f64 * p_1330;
-    prob[4][3] += 4_f64 * p_1330;
-let p_1331 = p_939 * p_1_1_6;
-    prob[1][4] += 10_f64 * p_1331;
-    prob[2][4] += 10_f64 * p_1331;
-    prob[3][4] += 14_f64 * p_1331;
-    prob[4][4] += 14_f64 * p_1331;
-let p_1332 = p_942 * p_1_2_6;
-    prob[3][4] += 2_f64 * p_1332;
-    prob[4][4] += p_1332;
-let p_1333 = p_944 * p_1_2_6;
-    prob[1][3] += p_1333;
-    prob[4][3] += 2_f64 * p_1333;
-let p_1334 = p_945 * p_1_1_6;
-    prob[1][4] += 5_f64 * p_1334;
-    prob[4][4] += 7_f64 * p_1334;
-let p_1335 = p_947 * p_1_2_6;
-    prob[2][4] += p_1335;
-    prob[4][4] += 2_f64 * p_1335;
-let p_1336 = p_950 * p_1_5_6;
-let p_1337 = p_951 * p_1_4_6;
-let p_1338 = p_952 * p_1_3_6;
-let p_1339 = p_821 * p_6_0_6;
-    prob[1][0] += 12_f64 * p_1339;
-    prob[5][0] += 3_f64 * p_1339;
-let p_1340 = p_822 * p_6_0_6;
-    prob[1][1] += 6_f64 * p_1340;
-    prob[5][1] += p_1340;
-let p_1341 = p_954 * p_1_4_6;
-let p_1342 = p_953 * p_3_0_6;
-    prob[0][0] += 12_f64 * p_1342;
-    prob[1][0] += 48_f64 * p_1342;
-    prob[2][0] += 22_f64 * p_1342;
-    prob[5][0] += 18_f64 * p_1342;
-let p_1343 = p_956 * p_1_0_6;
-    prob[0][1] += 2_f64 * p_1343;
-    prob[1][1] += 36_f64 * p_1343;
-    prob[2][1] += 12_f64 * p_1343;
-    prob[5][1] += 12_f64 * p_1343;
-let p_1344 = p_956 * p_2_4_6;
-    prob[1][2] += 10_f64 * p_1344;
-    prob[2][2] += p_1344;
-    prob[5][2] += p_1344;
-let p_1345 = p_955 * p_3_1_6;
-    prob[0][0] += 12_f64 * p_1345;
-    prob[1][0] += 15_f64 * p_1345;
-    prob[2][0] += 26_f64 * p_1345;
-    prob[5][0] += 12_f64 * p_1345;
-let p_1346 = p_957 * p_1_1_6;
-    prob[0][1] += 11_f64 * p_1346;
-    prob[1][1] += 28_f64 * p_1346;
-    prob[2][1] += 29_f64 * p_1346;
-    prob[5][1] += 16_f64 * p_1346;
-let p_1347 = p_958 * p_1_0_6;
-    prob[0][2] += 2_f64 * p_1347;
-    prob[1][2] += 31_f64 * p_1347;
-    prob[2][2] += 15_f64 * p_1347;
-    prob[5][2] += 10_f64 * p_1347;
-let p_1348 = p_958 * p_2_3_6;
-    prob[1][3] += 10_f64 * p_1348;
-    prob[2][3] += 5_f64 * p_1348;
-let p_1349 = p_959 * p_1_2_6;
-    prob[0][1] += 3_f64 * p_1349;
-    prob[2][1] += 3_f64 * p_1349;
-    prob[5][1] += p_1349;
-let p_1350 = p_960 * p_1_1_6;
-    prob[0][2] += 4_f64 * p_1350;
-    prob[1][2] += 12_f64 * p_1350;
-    prob[2][2] += 21_f64 * p_1350;
-    prob[5][2] += 9_f64 * p_1350;
-let p_1351 = p_961 * p_1_0_6;
-    prob[1][3] += 5_f64 * p_1351;
-    prob[2][3] += 9_f64 * p_1351;
-let p_1352 = p_961 * p_2_2_6;
-    prob[1][4] += 10_f64 * p_1352;
-    prob[2][4] += 10_f64 * p_1352;
-let p_1353 = p_962 * p_1_1_6;
-    prob[2][3] += p_1353;
-let p_1354 = p_963 * p_1_0_6;
-    prob[1][4] += 2_f64 * p_1354;
-    prob[2][4] += 8_f64 * p_1354;
-let p_1355 = p_952 * p_4_0_6;
-    prob[0][0] += 12_f64 * p_1355;
-    prob[1][0] += 18_f64 * p_1355;
-    prob[3][0] += 12_f64 * p_1355;
-    prob[5][0] += 9_f64 * p_1355;
-let p_1356 = p_966 * p_1_1_6;
-    prob[0][1] += 8_f64 * p_1356;
-    prob[1][1] += 19_f64 * p_1356;
-    prob[3][1] += 8_f64 * p_1356;
-    prob[5][1] += 8_f64 * p_1356;
-let p_1357 = p_967 * p_1_0_6;
-    prob[1][2] += 11_f64 * p_1357;
-    prob[3][2] += p_1357;
-    prob[5][2] += 2_f64 * p_1357;
-let p_1358 = p_967 * p_2_4_6;
-    prob[1][3] += 10_f64 * p_1358;
-    prob[3][3] += p_1358;
-    prob[5][3] += p_1358;
-let p_1359 = p_965 * p_3_0_6;
-    prob[0][0] += 12_f64 * p_1359;
-    prob[2][0] += 4_f64 * p_1359;
-    prob[3][0] += 5_f64 * p_1359;
-    prob[5][0] += 2_f64 * p_1359;
-let p_1360 = p_968 * p_1_2_6;
-    prob[0][1] += 16_f64 * p_1360;
-    prob[1][1] += 6_f64 * p_1360;
-    prob[2][1] += 8_f64 * p_1360;
-    prob[3][1] += 8_f64 * p_1360;
-    prob[5][1] += 6_f64 * p_1360;
-let p_1361 = p_969 * p_1_1_6;
-    prob[0][2] += 12_f64 * p_1361;
-    prob[1][2] += 36_f64 * p_1361;
-    prob[2][2] += 14_f64 * p_1361;
-    prob[3][2] += 11_f64 * p_1361;
-    prob[5][2] += 14_f64 * p_1361;
-let p_1362 = p_970 * p_1_0_6;
-    prob[0][3] += 2_f64 * p_1362;
-    prob[1][3] += 43_f64 * p_1362;
-    prob[2][3] += 18_f64 * p_1362;
-    prob[3][3] += 7_f64 * p_1362;
-    prob[5][3] += 10_f64 * p_1362;
-let p_1363 = p_970 * p_2_3_6;
-    prob[1][4] += 20_f64 * p_1363;
-    prob[2][4] += 5_f64 * p_1363;
-    prob[3][4] += 5_f64 * p_1363;
-let p_1364 = p_971 * p_1_2_6;
-    prob[0][2] += 10_f64 * p_1364;
-    prob[2][2] += 6_f64 * p_1364;
-    prob[3][2] += 4_f64 * p_1364;
-    prob[5][2] += 3_f64 * p_1364;
-let p_1365 = p_972 * p_1_1_6;
-    prob[0][3] += 4_f64 * p_1365;
-    prob[1][3] += 14_f64 * p_1365;
-    prob[2][3] += 24_f64 * p_1365;
-    prob[3][3] += 3_f64 * p_1365;
-    prob[5][3] += 9_f64 * p_1365;
-let p_1366 = p_973 * p_1_0_6;
-    prob[1][4] += 21_f64 * p_1366;
-    prob[2][4] += 21_f64 * p_1366;
-    prob[3][4] += 12_f64 * p_1366;
-let p_1367 = p_974 * p_1_1_6;
-    prob[2][4] += 3_f64 * p_1367;
-    prob[3][4] += p_1367;
-let p_1368 = p_976 * p_1_3_6;
-    prob[0][1] += 2_f64 * p_1368;
-    prob[3][1] += p_1368;
-let p_1369 = p_977 * p_1_2_6;
-    prob[0][2] += 4_f64 * p_1369;
-    prob[1][2] += 2_f64 * p_1369;
-    prob[3][2] += 4_f64 * p_1369;
-    prob[5][2] += 2_f64 * p_1369;
-let p_1370 = p_978 * p_1_1_6;
-    prob[0][3] += 5_f64 * p_1370;
-    prob[1][3] += 16_f64 * p_1370;
-    prob[3][3] += 11_f64 * p_1370;
-    prob[5][3] += 7_f64 * p_1370;
-let p_1371 = p_979 * p_1_0_6;
-    prob[1][4] += 9_f64 * p_1371;
-    prob[3][4] += 7_f64 * p_1371;
-let p_1372 = p_980 * p_1_2_6;
-    prob[0][3] += 7_f64 * p_1372;
-    prob[2][3] += 4_f64 * p_1372;
-    prob[3][3] += 6_f64 * p_1372;
-    prob[5][3] += 2_f64 * p_1372;
-let p_1373 = p_981 * p_1_1_6;
-    prob[1][4] += 3_f64 * p_1373;
-    prob[2][4] += 5_f64 * p_1373;
-    prob[3][4] += 10_f64 * p_1373;
-let p_1374 = p_984 * p_1_2_6;
-    prob[3][4] += p_1374;
-let p_1375 = p_951 * p_5_0_6;
-    prob[0][0] += 8_f64 * p_1375;
-    prob[1][0] += 3_f64 * p_1375;
-    prob[4][0] += 10_f64 * p_1375;
-    prob[5][0] += 6_f64 * p_1375;
-let p_1376 = p_986 * p_1_2_6;
-    prob[0][1] += 6_f64 * p_1376;
-    prob[1][1] += 5_f64 * p_1376;
-    prob[4][1] += 8_f64 * p_1376;
-    prob[5][1] += 5_f64 * p_1376;
-let p_1377 = p_987 * p_1_1_6;
-    prob[0][2] += 3_f64 * p_1377;
-    prob[1][2] += 9_f64 * p_1377;
-    prob[4][2] += p_1377;
-    prob[5][2] += p_1377;
-let p_1378 = p_988 * p_1_0_6;
-    prob[1][3] += 14_f64 * p_1378;
-    prob[4][3] += 2_f64 * p_1378;
-    prob[5][3] += 2_f64 * p_1378;
-let p_1379 = p_988 * p_2_4_6;
-    prob[1][4] += 10_f64 * p_1379;
-    prob[4][4] += p_1379;
-    prob[5][4] += p_1379;
-let p_1380 = p_989 * p_1_3_6;
-    prob[0][1] += 4_f64 * p_1380;
-    prob[2][1] += p_1380;
-    prob[4][1] += 2_f64 * p_1380;
-    prob[5][1] += p_1380;
-let p_1381 = p_990 * p_1_2_6;
-    prob[0][2] += 8_f64 * p_1381;
-    prob[1][2] += 6_f64 * p_1381;
-    prob[2][2] += 5_f64 * p_1381;
-    prob[4][2] += 8_f64 * p_1381;
-    prob[5][2] += 7_f64 * p_1381;
-let p_1382 = p_991 * p_1_1_6;
-    prob[0][3] += 7_f64 * p_1382;
-    prob[1][3] += 23_f64 * p_1382;
-    prob[2][3] += 14_f64 * p_1382;
-    prob[4][3] += 3_f64 * p_1382;
-    prob[5][3] += 7_f64 * p_1382;
-let p_1383 = p_992 * p_1_0_6;
-    prob[0][4] += 2_f64 * p_1383;
-    prob[1][4] += 46_f64 * p_1383;
-    prob[2][4] += 18_f64 * p_1383;
-    prob[4][4] += 8_f64 * p_1383;
-    prob[5][4] += 10_f64 * p_1383;
-let p_1384 = p_993 * p_1_2_6;
-    prob[0][3] += 3_f64 * p_1384;
-    prob[2][3] += 5_f64 * p_1384;
-    prob[4][3] += p_1384;
-    prob[5][3] += p_1384;
-let p_1385 = p_994 * p_1_1_6;
-    prob[0][4] += 4_f64 * p_1385;
-    prob[1][4] += 14_f64 * p_1385;
-    prob[2][4] += 28_f64 * p_1385;
-    prob[4][4] += 5_f64 * p_1385;
-    prob[5][4] += 9_f64 * p_1385;
-let p_1386 = p_997 * p_1_3_6;
-    prob[0][2] += 3_f64 * p_1386;
-    prob[3][2] += p_1386;
-    prob[4][2] += 2_f64 * p_1386;
-    prob[5][2] += p_1386;
-let p_1387 = p_998 * p_1_2_6;
-    prob[0][3] += 7_f64 * p_1387;
-    prob[1][3] += 6_f64 * p_1387;
-    prob[3][3] += 6_f64 * p_1387;
-    prob[4][3] += 10_f64 * p_1387;
-    prob[5][3] += 7_f64 * p_1387;
-let p_1388 = p_999 * p_1_1_6;
-    prob[0][4] += 5_f64 * p_1388;
-    prob[1][4] += 23_f64 * p_1388;
-    prob[3][4] += 15_f64 * p_1388;
-    prob[4][4] += 7_f64 * p_1388;
-    prob[5][4] += 7_f64 * p_1388;
-let p_1389 = p_1001 * p_1_2_6;
-    prob[0][4] += 7_f64 * p_1389;
-    prob[2][4] += 5_f64 * p_1389;
-    prob[3][4] += 6_f64 * p_1389;
-    prob[4][4] += 2_f64 * p_1389;
-    prob[5][4] += 2_f64 * p_1389;
-let p_1390 = p_1004 * p_1_3_6;
-    prob[0][3] += p_1390;
-    prob[4][3] += 2_f64 * p_1390;
-    prob[5][3] += p_1390;
-let p_1391 = p_1005 * p_1_2_6;
-    prob[0][4] += 3_f64 * p_1391;
-    prob[1][4] += 3_f64 * p_1391;
-    prob[4][4] += 10_f64 * p_1391;
-    prob[5][4] += 5_f64 * p_1391;
-let p_1392 = p_950 * p_6_1_6;
-    prob[5][0] += p_1392;
-let p_1393 = p_1008 * p_1_3_6;
-    prob[0][1] += 4_f64 * p_1393;
-    prob[1][1] += p_1393;
-    prob[5][1] += p_1393;
-let p_1394 = p_1009 * p_1_2_6;
-    prob[0][2] += 3_f64 * p_1394;
-    prob[1][2] += 3_f64 * p_1394;
-let p_1395 = p_1010 * p_1_1_6;
-    prob[0][3] += 3_f64 * p_1395;
-    prob[1][3] += 6_f64 * p_1395;
-    prob[5][3] += p_1395;
-let p_1396 = p_1011 * p_1_0_6;
-    prob[1][4] += 7_f64 * p_1396;
-    prob[5][4] += 2_f64 * p_1396;
-let p_1397 = p_1012 * p_1_3_6;
-    prob[0][2] += 3_f64 * p_1397;
-    prob[2][2] += p_1397;
-    prob[5][2] += p_1397;
-let p_1398 = p_1013 * p_1_2_6;
-    prob[0][3] += 5_f64 * p_1398;
-    prob[1][3] += 4_f64 * p_1398;
-    prob[2][3] += 5_f64 * p_1398;
-    prob[5][3] += 2_f64 * p_1398;
-let p_1399 = p_1014 * p_1_1_6;
-    prob[0][4] += 7_f64 * p_1399;
-    prob[1][4] += 17_f64 * p_1399;
-    prob[2][4] += 11_f64 * p_1399;
-    prob[5][4] += 7_f64 * p_1399;
-let p_1400 = p_1016 * p_1_2_6;
-    prob[0][4] += 3_f64 * p_1400;
-    prob[2][4] += 3_f64 * p_1400;
-    prob[5][4] += p_1400;
-let p_1401 = p_1018 * p_1_3_6;
-    prob[0][3] += 2_f64 * p_1401;
-    prob[3][3] += p_1401;
-    prob[5][3] += p_1401;
-let p_1402 = p_1019 * p_1_2_6;
-    prob[0][4] += 4_f64 * p_1402;
-    prob[1][4] += 2_f64 * p_1402;
-    prob[3][4] += 4_f64 * p_1402;
-    prob[5][4] += 2_f64 * p_1402;
-let p_1403 = p_1022 * p_1_3_6;
-    prob[0][4] += p_1403;
-    prob[4][4] += 2_f64 * p_1403;
-    prob[5][4] += 2_f64 * p_1403;
-let p_1404 = p_1025 * p_1_5_6;
-let p_1405 = p_820 * p_7_0_6;
-    prob[0][0] += 6_f64 * p_1405;
-    prob[1][0] += 25_f64 * p_1405;
-    prob[6][0] += 6_f64 * p_1405;
-let p_1406 = p_1027 * p_1_0_6;
-    prob[0][1] += p_1406;
-    prob[1][1] += 11_f64 * p_1406;
-    prob[6][1] += 3_f64 * p_1406;
-let p_1407 = p_1027 * p_2_5_6;
-    prob[1][2] += p_1407;
-let p_1408 = p_825 * p_7_0_6;
-    prob[0][0] += 30_f64 * p_1408;
-    prob[1][0] += 38_f64 * p_1408;
-    prob[2][0] += 19_f64 * p_1408;
-    prob[6][0] += 12_f64 * p_1408;
-let p_1409 = p_1028 * p_1_1_6;
-    prob[0][1] += 16_f64 * p_1409;
-    prob[1][1] += 39_f64 * p_1409;
-    prob[2][1] += 12_f64 * p_1409;
-    prob[6][1] += 12_f64 * p_1409;
-let p_1410 = p_1029 * p_1_0_6;
-    prob[0][2] += p_1410;
-    prob[1][2] += 15_f64 * p_1410;
-    prob[2][2] += p_1410;
-    prob[6][2] += 3_f64 * p_1410;
-let p_1411 = p_1029 * p_2_4_6;
-    prob[1][3] += 5_f64 * p_1411;
-    prob[2][3] += p_1411;
-let p_1412 = p_828 * p_7_0_6;
-    prob[0][0] += 12_f64 * p_1412;
-    prob[2][0] += 7_f64 * p_1412;
-    prob[6][0] += p_1412;
-let p_1413 = p_1030 * p_1_2_6;
-    prob[0][1] += 19_f64 * p_1413;
-    prob[1][1] += 5_f64 * p_1413;
-    prob[2][1] += 12_f64 * p_1413;
-    prob[6][1] += 3_f64 * p_1413;
-let p_1414 = p_1031 * p_1_1_6;
-    prob[0][2] += 11_f64 * p_1414;
-    prob[1][2] += 25_f64 * p_1414;
-    prob[2][2] += 12_f64 * p_1414;
-    prob[6][2] += 9_f64 * p_1414;
-let p_1415 = p_1032 * p_1_0_6;
-    prob[1][3] += 6_f64 * p_1415;
-    prob[2][3] += 4_f64 * p_1415;
-let p_1416 = p_1032 * p_2_3_6;
-    prob[1][4] += 10_f64 * p_1416;
-    prob[2][4] += 5_f64 * p_1416;
-let p_1417 = p_1033 * p_1_2_6;
-    prob[0][2] += 7_f64 * p_1417;
-    prob[2][2] += 5_f64 * p_1417;
-    prob[6][2] += p_1417;
-let p_1418 = p_1034 * p_1_1_6;
-    prob[1][3] += p_1418;
-    prob[2][3] += 3_f64 * p_1418;
-let p_1419 = p_1035 * p_1_0_6;
-    prob[1][4] += 5_f64 * p_1419;
-    prob[2][4] += 9_f64 * p_1419;
-let p_1420 = p_1036 * p_1_1_6;
-    prob[2][4] += p_1420;
-let p_1421 = p_1026 * p_4_0_6;
-    prob[0][0] += 20_f64 * p_1421;
-    prob[1][0] += 5_f64 * p_1421;
-    prob[3][0] += 13_f64 * p_1421;
-    prob[6][0] += 6_f64 * p_1421;
-let p_1422 = p_1038 * p_1_2_6;
-    prob[0][1] += 17_f64 * p_1422;
-    prob[1][1] += 11_f64 * p_1422;
-    prob[3][1] += 10_f64 * p_1422;
-    prob[6][1] += 6_f64 * p_1422;
-let p_1423 = p_1039 * p_1_1_6;
-    prob[0][2] += 5_f64 * p_1423;
-    prob[1][2] += 14_f64 * p_1423;
-    prob[6][2] += 3_f64 * p_1423;
-let p_1424 = p_1040 * p_1_0_6;
-    prob[0][3] += p_1424;
-    prob[1][3] += 15_f64 * p_1424;
-    prob[3][3] += p_1424;
-    prob[6][3] += 3_f64 * p_1424;
-let p_1425 = p_1040 * p_2_4_6;
-    prob[1][4] += 5_f64 * p_1425;
-    prob[3][4] += p_1425;
-let p_1426 = p_1041 * p_1_3_6;
-    prob[0][1] += 8_f64 * p_1426;
-    prob[2][1] += 2_f64 * p_1426;
-    prob[3][1] += 3_f64 * p_1426;
-    prob[6][1] += p_1426;
-let p_1427 = p_1042 * p_1_2_6;
-    prob[0][2] += 22_f64 * p_1427;
-    prob[1][2] += 8_f64 * p_1427;
-    prob[2][2] += 7_f64 * p_1427;
-    prob[3][2] += 10_f64 * p_1427;
-    prob[6][2] += 7_f64 * p_1427;
-let p_1428 = p_1043 * p_1_1_6;
-    prob[0][3] += 11_f64 * p_1428;
-    prob[1][3] += 31_f64 * p_1428;
-    prob[2][3] += 15_f64 * p_1428;
-    prob[3][3] += 3_f64 * p_1428;
-    prob[6][3] += 9_f64 * p_1428;
-let p_1429 = p_1044 * p_1_0_6;
-    prob[1][4] += 18_f64 * p_1429;
-    prob[2][4] += 7_f64 * p_1429;
-    prob[3][4] += 7_f64 * p_1429;
-let p_1430 = p_1045 * p_1_2_6;
-    prob[0][3] += 7_f64 * p_1430;
-    prob[2][3] += 5_f64 * p_1430;
-    prob[6][3] += p_1430;
-let p_1431 = p_1046 * p_1_1_6;
-    prob[1][4] += 3_f64 * p_1431;
-    prob[2][4] += 6_f64 * p_1431;
-    prob[3][4] += 3_f64 * p_1431;
-let p_1432 = p_1049 * p_1_3_6;
-    prob[0][2] += 3_f64 * p_1432;
-    prob[3][2] += 3_f64 * p_1432;
-    prob[6][2] += p_1432;
-let p_1433 = p_1050 * p_1_2_6;
-    prob[0][3] += 10_f64 * p_1433;
-    prob[1][3] += 3_f64 * p_1433;
-    prob[3][3] += 10_f64 * p_1433;
-    prob[6][3] += 5_f64 * p_1433;
-let p_1434 = p_1051 * p_1_1_6;
-    prob[1][4] += 3_f64 * p_1434;
-    prob[3][4] += 3_f64 * p_1434;
-let p_1435 = p_1053 * p_1_2_6;
-    prob[2][4] += p_1435;
-    prob[3][4] += 2_f64 * p_1435;
-let p_1436 = p_1025 * p_5_0_6;
-    prob[0][0] += 2_f64 * p_1436;
-    prob[4][0] += 2_f64 * p_1436;
-    prob[6][0] += p_1436;
-let p_1437 = p_1056 * p_1_3_6;
-    prob[0][1] += 8_f64 * p_1437;
-    prob[1][1] += 2_f64 * p_1437;
-    prob[4][1] += 2_f64 * p_1437;
-    prob[6][1] += p_1437;
-let p_1438 = p_1057 * p_1_2_6;
-    prob[0][2] += 7_f64 * p_1438;
-    prob[1][2] += 8_f64 * p_1438;
-    prob[6][2] += p_1438;
-let p_1439 = p_1058 * p_1_1_6;
-    prob[0][3] += 5_f64 * p_1439;
-    prob[1][3] += 17_f64 * p_1439;
-    prob[4][3] += p_1439;
-    prob[6][3] += 3_f64 * p_1439;
-let p_1440 = p_1059 * p_1_0_6;
-    prob[0][4] += p_1440;
-    prob[1][4] += 18_f64 * p_1440;
-    prob[4][4] += 2_f64 * p_1440;
-    prob[6][4] += 3_f64 * p_1440;
-let p_1441 = p_1060 * p_1_3_6;
-    prob[0][2] += 6_f64 * p_1441;
-    prob[2][2] += 2_f64 * p_1441;
-    prob[4][2] += 2_f64 * p_1441;
-    prob[6][2] += p_1441;
-let p_1442 = p_1061 * p_1_2_6;
-    prob[0][3] += 12_f64 * p_1442;
-    prob[1][3] += 5_f64 * p_1442;
-    prob[2][3] += 7_f64 * p_1442;
-    prob[6][3] += 2_f64 * p_1442;
-let p_1443 = p_1062 * p_1_1_6;
-    prob[0][4] += 11_f64 * p_1443;
-    prob[1][4] += 31_f64 * p_1443;
-    prob[2][4] += 15_f64 * p_1443;
-    prob[4][4] += 3_f64 * p_1443;
-    prob[6][4] += 9_f64 * p_1443;
-let p_1444 = p_1064 * p_1_2_6;
-    prob[0][4] += 7_f64 * p_1444;
-    prob[2][4] += 7_f64 * p_1444;
-    prob[4][4] += p_1444;
-    prob[6][4] += p_1444;
-let p_1445 = p_1066 * p_1_3_6;
-    prob[0][3] += 4_f64 * p_1445;
-    prob[3][3] += 3_f64 * p_1445;
-    prob[4][3] += 2_f64 * p_1445;
-    prob[6][3] += 2_f64 * p_1445;
-let p_1446 = p_1067 * p_1_2_6;
-    prob[0][4] += 10_f64 * p_1446;
-    prob[1][4] += 5_f64 * p_1446;
-    prob[3][4] += 12_f64 * p_1446;
-    prob[4][4] += 2_f64 * p_1446;
-    prob[6][4] += 5_f64 * p_1446;
-let p_1447 = p_1070 * p_1_3_6;
-    prob[0][4] += p_1447;
-    prob[4][4] += 2_f64 * p_1447;
-    prob[6][4] += p_1447;
-let p_1448 = p_1072 * p_1_4_6;
-    prob[0][1] += p_1448;
-let p_1449 = p_1073 * p_1_3_6;
-    prob[0][2] += 11_f64 * p_1449;
-    prob[1][2] += 3_f64 * p_1449;
-let p_1450 = p_1074 * p_1_2_6;
-    prob[0][3] += 10_f64 * p_1450;
-    prob[1][3] += 11_f64 * p_1450;
-    prob[6][3] += p_1450;
-let p_1451 = p_1075 * p_1_1_6;
-    prob[0][4] += 8_f64 * p_1451;
-    prob[1][4] += 20_f64 * p_1451;
-    prob[5][4] += p_1451;
-    prob[6][4] += 3_f64 * p_1451;
-let p_1452 = p_1077 * p_1_3_6;
-    prob[0][3] += 8_f64 * p_1452;
-    prob[2][3] += 3_f64 * p_1452;
-let p_1453 = p_1078 * p_1_2_6;
-    prob[0][4] += 17_f64 * p_1453;
-    prob[1][4] += 9_f64 * p_1453;
-    prob[2][4] += 12_f64 * p_1453;
-    prob[5][4] += 2_f64 * p_1453;
-    prob[6][4] += 2_f64 * p_1453;
-let p_1454 = p_1081 * p_1_3_6;
-    prob[0][4] += 5_f64 * p_1454;
-    prob[3][4] += 4_f64 * p_1454;
-    prob[6][4] += p_1454;
-let p_1455 = p_1025 * p_7_1_6;
-    prob[0][2] += p_1455;
-let p_1456 = p_1084 * p_1_3_6;
-    prob[0][3] += 7_f64 * p_1456;
-    prob[1][3] += 2_f64 * p_1456;
-let p_1457 = p_1085 * p_1_2_6;
-    prob[0][4] += 7_f64 * p_1457;
-    prob[1][4] += 8_f64 * p_1457;
-    prob[6][4] += p_1457;
-let p_1458 = p_1087 * p_1_3_6;
-    prob[0][4] += 5_f64 * p_1458;
-    prob[2][4] += 2_f64 * p_1458;
-let p_1459 = p_819 * p_8_0_6;
-    prob[0][0] += 18_f64 * p_1459;
-    prob[1][0] += 25_f64 * p_1459;
-    prob[7][0] += 4_f64 * p_1459;
-let p_1460 = p_1090 * p_1_1_6;
-    prob[0][1] += 7_f64 * p_1460;
-    prob[1][1] += 15_f64 * p_1460;
-    prob[7][1] += 3_f64 * p_1460;
-let p_1461 = p_1091 * p_2_5_6;
-    prob[1][3] += p_1461;
-let p_1462 = p_824 * p_8_0_6;
-    prob[0][0] += 36_f64 * p_1462;
-    prob[1][0] += 10_f64 * p_1462;
-    prob[2][0] += 17_f64 * p_1462;
-    prob[7][0] += 6_f64 * p_1462;
-let p_1463 = p_1092 * p_1_2_6;
-    prob[0][1] += 30_f64 * p_1463;
-    prob[1][1] += 17_f64 * p_1463;
-    prob[2][1] += 13_f64 * p_1463;
-    prob[7][1] += 6_f64 * p_1463;
-let p_1464 = p_1093 * p_1_1_6;
-    prob[0][2] += 7_f64 * p_1464;
-    prob[1][2] += 15_f64 * p_1464;
-    prob[7][2] += 3_f64 * p_1464;
-let p_1465 = p_1094 * p_1_0_6;
-    prob[1][3] += 4_f64 * p_1465;
-    prob[2][3] += p_1465;
-let p_1466 = p_1094 * p_2_4_6;
-    prob[1][4] += 5_f64 * p_1466;
-    prob[2][4] += p_1466;
-let p_1467 = p_1095 * p_1_3_6;
-    prob[0][1] += 7_f64 * p_1467;
-    prob[2][1] += 4_f64 * p_1467;
-    prob[7][1] += p_1467;
-let p_1468 = p_1096 * p_1_2_6;
-    prob[0][2] += 19_f64 * p_1468;
-    prob[1][2] += 7_f64 * p_1468;
-    prob[2][2] += 13_f64 * p_1468;
-    prob[7][2] += 5_f64 * p_1468;
-let p_1469 = p_1098 * p_1_0_6;
-    prob[1][4] += 6_f64 * p_1469;
-    prob[2][4] += 4_f64 * p_1469;
-let p_1470 = p_1100 * p_1_1_6;
-    prob[1][4] += p_1470;
-    prob[2][4] += 3_f64 * p_1470;
-let p_1471 = p_840 * p_8_0_6;
-    prob[0][0] += 6_f64 * p_1471;
-    prob[3][0] += 3_f64 * p_1471;
-    prob[7][0] += p_1471;
-let p_1472 = p_1103 * p_1_3_6;
-    prob[0][1] += 14_f64 * p_1472;
-    prob[1][1] += 3_f64 * p_1472;
-    prob[3][1] += 3_f64 * p_1472;
-    prob[7][1] += p_1472;
-let p_1473 = p_1104 * p_1_2_6;
-    prob[0][2] += 11_f64 * p_1473;
-    prob[1][2] += 10_f64 * p_1473;
-    prob[7][2] += p_1473;
-let p_1474 = p_1105 * p_1_1_6;
-    prob[0][3] += 7_f64 * p_1474;
-    prob[1][3] += 15_f64 * p_1474;
-    prob[7][3] += 3_f64 * p_1474;
-let p_1475 = p_1106 * p_1_0_6;
-    prob[1][4] += 4_f64 * p_1475;
-    prob[3][4] += p_1475;
-let p_1476 = p_1107 * p_1_3_6;
-    prob[0][2] += 11_f64 * p_1476;
-    prob[2][2] += 4_f64 * p_1476;
-    prob[3][2] += 3_f64 * p_1476;
-    prob[7][2] += 2_f64 * p_1476;
-let p_1477 = p_1108 * p_1_2_6;
-    prob[0][3] += 19_f64 * p_1477;
-    prob[1][3] += 7_f64 * p_1477;
-    prob[2][3] += 13_f64 * p_1477;
-    prob[7][3] += 5_f64 * p_1477;
-let p_1478 = p_1109 * p_1_1_6;
-    prob[1][4] += 6_f64 * p_1478;
-    prob[2][4] += 3_f64 * p_1478;
-    prob[3][4] += 3_f64 * p_1478;
-let p_1479 = p_1113 * p_1_3_6;
-    prob[0][3] += 4_f64 * p_1479;
-    prob[3][3] += 3_f64 * p_1479;
-    prob[7][3] += p_1479;
-let p_1480 = p_1117 * p_1_4_6;
-    prob[0][1] += 2_f64 * p_1480;
-let p_1481 = p_1118 * p_1_3_6;
-    prob[0][2] += 10_f64 * p_1481;
-    prob[1][2] += 3_f64 * p_1481;
-let p_1482 = p_1119 * p_1_2_6;
-    prob[0][3] += 11_f64 * p_1482;
-    prob[1][3] += 10_f64 * p_1482;
-    prob[7][3] += p_1482;
-let p_1483 = p_1120 * p_1_1_6;
-    prob[0][4] += 7_f64 * p_1483;
-    prob[1][4] += 18_f64 * p_1483;
-    prob[4][4] += p_1483;
-    prob[7][4] += 3_f64 * p_1483;
-let p_1484 = p_1122 * p_1_3_6;
-    prob[0][3] += 7_f64 * p_1484;
-    prob[2][3] += 4_f64 * p_1484;
-    prob[7][3] += p_1484;
-let p_1485 = p_1123 * p_1_2_6;
-    prob[0][4] += 19_f64 * p_1485;
-    prob[1][4] += 7_f64 * p_1485;
-    prob[2][4] += 13_f64 * p_1485;
-    prob[7][4] += 5_f64 * p_1485;
-let p_1486 = p_1126 * p_1_3_6;
-    prob[0][4] += 4_f64 * p_1486;
-    prob[3][4] += 3_f64 * p_1486;
-    prob[7][4] += p_1486;
-let p_1487 = p_1129 * p_1_4_6;
-    prob[0][2] += 2_f64 * p_1487;
-let p_1488 = p_1130 * p_1_3_6;
-    prob[0][3] += 14_f64 * p_1488;
-    prob[1][3] += 4_f64 * p_1488;
-let p_1489 = p_1131 * p_1_2_6;
-    prob[0][4] += 14_f64 * p_1489;
-    prob[1][4] += 13_f64 * p_1489;
-    prob[7][4] += p_1489;
-let p_1490 = p_1133 * p_1_3_6;
-    prob[0][4] += 10_f64 * p_1490;
-    prob[2][4] += 5_f64 * p_1490;
-    prob[7][4] += p_1490;
-let p_1491 = p_1136 * p_1_4_6;
-    prob[0][3] += 3_f64 * p_1491;
-let p_1492 = p_1137 * p_1_3_6;
-    prob[0][4] += 17_f64 * p_1492;
-    prob[1][4] += 5_f64 * p_1492;
-let p_1493 = p_1140 * p_1_4_6;
-    prob[0][4] += 2_f64 * p_1493;
-let p_1494 = p_818 * p_9_0_6;
-    prob[0][0] += 28_f64 * p_1494;
-    prob[1][0] += 21_f64 * p_1494;
-    prob[8][0] += 5_f64 * p_1494;
-let p_1495 = p_1142 * p_1_2_6;
-    prob[0][1] += 15_f64 * p_1495;
-    prob[1][1] += 16_f64 * p_1495;
-    prob[8][1] += 4_f64 * p_1495;
-let p_1496 = p_1144 * p_2_5_6;
-    prob[1][4] += p_1496;
-let p_1497 = p_823 * p_9_0_6;
-    prob[0][0] += 12_f64 * p_1497;
-    prob[2][0] += 4_f64 * p_1497;
-    prob[8][0] += p_1497;
-let p_1498 = p_1145 * p_1_3_6;
-    prob[0][1] += 22_f64 * p_1498;
-    prob[1][1] += 5_f64 * p_1498;
-    prob[2][1] += 4_f64 * p_1498;
-    prob[8][1] += 2_f64 * p_1498;
-let p_1499 = p_1146 * p_1_2_6;
-    prob[0][2] += 15_f64 * p_1499;
-    prob[1][2] += 16_f64 * p_1499;
-    prob[8][2] += 4_f64 * p_1499;
-let p_1500 = p_1148 * p_1_0_6;
-    prob[1][4] += 4_f64 * p_1500;
-    prob[2][4] += p_1500;
-let p_1501 = p_1149 * p_1_3_6;
-    prob[0][2] += 9_f64 * p_1501;
-    prob[2][2] += 4_f64 * p_1501;
-    prob[8][2] += p_1501;
-let p_1502 = p_1153 * p_1_4_6;
-    prob[0][1] += 3_f64 * p_1502;
-let p_1503 = p_1154 * p_1_3_6;
-    prob[0][2] += 13_f64 * p_1503;
-    prob[1][2] += 5_f64 * p_1503;
-    prob[8][2] += p_1503;
-let p_1504 = p_1155 * p_1_2_6;
-    prob[0][3] += 15_f64 * p_1504;
-    prob[1][3] += 16_f64 * p_1504;
-    prob[8][3] += 4_f64 * p_1504;
-let p_1505 = p_1158 * p_1_3_6;
-    prob[0][3] += 9_f64 * p_1505;
-    prob[2][3] += 4_f64 * p_1505;
-    prob[8][3] += p_1505;
-let p_1506 = p_1162 * p_1_4_6;
-    prob[0][2] += 3_f64 * p_1506;
-let p_1507 = p_1163 * p_1_3_6;
-    prob[0][3] += 13_f64 * p_1507;
-    prob[1][3] += 5_f64 * p_1507;
-    prob[8][3] += p_1507;
-let p_1508 = p_1164 * p_1_2_6;
-    prob[0][4] += 15_f64 * p_1508;
-    prob[1][4] += 16_f64 * p_1508;
-    prob[8][4] += 4_f64 * p_1508;
-let p_1509 = p_1166 * p_1_3_6;
-    prob[0][4] += 9_f64 * p_1509;
-    prob[2][4] += 4_f64 * p_1509;
-    prob[8][4] += p_1509;
-let p_1510 = p_1169 * p_1_4_6;
-    prob[0][3] += 3_f64 * p_1510;
-let p_1511 = p_1170 * p_1_3_6;
-    prob[0][4] += 17_f64 * p_1511;
-    prob[1][4] += 6_f64 * p_1511;
-    prob[8][4] += p_1511;
-let p_1512 = p_1173 * p_1_4_6;
-    prob[0][4] += 4_f64 * p_1512;
-let p_1513 = p_817 * p_10_0_6;
-    prob[0][0] += 20_f64 * p_1513;
-    prob[1][0] += 5_f64 * p_1513;
-    prob[9][0] += p_1513;
-let p_1514 = p_1176 * p_1_3_6;
-    prob[0][1] += 16_f64 * p_1514;
-    prob[1][1] += 5_f64 * p_1514;
-    prob[9][1] += p_1514;
-let p_1515 = p_1179 * p_1_4_6;
-    prob[0][1] += 4_f64 * p_1515;
-let p_1516 = p_1180 * p_1_3_6;
-    prob[0][2] += 16_f64 * p_1516;
-    prob[1][2] += 5_f64 * p_1516;
-    prob[9][2] += p_1516;
-let p_1517 = p_1184 * p_1_4_6;
-    prob[0][2] += 4_f64 * p_1517;
-let p_1518 = p_1185 * p_1_3_6;
-    prob[0][3] += 16_f64 * p_1518;
-    prob[1][3] += 5_f64 * p_1518;
-    prob[9][3] += p_1518;
-let p_1519 = p_1188 * p_1_4_6;
-    prob[0][3] += 4_f64 * p_1519;
-let p_1520 = p_1189 * p_1_3_6;
-    prob[0][4] += 16_f64 * p_1520;
-    prob[1][4] += 5_f64 * p_1520;
-    prob[9][4] += p_1520;
-let p_1521 = p_1192 * p_1_4_6;
-    prob[0][4] += 4_f64 * p_1521;
-let p_1522 = p_1195 * p_1_7_7;
-let p_1523 = p_1196 * p_1_6_7;
-let p_1524 = p_1197 * p_1_5_7;
-let p_1525 = p_1198 * p_1_4_7;
-let p_1526 = p_1200 * p_1_1_7;
-let p_1527 = p_1201 * p_1_0_7;
-let p_1528 = p_1201 * p_2_7_7;
-let p_1529 = p_1202 * p_1_6_7;
-let p_1530 = p_1204 * p_1_2_7;
-let p_1531 = p_1205 * p_1_1_7;
-let p_1532 = p_1206 * p_1_0_7;
-let p_1533 = p_1206 * p_2_6_7;
-    prob[1][0] += 7_f64 * p_1533;
-    prob[2][0] += p_1533;
-let p_1534 = p_1207 * p_1_2_7;
-let p_1535 = p_1208 * p_1_1_7;
-let p_1536 = p_1209 * p_1_0_7;
-    prob[1][0] += 44_f64 * p_1536;
-    prob[2][0] += 18_f64 * p_1536;
-let p_1537 = p_1209 * p_2_5_7;
-    prob[1][1] += 21_f64 * p_1537;
-    prob[2][1] += 7_f64 * p_1537;
-let p_1538 = p_1210 * p_1_3_7;
-let p_1539 = p_1211 * p_1_2_7;
-let p_1540 = p_1212 * p_1_1_7;
-    prob[1][0] += 33_f64 * p_1540;
-    prob[2][0] += 39_f64 * p_1540;
-let p_1541 = p_1213 * p_1_0_7;
-    prob[1][1] += 41_f64 * p_1541;
-    prob[2][1] += 33_f64 * p_1541;
-let p_1542 = p_1213 * p_2_4_7;
-    prob[1][2] += 15_f64 * p_1542;
-    prob[2][2] += 6_f64 * p_1542;
-let p_1543 = p_1214 * p_1_3_7;
-let p_1544 = p_1215 * p_1_2_7;
-    prob[1][0] += 4_f64 * p_1544;
-    prob[2][0] += 16_f64 * p_1544;
-let p_1545 = p_1216 * p_1_1_7;
-    prob[1][1] += 9_f64 * p_1545;
-    prob[2][1] += 24_f64 * p_1545;
-let p_1546 = p_1217 * p_1_0_7;
-    prob[1][2] += 21_f64 * p_1546;
-    prob[2][2] += 23_f64 * p_1546;
-let p_1547 = p_1218 * p_1_2_7;
-    prob[2][1] += p_1547;
-let p_1548 = p_1219 * p_1_1_7;
-    prob[1][2] += 3_f64 * p_1548;
-    prob[2][2] += 12_f64 * p_1548;
-let p_1549 = p_1223 * p_1_2_7;
-let p_1550 = p_1224 * p_1_1_7;
-let p_1551 = p_1225 * p_1_0_7;
-    prob[1][0] += 16_f64 * p_1551;
-    prob[3][0] += 3_f64 * p_1551;
-let p_1552 = p_1225 * p_2_6_7;
-    prob[1][1] += 7_f64 * p_1552;
-    prob[3][1] += p_1552;
-let p_1553 = p_1226 * p_1_3_7;
-let p_1554 = p_1227 * p_1_2_7;
-let p_1555 = p_1228 * p_1_1_7;
-    prob[1][0] += 78_f64 * p_1555;
-    prob[2][0] += 24_f64 * p_1555;
-    prob[3][0] += 24_f64 * p_1555;
-let p_1556 = p_1229 * p_1_0_7;
-    prob[1][1] += 64_f64 * p_1556;
-    prob[2][1] += 15_f64 * p_1556;
-    prob[3][1] += 15_f64 * p_1556;
-let p_1557 = p_1229 * p_2_5_7;
-    prob[1][2] += 12_f64 * p_1557;
-    prob[2][2] += p_1557;
-    prob[3][2] += p_1557;
-let p_1558 = p_1230 * p_1_3_7;
-let p_1559 = p_1231 * p_1_2_7;
-    prob[1][0] += 39_f64 * p_1559;
-    prob[2][0] += 39_f64 * p_1559;
-    prob[3][0] += 24_f64 * p_1559;
-let p_1560 = p_1232 * p_1_1_7;
-    prob[1][1] += 60_f64 * p_1560;
-    prob[2][1] += 44_f64 * p_1560;
-    prob[3][1] += 28_f64 * p_1560;
-let p_1561 = p_1233 * p_1_0_7;
-    prob[1][2] += 66_f64 * p_1561;
-    prob[2][2] += 23_f64 * p_1561;
-    prob[3][2] += 13_f64 * p_1561;
-let p_1562 = p_1233 * p_2_4_7;
-    prob[1][3] += 15_f64 * p_1562;
-    prob[2][3] += 6_f64 * p_1562;
-let p_1563 = p_1234 * p_1_3_7;
-    prob[2][0] += 3_f64 * p_1563;
-    prob[3][0] += p_1563;
-let p_1564 = p_1235 * p_1_2_7;
-    prob[1][1] += 4_f64 * p_1564;
-    prob[2][1] += 12_f64 * p_1564;
-    prob[3][1] += 4_f64 * p_1564;
-let p_1565 = p_1236 * p_1_1_7;
-    prob[1][2] += 36_f64 * p_1565;
-    prob[2][2] += 42_f64 * p_1565;
-    prob[3][2] += 18_f64 * p_1565;
-let p_1566 = p_1237 * p_1_0_7;
-    prob[1][3] += 21_f64 * p_1566;
-    prob[2][3] += 23_f64 * p_1566;
-let p_1567 = p_1238 * p_1_2_7;
-    prob[2][2] += 4_f64 * p_1567;
-    prob[3][2] += p_1567;
-let p_1568 = p_1239 * p_1_1_7;
-    prob[1][3] += 3_f64 * p_1568;
-    prob[2][3] += 12_f64 * p_1568;
-let p_1569 = p_1242 * p_1_3_7;
-let p_1570 = p_1243 * p_1_2_7;
-    prob[1][0] += 15_f64 * p_1570;
-    prob[3][0] += 13_f64 * p_1570;
-let p_1571 = p_1244 * p_1_1_7;
-    prob[1][1] += 16_f64 * p_1571;
-    prob[3][1] += 11_f64 * p_1571;
-let p_1572 = p_1245 * p_1_0_7;
-    prob[1][2] += 9_f64 * p_1572;
-    prob[3][2] += 2_f64 * p_1572;
-let p_1573 = p_1245 * p_2_5_7;
-    prob[1][3] += 6_f64 * p_1573;
-    prob[3][3] += p_1573;
-let p_1574 = p_1246 * p_1_4_7;
-let p_1575 = p_1247 * p_1_3_7;
-    prob[1][0] += 3_f64 * p_1575;
-    prob[2][0] += 7_f64 * p_1575;
-    prob[3][0] += 14_f64 * p_1575;
-let p_1576 = p_1248 * p_1_2_7;
-    prob[1][1] += 12_f64 * p_1576;
-    prob[2][1] += 9_f64 * p_1576;
-    prob[3][1] += 18_f64 * p_1576;
-let p_1577 = p_1249 * p_1_1_7;
-    prob[1][2] += 36_f64 * p_1577;
-    prob[2][2] += 10_f64 * p_1577;
-    prob[3][2] += 20_f64 * p_1577;
-let p_1578 = p_1250 * p_1_0_7;
-    prob[1][3] += 46_f64 * p_1578;
-    prob[2][3] += 13_f64 * p_1578;
-    prob[3][3] += 13_f64 * p_1578;
-let p_1579 = p_1251 * p_1_3_7;
-    prob[2][1] += p_1579;
-    prob[3][1] += 2_f64 * p_1579;
-let p_1580 = p_1252 * p_1_2_7;
-    prob[1][2] += 6_f64 * p_1580;
-    prob[2][2] += 12_f64 * p_1580;
-    prob[3][2] += 15_f64 * p_1580;
-let p_1581 = p_1253 * p_1_1_7;
-    prob[1][3] += 30_f64 * p_1581;
-    prob[2][3] += 30_f64 * p_1581;
-    prob[3][3] += 18_f64 * p_1581;
-let p_1582 = p_1255 * p_1_2_7;
-    prob[2][3] += 3_f64 * p_1582;
-    prob[3][3] += p_1582;
-let p_1583 = p_1257 * p_1_4_7;
-    prob[3][0] += p_1583;
-let p_1584 = p_1258 * p_1_3_7;
-    prob[3][1] += p_1584;
-let p_1585 = p_1259 * p_1_2_7;
-    prob[1][2] += 3_f64 * p_1585;
-    prob[3][2] += 3_f64 * p_1585;
-let p_1586 = p_1260 * p_1_1_7;
-    prob[1][3] += 12_f64 * p_1586;
-    prob[3][3] += 10_f64 * p_1586;
-let p_1587 = p_1262 * p_1_3_7;
-    prob[2][2] += p_1587;
-    prob[3][2] += 3_f64 * p_1587;
-let p_1588 = p_1263 * p_1_2_7;
-    prob[1][3] += 3_f64 * p_1588;
-    prob[2][3] += 6_f64 * p_1588;
-    prob[3][3] += 12_f64 * p_1588;
-let p_1589 = p_1266 * p_1_3_7;
-    prob[3][3] += p_1589;
-let p_1590 = p_1270 * p_1_3_7;
-let p_1591 = p_1271 * p_1_2_7;
-let p_1592 = p_1271 * p_2_4_7;
-    prob[1][0] += 22_f64 * p_1592;
-    prob[4][0] += 6_f64 * p_1592;
-let p_1593 = p_1272 * p_1_0_7;
-    prob[1][1] += 13_f64 * p_1593;
-    prob[4][1] += 3_f64 * p_1593;
-let p_1594 = p_1272 * p_2_6_7;
-    prob[1][2] += p_1594;
-let p_1595 = p_1273 * p_1_3_7;
-let p_1596 = p_1273 * p_2_2_7;
-    prob[1][0] += 39_f64 * p_1596;
-    prob[2][0] += 13_f64 * p_1596;
-    prob[4][0] += 16_f64 * p_1596;
-let p_1597 = p_1274 * p_1_1_7;
-    prob[1][1] += 44_f64 * p_1597;
-    prob[2][1] += 11_f64 * p_1597;
-    prob[4][1] += 15_f64 * p_1597;
-let p_1598 = p_1275 * p_1_0_7;
-    prob[1][2] += 21_f64 * p_1598;
-    prob[2][2] += 2_f64 * p_1598;
-    prob[4][2] += 3_f64 * p_1598;
-let p_1599 = p_1275 * p_2_5_7;
-    prob[1][3] += 6_f64 * p_1599;
-    prob[2][3] += p_1599;
-let p_1600 = p_1276 * p_1_4_7;
-let p_1601 = p_1276 * p_2_0_7;
-    prob[1][0] += 3_f64 * p_1601;
-    prob[2][0] += 14_f64 * p_1601;
-    prob[4][0] += 7_f64 * p_1601;
-let p_1602 = p_1277 * p_1_2_7;
-    prob[1][1] += 9_f64 * p_1602;
-    prob[2][1] += 15_f64 * p_1602;
-    prob[4][1] += 9_f64 * p_1602;
-let p_1603 = p_1278 * p_1_1_7;
-    prob[1][2] += 36_f64 * p_1603;
-    prob[2][2] += 14_f64 * p_1603;
-    prob[4][2] += 12_f64 * p_1603;
-let p_1604 = p_1279 * p_1_0_7;
-    prob[1][3] += 20_f64 * p_1604;
-    prob[2][3] += 10_f64 * p_1604;
-let p_1605 = p_1279 * p_2_4_7;
-    prob[1][4] += 15_f64 * p_1605;
-    prob[2][4] += 6_f64 * p_1605;
-let p_1606 = p_1280 * p_1_3_7;
-    prob[2][1] += 2_f64 * p_1606;
-    prob[4][1] += p_1606;
-let p_1607 = p_1281 * p_1_2_7;
-    prob[1][2] += 4_f64 * p_1607;
-    prob[2][2] += 15_f64 * p_1607;
-    prob[4][2] += 6_f64 * p_1607;
-let p_1608 = p_1282 * p_1_1_7;
-    prob[1][3] += 6_f64 * p_1608;
-    prob[2][3] += 12_f64 * p_1608;
-let p_1609 = p_1283 * p_1_0_7;
-    prob[1][4] += 21_f64 * p_1609;
-    prob[2][4] += 23_f64 * p_1609;
-let p_1610 = p_1284 * p_1_2_7;
-    prob[2][3] += p_1610;
-let p_1611 = p_1285 * p_1_1_7;
-    prob[1][4] += 3_f64 * p_1611;
-    prob[2][4] += 12_f64 * p_1611;
-let p_1612 = p_1289 * p_1_4_7;
-let p_1613 = p_1289 * p_2_1_7;
-    prob[1][0] += 14_f64 * p_1613;
-    prob[3][0] += 11_f64 * p_1613;
-    prob[4][0] += 11_f64 * p_1613;
-let p_1614 = p_1290 * p_1_2_7;
-    prob[1][1] += 15_f64 * p_1614;
-    prob[3][1] += 9_f64 * p_1614;
-    prob[4][1] += 10_f64 * p_1614;
-let p_1615 = p_1291 * p_1_1_7;
-    prob[1][2] += 14_f64 * p_1615;
-    prob[3][2] += p_1615;
-    prob[4][2] += 3_f64 * p_1615;
-let p_1616 = p_1292 * p_1_0_7;
-    prob[1][3] += 21_f64 * p_1616;
-    prob[3][3] += 2_f64 * p_1616;
-    prob[4][3] += 3_f64 * p_1616;
-let p_1617 = p_1292 * p_2_5_7;
-    prob[1][4] += 6_f64 * p_1617;
-    prob[3][4] += p_1617;
-let p_1618 = p_1288 * p_3_0_7;
-    prob[2][0] += 2_f64 * p_1618;
-    prob[3][0] += 2_f64 * p_1618;
-    prob[4][0] += 2_f64 * p_1618;
-let p_1619 = p_1293 * p_1_3_7;
-    prob[1][1] += 2_f64 * p_1619;
-    prob[2][1] += 2_f64 * p_1619;
-    prob[3][1] += 4_f64 * p_1619;
-    prob[4][1] += 4_f64 * p_1619;
-let p_1620 = p_1294 * p_1_2_7;
-    prob[1][2] += 24_f64 * p_1620;
-    prob[2][2] += 6_f64 * p_1620;
-    prob[3][2] += 12_f64 * p_1620;
-    prob[4][2] += 12_f64 * p_1620;
-let p_1621 = p_1295 * p_1_1_7;
-    prob[1][3] += 54_f64 * p_1621;
-    prob[2][3] += 20_f64 * p_1621;
-    prob[3][3] += 10_f64 * p_1621;
-    prob[4][3] += 12_f64 * p_1621;
-let p_1622 = p_1296 * p_1_0_7;
-    prob[1][4] += 46_f64 * p_1622;
-    prob[2][4] += 13_f64 * p_1622;
-    prob[3][4] += 13_f64 * p_1622;
-let p_1623 = p_1297 * p_1_3_7;
-    prob[2][2] += 4_f64 * p_1623;
-    prob[3][2] += 2_f64 * p_1623;
-    prob[4][2] += 3_f64 * p_1623;
-let p_1624 = p_1298 * p_1_2_7;
-    prob[1][3] += 6_f64 * p_1624;
-    prob[2][3] += 18_f64 * p_1624;
-    prob[3][3] += 3_f64 * p_1624;
-    prob[4][3] += 6_f64 * p_1624;
-let p_1625 = p_1299 * p_1_1_7;
-    prob[1][4] += 30_f64 * p_1625;
-    prob[2][4] += 30_f64 * p_1625;
-    prob[3][4] += 18_f64 * p_1625;
-let p_1626 = p_1301 * p_1_2_7;
-    prob[2][4] += 3_f64 * p_1626;
-    prob[3][4] += p_1626;
-let p_1627 = p_1304 * p_1_3_7;
-    prob[1][2] += 2_f64 * p_1627;
-    prob[3][2] += 2_f64 * p_1627;
-    prob[4][2] += 2_f64 * p_1627;
-let p_1628 = p_1305 * p_1_2_7;
-    prob[1][3] += 15_f64 * p_1628;
-    prob[3][3] += 12_f64 * p_1628;
-    prob[4][3] += 9_f64 * p_1628;
-let p_1629 = p_1306 * p_1_1_7;
-    prob[1][4] += 12_f64 * p_1629;
-    prob[3][4] += 10_f64 * p_1629;
-let p_1630 = p_1308 * p_1_3_7;
-    prob[2][3] += 3_f64 * p_1630;
-    prob[3][3] += 4_f64 * p_1630;
-    prob[4][3] += 2_f64 * p_1630;
-let p_1631 = p_1309 * p_1_2_7;
-    prob[1][4] += 3_f64 * p_1631;
-    prob[2][4] += 6_f64 * p_1631;
-    prob[3][4] += 12_f64 * p_1631;
-let p_1632 = p_1312 * p_1_3_7;
-    prob[3][4] += p_1632;
-let p_1633 = p_1269 * p_5_1_7;
-    prob[1][0] += p_1633;
-    prob[4][0] += 2_f64 * p_1633;
-let p_1634 = p_1314 * p_1_3_7;
-    prob[1][1] += p_1634;
-    prob[4][1] += 2_f64 * p_1634;
-let p_1635 = p_1315 * p_1_2_7;
-    prob[1][2] += 3_f64 * p_1635;
-    prob[4][2] += p_1635;
-let p_1636 = p_1316 * p_1_1_7;
-    prob[1][3] += 10_f64 * p_1636;
-    prob[4][3] += 3_f64 * p_1636;
-let p_1637 = p_1317 * p_1_0_7;
-    prob[1][4] += 12_f64 * p_1637;
-    prob[4][4] += 3_f64 * p_1637;
-let p_1638 = p_1319 * p_1_3_7;
-    prob[1][2] += p_1638;
-    prob[4][2] += 2_f64 * p_1638;
-let p_1639 = p_1320 * p_1_2_7;
-    prob[1][3] += 6_f64 * p_1639;
-    prob[2][3] += 3_f64 * p_1639;
-    prob[4][3] += 3_f64 * p_1639;
-let p_1640 = p_1321 * p_1_1_7;
-    prob[1][4] += 30_f64 * p_1640;
-    prob[2][4] += 10_f64 * p_1640;
-    prob[4][4] += 12_f64 * p_1640;
-let p_1641 = p_1323 * p_1_3_7;
-    prob[2][3] += 2_f64 * p_1641;
-    prob[4][3] += p_1641;
-let p_1642 = p_1324 * p_1_2_7;
-    prob[1][4] += 3_f64 * p_1642;
-    prob[2][4] += 12_f64 * p_1642;
-    prob[4][4] += 6_f64 * p_1642;
-let p_1643 = p_1327 * p_1_3_7;
-    prob[1][3] += 3_f64 * p_1643;
-    prob[3][3] += 2_f64 * p_1643;
-    prob[4][3] += 4_f64 * p_1643;
-let p_1644 = p_1328 * p_1_2_7;
-    prob[1][4] += 12_f64 * p_1644;
-    prob[3][4] += 9_f64 * p_1644;
-    prob[4][4] += 9_f64 * p_1644;
-let p_1645 = p_1330 * p_1_3_7;
-    prob[2][4] += 2_f64 * p_1645;
-    prob[3][4] += 2_f64 * p_1645;
-    prob[4][4] += 2_f64 * p_1645;
-let p_1646 = p_1333 * p_1_3_7;
-    prob[1][4] += p_1646;
-    prob[4][4] += 2_f64 * p_1646;
-let p_1647 = p_1336 * p_1_6_7;
-let p_1648 = p_1337 * p_1_5_7;
-let p_1649 = p_1199 * p_6_0_7;
-    prob[0][0] += 9_f64 * p_1649;
-    prob[1][0] += 22_f64 * p_1649;
-    prob[5][0] += 4_f64 * p_1649;
-let p_1650 = p_1339 * p_1_1_7;
-    prob[0][1] += 3_f64 * p_1650;
-    prob[1][1] += 13_f64 * p_1650;
-    prob[5][1] += 3_f64 * p_1650;
-let p_1651 = p_1340 * p_1_0_7;
-    prob[1][2] += p_1651;
-let p_1652 = p_1340 * p_2_6_7;
-    prob[1][3] += p_1652;
-let p_1653 = p_1341 * p_2_0_7;
-let p_1654 = p_1338 * p_3_0_7;
-    prob[0][0] += 34_f64 * p_1654;
-    prob[1][0] += 33_f64 * p_1654;
-    prob[2][0] += 22_f64 * p_1654;
-    prob[5][0] += 11_f64 * p_1654;
-let p_1655 = p_1342 * p_1_2_7;
-    prob[0][1] += 21_f64 * p_1655;
-    prob[1][1] += 33_f64 * p_1655;
-    prob[2][1] += 16_f64 * p_1655;
-    prob[5][1] += 10_f64 * p_1655;
-let p_1656 = p_1343 * p_1_1_7;
-    prob[0][2] += 3_f64 * p_1656;
-    prob[1][2] += 17_f64 * p_1656;
-    prob[2][2] += p_1656;
-    prob[5][2] += 3_f64 * p_1656;
-let p_1657 = p_1344 * p_1_0_7;
-    prob[1][3] += 9_f64 * p_1657;
-    prob[2][3] += 2_f64 * p_1657;
-let p_1658 = p_1344 * p_2_5_7;
-    prob[1][4] += 6_f64 * p_1658;
-    prob[2][4] += p_1658;
-let p_1659 = p_1341 * p_3_1_7;
-    prob[0][0] += 9_f64 * p_1659;
-    prob[2][0] += 5_f64 * p_1659;
-    prob[5][0] += p_1659;
-let p_1660 = p_1345 * p_1_3_7;
-    prob[0][1] += 18_f64 * p_1660;
-    prob[1][1] += 5_f64 * p_1660;
-    prob[2][1] += 10_f64 * p_1660;
-    prob[5][1] += 3_f64 * p_1660;
-let p_1661 = p_1346 * p_1_2_7;
-    prob[0][2] += 15_f64 * p_1661;
-    prob[1][2] += 24_f64 * p_1661;
-    prob[2][2] += 16_f64 * p_1661;
-    prob[5][2] += 9_f64 * p_1661;
-let p_1662 = p_1347 * p_1_1_7;
-    prob[1][3] += 6_f64 * p_1662;
-    prob[2][3] += 4_f64 * p_1662;
-let p_1663 = p_1348 * p_1_0_7;
-    prob[1][4] += 20_f64 * p_1663;
-    prob[2][4] += 10_f64 * p_1663;
-let p_1664 = p_1349 * p_1_3_7;
-    prob[0][2] += 6_f64 * p_1664;
-    prob[2][2] += 4_f64 * p_1664;
-    prob[5][2] += p_1664;
-let p_1665 = p_1350 * p_1_2_7;
-    prob[1][3] += p_1665;
-    prob[2][3] += 3_f64 * p_1665;
-let p_1666 = p_1351 * p_1_1_7;
-    prob[1][4] += 6_f64 * p_1666;
-    prob[2][4] += 12_f64 * p_1666;
-let p_1667 = p_1353 * p_1_2_7;
-    prob[2][4] += p_1667;
-let p_1668 = p_1336 * p_4_0_7;
-let p_1669 = p_1337 * p_4_0_7;
-    prob[0][0] += 16_f64 * p_1669;
-    prob[1][0] += 4_f64 * p_1669;
-    prob[3][0] += 6_f64 * p_1669;
-    prob[5][0] += 2_f64 * p_1669;
-let p_1670 = p_1355 * p_1_3_7;
-    prob[0][1] += 17_f64 * p_1670;
-    prob[1][1] += 7_f64 * p_1670;
-    prob[3][1] += 5_f64 * p_1670;
-    prob[5][1] += 2_f64 * p_1670;
-let p_1671 = p_1356 * p_1_2_7;
-    prob[0][2] += 6_f64 * p_1671;
-    prob[1][2] += 9_f64 * p_1671;
-    prob[5][2] += p_1671;
-let p_1672 = p_1357 * p_1_1_7;
-    prob[0][3] += 3_f64 * p_1672;
-    prob[1][3] += 17_f64 * p_1672;
-    prob[3][3] += p_1672;
-    prob[5][3] += 3_f64 * p_1672;
-let p_1673 = p_1358 * p_1_0_7;
-    prob[1][4] += 9_f64 * p_1673;
-    prob[3][4] += 2_f64 * p_1673;
-let p_1674 = p_1359 * p_1_4_7;
-    prob[0][1] += 5_f64 * p_1674;
-    prob[2][1] += p_1674;
-    prob[3][1] += p_1674;
-let p_1675 = p_1360 * p_1_3_7;
-    prob[0][2] += 22_f64 * p_1675;
-    prob[1][2] += 8_f64 * p_1675;
-    prob[2][2] += 6_f64 * p_1675;
-    prob[3][2] += 5_f64 * p_1675;
-    prob[5][2] += 4_f64 * p_1675;
-let p_1676 = p_1361 * p_1_2_7;
-    prob[0][3] += 15_f64 * p_1676;
-    prob[1][3] += 30_f64 * p_1676;
-    prob[2][3] += 19_f64 * p_1676;
-    prob[3][3] += 3_f64 * p_1676;
-    prob[5][3] += 9_f64 * p_1676;
-let p_1677 = p_1362 * p_1_1_7;
-    prob[1][4] += 24_f64 * p_1677;
-    prob[2][4] += 10_f64 * p_1677;
-    prob[3][4] += 10_f64 * p_1677;
-let p_1678 = p_1364 * p_1_3_7;
-    prob[0][3] += 6_f64 * p_1678;
-    prob[2][3] += 4_f64 * p_1678;
-    prob[5][3] += p_1678;
-let p_1679 = p_1365 * p_1_2_7;
-    prob[1][4] += 3_f64 * p_1679;
-    prob[2][4] += 6_f64 * p_1679;
-    prob[3][4] += 3_f64 * p_1679;
-let p_1680 = p_1368 * p_1_4_7;
-    prob[0][2] += 2_f64 * p_1680;
-    prob[3][2] += p_1680;
-let p_1681 = p_1369 * p_1_3_7;
-    prob[0][3] += 10_f64 * p_1681;
-    prob[1][3] += 3_f64 * p_1681;
-    prob[3][3] += 5_f64 * p_1681;
-    prob[5][3] += 2_f64 * p_1681;
-let p_1682 = p_1370 * p_1_2_7;
-    prob[1][4] += 3_f64 * p_1682;
-    prob[3][4] += 3_f64 * p_1682;
-let p_1683 = p_1372 * p_1_3_7;
-    prob[2][4] += p_1683;
-    prob[3][4] += 2_f64 * p_1683;
-let p_1684 = p_1336 * p_5_0_7;
-    prob[0][0] += 2_f64 * p_1684;
-    prob[4][0] += 2_f64 * p_1684;
-    prob[5][0] += p_1684;
-let p_1685 = p_1375 * p_1_4_7;
-    prob[0][1] += 5_f64 * p_1685;
-    prob[1][1] += p_1685;
-    prob[4][1] += 2_f64 * p_1685;
-    prob[5][1] += p_1685;
-let p_1686 = p_1376 * p_1_3_7;
-    prob[0][2] += 7_f64 * p_1686;
-    prob[1][2] += 4_f64 * p_1686;
-let p_1687 = p_1377 * p_1_2_7;
-    prob[0][3] += 6_f64 * p_1687;
-    prob[1][3] += 12_f64 * p_1687;
-    prob[4][3] += p_1687;
-    prob[5][3] += p_1687;
-let p_1688 = p_1378 * p_1_1_7;
-    prob[0][4] += 3_f64 * p_1688;
-    prob[1][4] += 23_f64 * p_1688;
-    prob[4][4] += 3_f64 * p_1688;
-    prob[5][4] += 3_f64 * p_1688;
-let p_1689 = p_1380 * p_1_4_7;
-    prob[0][2] += 4_f64 * p_1689;
-    prob[2][2] += p_1689;
-    prob[4][2] += 2_f64 * p_1689;
-    prob[5][2] += p_1689;
-let p_1690 = p_1381 * p_1_3_7;
-    prob[0][3] += 12_f64 * p_1690;
-    prob[1][3] += 5_f64 * p_1690;
-    prob[2][3] += 6_f64 * p_1690;
-    prob[5][3] += 2_f64 * p_1690;
-let p_1691 = p_1382 * p_1_2_7;
-    prob[0][4] += 15_f64 * p_1691;
-    prob[1][4] += 30_f64 * p_1691;
-    prob[2][4] += 19_f64 * p_1691;
-    prob[4][4] += 3_f64 * p_1691;
-    prob[5][4] += 9_f64 * p_1691;
-let p_1692 = p_1384 * p_1_3_7;
-    prob[0][4] += 6_f64 * p_1692;
-    prob[2][4] += 6_f64 * p_1692;
-    prob[4][4] += p_1692;
-    prob[5][4] += p_1692;
-let p_1693 = p_1386 * p_1_4_7;
-    prob[0][3] += 3_f64 * p_1693;
-    prob[3][3] += p_1693;
-    prob[4][3] += 2_f64 * p_1693;
-    prob[5][3] += p_1693;
-let p_1694 = p_1387 * p_1_3_7;
-    prob[0][4] += 10_f64 * p_1694;
-    prob[1][4] += 5_f64 * p_1694;
-    prob[3][4] += 7_f64 * p_1694;
-    prob[4][4] += 2_f64 * p_1694;
-    prob[5][4] += 2_f64 * p_1694;
-let p_1695 = p_1390 * p_1_4_7;
-    prob[0][4] += p_1695;
-    prob[4][4] += 2_f64 * p_1695;
-    prob[5][4] += p_1695;
-let p_1696 = p_1392 * p_1_5_7;
-    prob[0][1] += p_1696;
-let p_1697 = p_1393 * p_1_4_7;
-    prob[0][2] += 4_f64 * p_1697;
-    prob[1][2] += p_1697;
-let p_1698 = p_1394 * p_1_3_7;
-    prob[0][3] += 7_f64 * p_1698;
-    prob[1][3] += 4_f64 * p_1698;
-let p_1699 = p_1395 * p_1_2_7;
-    prob[0][4] += 6_f64 * p_1699;
-    prob[1][4] += 9_f64 * p_1699;
-    prob[5][4] += p_1699;
-let p_1700 = p_1397 * p_1_4_7;
-    prob[0][3] += 3_f64 * p_1700;
-    prob[2][3] += p_1700;
-let p_1701 = p_1398 * p_1_3_7;
-    prob[0][4] += 12_f64 * p_1701;
-    prob[1][4] += 5_f64 * p_1701;
-    prob[2][4] += 6_f64 * p_1701;
-    prob[5][4] += 2_f64 * p_1701;
-let p_1702 = p_1401 * p_1_4_7;
-    prob[0][4] += 2_f64 * p_1702;
-    prob[3][4] += p_1702;
-let p_1703 = p_1198 * p_7_0_7;
-    prob[0][0] += 26_f64 * p_1703;
-    prob[1][0] += 32_f64 * p_1703;
-    prob[6][0] += 5_f64 * p_1703;
-let p_1704 = p_1405 * p_1_2_7;
-    prob[0][1] += 12_f64 * p_1704;
-    prob[1][1] += 22_f64 * p_1704;
-    prob[6][1] += 4_f64 * p_1704;
-let p_1705 = p_1407 * p_1_0_7;
-    prob[1][3] += p_1705;
-let p_1706 = p_1407 * p_2_6_7;
-    prob[1][4] += p_1706;
-let p_1707 = p_1203 * p_7_0_7;
-    prob[0][0] += 38_f64 * p_1707;
-    prob[1][0] += 9_f64 * p_1707;
-    prob[2][0] += 11_f64 * p_1707;
-    prob[6][0] += 2_f64 * p_1707;
-let p_1708 = p_1408 * p_1_3_7;
-    prob[0][1] += 38_f64 * p_1708;
-    prob[1][1] += 17_f64 * p_1708;
-    prob[2][1] += 9_f64 * p_1708;
-    prob[6][1] += 3_f64 * p_1708;
-let p_1709 = p_1409 * p_1_2_7;
-    prob[0][2] += 12_f64 * p_1709;
-    prob[1][2] += 22_f64 * p_1709;
-    prob[6][2] += 4_f64 * p_1709;
-let p_1710 = p_1410 * p_1_1_7;
-    prob[1][3] += 4_f64 * p_1710;
-    prob[2][3] += p_1710;
-let p_1711 = p_1411 * p_1_0_7;
-    prob[1][4] += 9_f64 * p_1711;
-    prob[2][4] += 2_f64 * p_1711;
-let p_1712 = p_1412 * p_1_4_7;
-    prob[0][1] += 6_f64 * p_1712;
-    prob[2][1] += 2_f64 * p_1712;
-let p_1713 = p_1413 * p_1_3_7;
-    prob[0][2] += 24_f64 * p_1713;
-    prob[1][2] += 7_f64 * p_1713;
-    prob[2][2] += 9_f64 * p_1713;
-    prob[6][2] += 2_f64 * p_1713;
-let p_1714 = p_1415 * p_1_1_7;
-    prob[1][4] += 6_f64 * p_1714;
-    prob[2][4] += 4_f64 * p_1714;
-let p_1715 = p_1418 * p_1_2_7;
-    prob[1][4] += p_1715;
-    prob[2][4] += 3_f64 * p_1715;
-let p_1716 = p_1404 * p_4_0_7;
-    prob[0][0] += 6_f64 * p_1716;
-    prob[3][0] += 3_f64 * p_1716;
-    prob[6][0] += p_1716;
-let p_1717 = p_1421 * p_1_4_7;
-    prob[0][1] += 12_f64 * p_1717;
-    prob[1][1] += 2_f64 * p_1717;
-    prob[3][1] += 3_f64 * p_1717;
-    prob[6][1] += p_1717;
-let p_1718 = p_1422 * p_1_3_7;
-    prob[0][2] += 14_f64 * p_1718;
-    prob[1][2] += 10_f64 * p_1718;
-    prob[6][2] += p_1718;
-let p_1719 = p_1423 * p_1_2_7;
-    prob[0][3] += 12_f64 * p_1719;
-    prob[1][3] += 22_f64 * p_1719;
-    prob[6][3] += 4_f64 * p_1719;
-let p_1720 = p_1424 * p_1_1_7;
-    prob[1][4] += 4_f64 * p_1720;
-    prob[3][4] += p_1720;
-let p_1721 = p_1426 * p_1_4_7;
-    prob[0][2] += 10_f64 * p_1721;
-    prob[2][2] += 2_f64 * p_1721;
-    prob[3][2] += 3_f64 * p_1721;
-    prob[6][2] += p_1721;
-let p_1722 = p_1427 * p_1_3_7;
-    prob[0][3] += 24_f64 * p_1722;
-    prob[1][3] += 7_f64 * p_1722;
-    prob[2][3] += 9_f64 * p_1722;
-    prob[6][3] += 2_f64 * p_1722;
-let p_1723 = p_1428 * p_1_2_7;
-    prob[1][4] += 6_f64 * p_1723;
-    prob[2][4] += 3_f64 * p_1723;
-    prob[3][4] += 3_f64 * p_1723;
-let p_1724 = p_1432 * p_1_4_7;
-    prob[0][3] += 4_f64 * p_1724;
-    prob[3][3] += 3_f64 * p_1724;
-    prob[6][3] += p_1724;
-let p_1725 = p_1436 * p_1_5_7;
-    prob[0][1] += 2_f64 * p_1725;
-let p_1726 = p_1437 * p_1_4_7;
-    prob[0][2] += 8_f64 * p_1726;
-    prob[1][2] += 2_f64 * p_1726;
-let p_1727 = p_1438 * p_1_3_7;
-    prob[0][3] += 14_f64 * p_1727;
-    prob[1][3] += 10_f64 * p_1727;
-    prob[6][3] += p_1727;
-let p_1728 = p_1439 * p_1_2_7;
-    prob[0][4] += 12_f64 * p_1728;
-    prob[1][4] += 25_f64 * p_1728;
-    prob[4][4] += p_1728;
-    prob[6][4] += 4_f64 * p_1728;
-let p_1729 = p_1441 * p_1_4_7;
-    prob[0][3] += 6_f64 * p_1729;
-    prob[2][3] += 2_f64 * p_1729;
-let p_1730 = p_1442 * p_1_3_7;
-    prob[0][4] += 24_f64 * p_1730;
-    prob[1][4] += 7_f64 * p_1730;
-    prob[2][4] += 9_f64 * p_1730;
-    prob[6][4] += 2_f64 * p_1730;
-let p_1731 = p_1445 * p_1_4_7;
-    prob[0][4] += 4_f64 * p_1731;
-    prob[3][4] += 3_f64 * p_1731;
-    prob[6][4] += p_1731;
-let p_1732 = p_1448 * p_1_5_7;
-    prob[0][2] += 3_f64 * p_1732;
-let p_1733 = p_1449 * p_1_4_7;
-    prob[0][3] += 12_f64 * p_1733;
-    prob[1][3] += 3_f64 * p_1733;
-let p_1734 = p_1450 * p_1_3_7;
-    prob[0][4] += 21_f64 * p_1734;
-    prob[1][4] += 14_f64 * p_1734;
-    prob[6][4] += p_1734;
-let p_1735 = p_1452 * p_1_4_7;
-    prob[0][4] += 9_f64 * p_1735;
-    prob[2][4] += 3_f64 * p_1735;
-let p_1736 = p_1455 * p_1_5_7;
-    prob[0][3] += 2_f64 * p_1736;
-let p_1737 = p_1456 * p_1_4_7;
-    prob[0][4] += 8_f64 * p_1737;
-    prob[1][4] += 2_f64 * p_1737;
-let p_1738 = p_1197 * p_8_0_7;
-    prob[0][0] += 33_f64 * p_1738;
-    prob[1][0] += 16_f64 * p_1738;
-    prob[7][0] += p_1738;
-let p_1739 = p_1459 * p_1_3_7;
-    prob[0][1] += 21_f64 * p_1739;
-    prob[1][1] += 13_f64 * p_1739;
-    prob[7][1] += p_1739;
-let p_1740 = p_1461 * p_1_0_7;
-    prob[1][4] += p_1740;
-let p_1741 = p_1202 * p_8_0_7;
-    prob[0][0] += 12_f64 * p_1741;
-    prob[2][0] += 4_f64 * p_1741;
-    prob[7][0] += p_1741;
-let p_1742 = p_1462 * p_1_4_7;
-    prob[0][1] += 21_f64 * p_1742;
-    prob[1][1] += 3_f64 * p_1742;
-    prob[2][1] += 4_f64 * p_1742;
-    prob[7][1] += p_1742;
-let p_1743 = p_1463 * p_1_3_7;
-    prob[0][2] += 21_f64 * p_1743;
-    prob[1][2] += 13_f64 * p_1743;
-    prob[7][2] += p_1743;
-let p_1744 = p_1465 * p_1_1_7;
-    prob[1][4] += 4_f64 * p_1744;
-    prob[2][4] += p_1744;
-let p_1745 = p_1467 * p_1_4_7;
-    prob[0][2] += 9_f64 * p_1745;
-    prob[2][2] += 4_f64 * p_1745;
-    prob[7][2] += p_1745;
-let p_1746 = p_1471 * p_1_5_7;
-    prob[0][1] += 3_f64 * p_1746;
-let p_1747 = p_1472 * p_1_4_7;
-    prob[0][2] += 12_f64 * p_1747;
-    prob[1][2] += 3_f64 * p_1747;
-let p_1748 = p_1473 * p_1_3_7;
-    prob[0][3] += 21_f64 * p_1748;
-    prob[1][3] += 13_f64 * p_1748;
-    prob[7][3] += p_1748;
-let p_1749 = p_1476 * p_1_4_7;
-    prob[0][3] += 9_f64 * p_1749;
-    prob[2][3] += 4_f64 * p_1749;
-    prob[7][3] += p_1749;
-let p_1750 = p_1480 * p_1_5_7;
-    prob[0][2] += 3_f64 * p_1750;
-let p_1751 = p_1481 * p_1_4_7;
-    prob[0][3] += 12_f64 * p_1751;
-    prob[1][3] += 3_f64 * p_1751;
-let p_1752 = p_1482 * p_1_3_7;
-    prob[0][4] += 21_f64 * p_1752;
-    prob[1][4] += 13_f64 * p_1752;
-    prob[7][4] += p_1752;
-let p_1753 = p_1484 * p_1_4_7;
-    prob[0][4] += 9_f64 * p_1753;
-    prob[2][4] += 4_f64 * p_1753;
-    prob[7][4] += p_1753;
-let p_1754 = p_1487 * p_1_5_7;
-    prob[0][3] += 4_f64 * p_1754;
-let p_1755 = p_1488 * p_1_4_7;
-    prob[0][4] += 16_f64 * p_1755;
-    prob[1][4] += 4_f64 * p_1755;
-let p_1756 = p_1491 * p_1_5_7;
-    prob[0][4] += 5_f64 * p_1756;
-let p_1757 = p_1196 * p_9_0_7;
-    prob[0][0] += 20_f64 * p_1757;
-    prob[1][0] += 5_f64 * p_1757;
-    prob[8][0] += p_1757;
-let p_1758 = p_1494 * p_1_4_7;
-    prob[0][1] += 16_f64 * p_1758;
-    prob[1][1] += 5_f64 * p_1758;
-    prob[8][1] += p_1758;
-let p_1759 = p_1497 * p_1_5_7;
-    prob[0][1] += 4_f64 * p_1759;
-let p_1760 = p_1498 * p_1_4_7;
-    prob[0][2] += 16_f64 * p_1760;
-    prob[1][2] += 5_f64 * p_1760;
-    prob[8][2] += p_1760;
-let p_1761 = p_1502 * p_1_5_7;
-    prob[0][2] += 4_f64 * p_1761;
-let p_1762 = p_1503 * p_1_4_7;
-    prob[0][3] += 16_f64 * p_1762;
-    prob[1][3] += 5_f64 * p_1762;
-    prob[8][3] += p_1762;
-let p_1763 = p_1506 * p_1_5_7;
-    prob[0][3] += 4_f64 * p_1763;
-let p_1764 = p_1507 * p_1_4_7;
-    prob[0][4] += 16_f64 * p_1764;
-    prob[1][4] += 5_f64 * p_1764;
-    prob[8][4] += p_1764;
-let p_1765 = p_1510 * p_1_5_7;
-    prob[0][4] += 5_f64 * p_1765;
-let p_1766 = p_1195 * p_10_0_7;
-    prob[0][0] += 5_f64 * p_1766;
-let p_1767 = p_1513 * p_1_5_7;
-    prob[0][1] += 5_f64 * p_1767;
-let p_1768 = p_1515 * p_1_5_7;
-    prob[0][2] += 5_f64 * p_1768;
-let p_1769 = p_1517 * p_1_5_7;
-    prob[0][3] += 5_f64 * p_1769;
-let p_1770 = p_1519 * p_1_5_7;
-    prob[0][4] += 5_f64 * p_1770;
-let p_1771 = p_1522 * p_1_8_8;
-let p_1772 = p_1523 * p_1_7_8;
-let p_1773 = p_1526 * p_1_2_8;
-let p_1774 = p_1527 * p_1_1_8;
-let p_1775 = p_1528 * p_1_0_8;
-    prob[1][0] += 3_f64 * p_1775;
-let p_1776 = p_1528 * p_2_8_8;
-    prob[1][1] += p_1776;
-let p_1777 = p_1530 * p_1_3_8;
-let p_1778 = p_1531 * p_1_2_8;
-let p_1779 = p_1532 * p_1_1_8;
-    prob[1][0] += 30_f64 * p_1779;
-    prob[2][0] += 6_f64 * p_1779;
-let p_1780 = p_1533 * p_1_0_8;
-    prob[1][1] += 18_f64 * p_1780;
-    prob[2][1] += 3_f64 * p_1780;
-let p_1781 = p_1533 * p_2_7_8;
-    prob[1][2] += p_1781;
-let p_1782 = p_1534 * p_1_3_8;
-let p_1783 = p_1535 * p_1_2_8;
-    prob[1][0] += 40_f64 * p_1783;
-    prob[2][0] += 20_f64 * p_1783;
-let p_1784 = p_1536 * p_1_1_8;
-    prob[1][1] += 43_f64 * p_1784;
-    prob[2][1] += 18_f64 * p_1784;
-let p_1785 = p_1537 * p_1_0_8;
-    prob[1][2] += 16_f64 * p_1785;
-    prob[2][2] += 3_f64 * p_1785;
-let p_1786 = p_1538 * p_1_4_8;
-let p_1787 = p_1539 * p_1_3_8;
-    prob[1][0] += 8_f64 * p_1787;
-    prob[2][0] += 18_f64 * p_1787;
-let p_1788 = p_1540 * p_1_2_8;
-    prob[1][1] += 13_f64 * p_1788;
-    prob[2][1] += 19_f64 * p_1788;
-let p_1789 = p_1541 * p_1_1_8;
-    prob[1][2] += 30_f64 * p_1789;
-    prob[2][2] += 15_f64 * p_1789;
-let p_1790 = p_1543 * p_1_4_8;
-    prob[2][0] += p_1790;
-let p_1791 = p_1544 * p_1_3_8;
-    prob[1][1] += p_1791;
-    prob[2][1] += 4_f64 * p_1791;
-let p_1792 = p_1545 * p_1_2_8;
-    prob[1][2] += 7_f64 * p_1792;
-    prob[2][2] += 15_f64 * p_1792;
-let p_1793 = p_1547 * p_1_3_8;
-    prob[2][2] += p_1793;
-let p_1794 = p_1549 * p_1_3_8;
-let p_1795 = p_1550 * p_1_2_8;
-    prob[1][0] += 17_f64 * p_1795;
-    prob[3][0] += 4_f64 * p_1795;
-let p_1796 = p_1551 * p_1_1_8;
-    prob[1][1] += 14_f64 * p_1796;
-    prob[3][1] += 3_f64 * p_1796;
-let p_1797 = p_1552 * p_1_0_8;
-    prob[1][2] += 2_f64 * p_1797;
-let p_1798 = p_1552 * p_2_7_8;
-    prob[1][3] += p_1798;
-let p_1799 = p_1553 * p_1_4_8;
-let p_1800 = p_1554 * p_1_3_8;
-    prob[1][0] += 36_f64 * p_1800;
-    prob[2][0] += 16_f64 * p_1800;
-    prob[3][0] += 16_f64 * p_1800;
-let p_1801 = p_1555 * p_1_2_8;
-    prob[1][1] += 38_f64 * p_1801;
-    prob[2][1] += 14_f64 * p_1801;
-    prob[3][1] += 14_f64 * p_1801;
-let p_1802 = p_1556 * p_1_1_8;
-    prob[1][2] += 26_f64 * p_1802;
-    prob[2][2] += 3_f64 * p_1802;
-    prob[3][2] += 3_f64 * p_1802;
-let p_1803 = p_1557 * p_1_0_8;
-    prob[1][3] += 16_f64 * p_1803;
-    prob[2][3] += 3_f64 * p_1803;
-let p_1804 = p_1558 * p_1_4_8;
-    prob[1][0] += 3_f64 * p_1804;
-    prob[2][0] += 6_f64 * p_1804;
-    prob[3][0] += 3_f64 * p_1804;
-let p_1805 = p_1559 * p_1_3_8;
-    prob[1][1] += 9_f64 * p_1805;
-    prob[2][1] += 9_f64 * p_1805;
-    prob[3][1] += 6_f64 * p_1805;
-let p_1806 = p_1560 * p_1_2_8;
-    prob[1][2] += 36_f64 * p_1806;
-    prob[2][2] += 17_f64 * p_1806;
-    prob[3][2] += 13_f64 * p_1806;
-let p_1807 = p_1561 * p_1_1_8;
-    prob[1][3] += 30_f64 * p_1807;
-    prob[2][3] += 15_f64 * p_1807;
-let p_1808 = p_1564 * p_1_3_8;
-    prob[1][2] += 4_f64 * p_1808;
-    prob[2][2] += 9_f64 * p_1808;
-    prob[3][2] += 3_f64 * p_1808;
-let p_1809 = p_1565 * p_1_2_8;
-    prob[1][3] += 7_f64 * p_1809;
-    prob[2][3] += 15_f64 * p_1809;
-let p_1810 = p_1567 * p_1_3_8;
-    prob[2][3] += p_1810;
-let p_1811 = p_1569 * p_1_4_8;
-    prob[1][0] += 3_f64 * p_1811;
-    prob[3][0] += 3_f64 * p_1811;
-let p_1812 = p_1570 * p_1_3_8;
-    prob[1][1] += 3_f64 * p_1812;
-    prob[3][1] += 3_f64 * p_1812;
-let p_1813 = p_1571 * p_1_2_8;
-    prob[1][2] += 4_f64 * p_1813;
-    prob[3][2] += p_1813;
-let p_1814 = p_1572 * p_1_1_8;
-    prob[1][3] += 13_f64 * p_1814;
-    prob[3][3] += 3_f64 * p_1814;
-let p_1815 = p_1574 * p_1_5_8;
-    prob[2][0] += p_1815;
-    prob[3][0] += 2_f64 * p_1815;
-let p_1816 = p_1575 * p_1_4_8;
-    prob[2][1] += p_1816;
-    prob[3][1] += 2_f64 * p_1816;
-let p_1817 = p_1576 * p_1_3_8;
-    prob[1][2] += 9_f64 * p_1817;
-    prob[2][2] += 3_f64 * p_1817;
-    prob[3][2] += 6_f64 * p_1817;
-let p_1818 = p_1577 * p_1_2_8;
-    prob[1][3] += 30_f64 * p_1818;
-    prob[2][3] += 13_f64 * p_1818;
-    prob[3][3] += 13_f64 * p_1818;
-let p_1819 = p_1579 * p_1_4_8;
-    prob[2][2] += p_1819;
-    prob[3][2] += 2_f64 * p_1819;
-let p_1820 = p_1580 * p_1_3_8;
-    prob[1][3] += 3_f64 * p_1820;
-    prob[2][3] += 6_f64 * p_1820;
-    prob[3][3] += 3_f64 * p_1820;
-let p_1821 = p_1585 * p_1_3_8;
-    prob[1][3] += 3_f64 * p_1821;
-    prob[3][3] += 3_f64 * p_1821;
-let p_1822 = p_1587 * p_1_4_8;
-    prob[2][3] += p_1822;
-    prob[3][3] += 2_f64 * p_1822;
-let p_1823 = p_1590 * p_1_4_8;
-let p_1824 = p_1591 * p_1_3_8;
-    prob[1][0] += 16_f64 * p_1824;
-    prob[4][0] += 5_f64 * p_1824;
-let p_1825 = p_1592 * p_1_2_8;
-    prob[1][1] += 13_f64 * p_1825;
-    prob[4][1] += 4_f64 * p_1825;
-let p_1826 = p_1593 * p_1_1_8;
-    prob[1][2] += p_1826;
-let p_1827 = p_1594 * p_1_0_8;
-    prob[1][3] += 2_f64 * p_1827;
-let p_1828 = p_1594 * p_2_7_8;
-    prob[1][4] += p_1828;
-let p_1829 = p_1595 * p_1_4_8;
-    prob[1][0] += 6_f64 * p_1829;
-    prob[2][0] += 3_f64 * p_1829;
-    prob[4][0] += 3_f64 * p_1829;
-let p_1830 = p_1596 * p_1_3_8;
-    prob[1][1] += 9_f64 * p_1830;
-    prob[2][1] += 3_f64 * p_1830;
-    prob[4][1] += 4_f64 * p_1830;
-let p_1831 = p_1597 * p_1_2_8;
-    prob[1][2] += 17_f64 * p_1831;
-    prob[2][2] += p_1831;
-    prob[4][2] += 4_f64 * p_1831;
-let p_1832 = p_1598 * p_1_1_8;
-    prob[1][3] += 13_f64 * p_1832;
-    prob[2][3] += 3_f64 * p_1832;
-let p_1833 = p_1599 * p_1_0_8;
-    prob[1][4] += 16_f64 * p_1833;
-    prob[2][4] += 3_f64 * p_1833;
-let p_1834 = p_1600 * p_1_5_8;
-    prob[2][0] += 2_f64 * p_1834;
-    prob[4][0] += p_1834;
-let p_1835 = p_1601 * p_1_4_8;
-    prob[2][1] += 2_f64 * p_1835;
-    prob[4][1] += p_1835;
-let p_1836 = p_1602 * p_1_3_8;
-    prob[1][2] += 6_f64 * p_1836;
-    prob[2][2] += 3_f64 * p_1836;
-    prob[4][2] += 3_f64 * p_1836;
-let p_1837 = p_1603 * p_1_2_8;
-    prob[1][3] += 6_f64 * p_1837;
-    prob[2][3] += 4_f64 * p_1837;
-let p_1838 = p_1604 * p_1_1_8;
-    prob[1][4] += 30_f64 * p_1838;
-    prob[2][4] += 15_f64 * p_1838;
-let p_1839 = p_1606 * p_1_4_8;
-    prob[2][2] += 2_f64 * p_1839;
-    prob[4][2] += p_1839;
-let p_1840 = p_1607 * p_1_3_8;
-    prob[1][3] += p_1840;
-    prob[2][3] += 3_f64 * p_1840;
-let p_1841 = p_1608 * p_1_2_8;
-    prob[1][4] += 7_f64 * p_1841;
-    prob[2][4] += 15_f64 * p_1841;
-let p_1842 = p_1610 * p_1_3_8;
-    prob[2][4] += p_1842;
-let p_1843 = p_1612 * p_1_5_8;
-    prob[1][0] += 2_f64 * p_1843;
-    prob[3][0] += 2_f64 * p_1843;
-    prob[4][0] += 2_f64 * p_1843;
-let p_1844 = p_1613 * p_1_4_8;
-    prob[1][1] += 2_f64 * p_1844;
-    prob[3][1] += 2_f64 * p_1844;
-    prob[4][1] += 2_f64 * p_1844;
-let p_1845 = p_1614 * p_1_3_8;
-    prob[1][2] += 3_f64 * p_1845;
-    prob[4][2] += p_1845;
-let p_1846 = p_1615 * p_1_2_8;
-    prob[1][3] += 17_f64 * p_1846;
-    prob[3][3] += p_1846;
-    prob[4][3] += 4_f64 * p_1846;
-let p_1847 = p_1616 * p_1_1_8;
-    prob[1][4] += 13_f64 * p_1847;
-    prob[3][4] += 3_f64 * p_1847;
-let p_1848 = p_1619 * p_1_4_8;
-    prob[1][2] += 2_f64 * p_1848;
-    prob[3][2] += 2_f64 * p_1848;
-    prob[4][2] += 2_f64 * p_1848;
-let p_1849 = p_1620 * p_1_3_8;
-    prob[1][3] += 12_f64 * p_1849;
-    prob[2][3] += 6_f64 * p_1849;
-    prob[3][3] += 3_f64 * p_1849;
-    prob[4][3] += 3_f64 * p_1849;
-let p_1850 = p_1621 * p_1_2_8;
-    prob[1][4] += 30_f64 * p_1850;
-    prob[2][4] += 13_f64 * p_1850;
-    prob[3][4] += 13_f64 * p_1850;
-let p_1851 = p_1623 * p_1_4_8;
-    prob[2][3] += 2_f64 * p_1851;
-    prob[4][3] += p_1851;
-let p_1852 = p_1624 * p_1_3_8;
-    prob[1][4] += 3_f64 * p_1852;
-    prob[2][4] += 6_f64 * p_1852;
-    prob[3][4] += 3_f64 * p_1852;
-let p_1853 = p_1627 * p_1_4_8;
-    prob[1][3] += 2_f64 * p_1853;
-    prob[3][3] += 2_f64 * p_1853;
-    prob[4][3] += 2_f64 * p_1853;
-let p_1854 = p_1628 * p_1_3_8;
-    prob[1][4] += 3_f64 * p_1854;
-    prob[3][4] += 3_f64 * p_1854;
-let p_1855 = p_1630 * p_1_4_8;
-    prob[2][4] += p_1855;
-    prob[3][4] += 2_f64 * p_1855;
-let p_1856 = p_1635 * p_1_3_8;
-    prob[1][3] += 3_f64 * p_1856;
-    prob[4][3] += p_1856;
-let p_1857 = p_1636 * p_1_2_8;
-    prob[1][4] += 13_f64 * p_1857;
-    prob[4][4] += 4_f64 * p_1857;
-let p_1858 = p_1639 * p_1_3_8;
-    prob[1][4] += 6_f64 * p_1858;
-    prob[2][4] += 3_f64 * p_1858;
-    prob[4][4] += 3_f64 * p_1858;
-let p_1859 = p_1641 * p_1_4_8;
-    prob[2][4] += 2_f64 * p_1859;
-    prob[4][4] += p_1859;
-let p_1860 = p_1643 * p_1_4_8;
-    prob[1][4] += 2_f64 * p_1860;
-    prob[3][4] += 2_f64 * p_1860;
-    prob[4][4] += 2_f64 * p_1860;
-let p_1861 = p_1647 * p_1_7_8;
-let p_1862 = p_1648 * p_2_1_8;
-let p_1863 = p_1525 * p_6_0_8;
-    prob[0][0] += 24_f64 * p_1863;
-    prob[1][0] += 18_f64 * p_1863;
-    prob[5][0] += p_1863;
-let p_1864 = p_1649 * p_1_3_8;
-    prob[0][1] += 13_f64 * p_1864;
-    prob[1][1] += 13_f64 * p_1864;
-    prob[5][1] += p_1864;
-let p_1865 = p_1651 * p_1_1_8;
-    prob[1][3] += p_1865;
-let p_1866 = p_1652 * p_1_0_8;
-    prob[1][4] += 2_f64 * p_1866;
-let p_1867 = p_1647 * p_3_0_8;
-let p_1868 = p_1653 * p_1_5_8;
-    prob[0][0] += 28_f64 * p_1868;
-    prob[1][0] += 7_f64 * p_1868;
-    prob[2][0] += 8_f64 * p_1868;
-    prob[5][0] += 2_f64 * p_1868;
-let p_1869 = p_1654 * p_1_4_8;
-    prob[0][1] += 30_f64 * p_1869;
-    prob[1][1] += 11_f64 * p_1869;
-    prob[2][1] += 7_f64 * p_1869;
-    prob[5][1] += 2_f64 * p_1869;
-let p_1870 = p_1655 * p_1_3_8;
-    prob[0][2] += 13_f64 * p_1870;
-    prob[1][2] += 13_f64 * p_1870;
-    prob[5][2] += p_1870;
-let p_1871 = p_1656 * p_1_2_8;
-    prob[1][3] += 4_f64 * p_1871;
-    prob[2][3] += p_1871;
-let p_1872 = p_1657 * p_1_1_8;
-    prob[1][4] += 13_f64 * p_1872;
-    prob[2][4] += 3_f64 * p_1872;
-let p_1873 = p_1659 * p_1_5_8;
-    prob[0][1] += 4_f64 * p_1873;
-    prob[2][1] += p_1873;
-let p_1874 = p_1660 * p_1_4_8;
-    prob[0][2] += 19_f64 * p_1874;
-    prob[1][2] += 6_f64 * p_1874;
-    prob[2][2] += 7_f64 * p_1874;
-    prob[5][2] += 2_f64 * p_1874;
-let p_1875 = p_1662 * p_1_2_8;
-    prob[1][4] += 6_f64 * p_1875;
-    prob[2][4] += 4_f64 * p_1875;
-let p_1876 = p_1665 * p_1_3_8;
-    prob[1][4] += p_1876;
-    prob[2][4] += 3_f64 * p_1876;
-let p_1877 = p_1668 * p_1_6_8;
-    prob[0][0] += 4_f64 * p_1877;
-    prob[3][0] += p_1877;
-let p_1878 = p_1669 * p_1_5_8;
-    prob[0][1] += 8_f64 * p_1878;
-    prob[1][1] += p_1878;
-    prob[3][1] += p_1878;
-let p_1879 = p_1670 * p_1_4_8;
-    prob[0][2] += 11_f64 * p_1879;
-    prob[1][2] += 5_f64 * p_1879;
-let p_1880 = p_1671 * p_1_3_8;
-    prob[0][3] += 13_f64 * p_1880;
-    prob[1][3] += 13_f64 * p_1880;
-    prob[5][3] += p_1880;
-let p_1881 = p_1672 * p_1_2_8;
-    prob[1][4] += 4_f64 * p_1881;
-    prob[3][4] += p_1881;
-let p_1882 = p_1674 * p_1_5_8;
-    prob[0][2] += 7_f64 * p_1882;
-    prob[2][2] += p_1882;
-    prob[3][2] += p_1882;
-let p_1883 = p_1675 * p_1_4_8;
-    prob[0][3] += 19_f64 * p_1883;
-    prob[1][3] += 6_f64 * p_1883;
-    prob[2][3] += 7_f64 * p_1883;
-    prob[5][3] += 2_f64 * p_1883;
-let p_1884 = p_1676 * p_1_3_8;
-    prob[1][4] += 6_f64 * p_1884;
-    prob[2][4] += 3_f64 * p_1884;
-    prob[3][4] += 3_f64 * p_1884;
-let p_1885 = p_1680 * p_1_5_8;
-    prob[0][3] += 3_f64 * p_1885;
-    prob[3][3] += p_1885;
-let p_1886 = p_1684 * p_1_6_8;
-    prob[0][1] += p_1886;
-let p_1887 = p_1685 * p_1_5_8;
-    prob[0][2] += 5_f64 * p_1887;
-    prob[1][2] += p_1887;
-let p_1888 = p_1686 * p_1_4_8;
-    prob[0][3] += 11_f64 * p_1888;
-    prob[1][3] += 5_f64 * p_1888;
-let p_1889 = p_1687 * p_1_3_8;
-    prob[0][4] += 13_f64 * p_1889;
-    prob[1][4] += 16_f64 * p_1889;
-    prob[4][4] += p_1889;
-    prob[5][4] += p_1889;
-let p_1890 = p_1689 * p_1_5_8;
-    prob[0][3] += 4_f64 * p_1890;
-    prob[2][3] += p_1890;
-let p_1891 = p_1690 * p_1_4_8;
-    prob[0][4] += 19_f64 * p_1891;
-    prob[1][4] += 6_f64 * p_1891;
-    prob[2][4] += 7_f64 * p_1891;
-    prob[5][4] += 2_f64 * p_1891;
-let p_1892 = p_1693 * p_1_5_8;
-    prob[0][4] += 3_f64 * p_1892;
-    prob[3][4] += p_1892;
-let p_1893 = p_1696 * p_1_6_8;
-    prob[0][2] += p_1893;
-let p_1894 = p_1697 * p_1_5_8;
-    prob[0][3] += 5_f64 * p_1894;
-    prob[1][3] += p_1894;
-let p_1895 = p_1698 * p_1_4_8;
-    prob[0][4] += 11_f64 * p_1895;
-    prob[1][4] += 5_f64 * p_1895;
-let p_1896 = p_1700 * p_1_5_8;
-    prob[0][4] += 4_f64 * p_1896;
-    prob[2][4] += p_1896;
-let p_1897 = p_1524 * p_7_0_8;
-    prob[0][0] += 32_f64 * p_1897;
-    prob[1][0] += 14_f64 * p_1897;
-    prob[6][0] += p_1897;
-let p_1898 = p_1703 * p_1_4_8;
-    prob[0][1] += 22_f64 * p_1898;
-    prob[1][1] += 12_f64 * p_1898;
-    prob[6][1] += p_1898;
-let p_1899 = p_1705 * p_1_1_8;
-    prob[1][4] += p_1899;
-let p_1900 = p_1529 * p_7_0_8;
-    prob[0][0] += 10_f64 * p_1900;
-    prob[2][0] += 2_f64 * p_1900;
-let p_1901 = p_1707 * p_1_5_8;
-    prob[0][1] += 18_f64 * p_1901;
-    prob[1][1] += 2_f64 * p_1901;
-    prob[2][1] += 2_f64 * p_1901;
-let p_1902 = p_1708 * p_1_4_8;
-    prob[0][2] += 22_f64 * p_1902;
-    prob[1][2] += 12_f64 * p_1902;
-    prob[6][2] += p_1902;
-let p_1903 = p_1710 * p_1_2_8;
-    prob[1][4] += 4_f64 * p_1903;
-    prob[2][4] += p_1903;
-let p_1904 = p_1712 * p_1_5_8;
-    prob[0][2] += 8_f64 * p_1904;
-    prob[2][2] += 2_f64 * p_1904;
-let p_1905 = p_1716 * p_1_6_8;
-    prob[0][1] += 2_f64 * p_1905;
-let p_1906 = p_1717 * p_1_5_8;
-    prob[0][2] += 10_f64 * p_1906;
-    prob[1][2] += 2_f64 * p_1906;
-let p_1907 = p_1718 * p_1_4_8;
-    prob[0][3] += 22_f64 * p_1907;
-    prob[1][3] += 12_f64 * p_1907;
-    prob[6][3] += p_1907;
-let p_1908 = p_1721 * p_1_5_8;
-    prob[0][3] += 8_f64 * p_1908;
-    prob[2][3] += 2_f64 * p_1908;
-let p_1909 = p_1725 * p_1_6_8;
-    prob[0][2] += 2_f64 * p_1909;
-let p_1910 = p_1726 * p_1_5_8;
-    prob[0][3] += 10_f64 * p_1910;
-    prob[1][3] += 2_f64 * p_1910;
-let p_1911 = p_1727 * p_1_4_8;
-    prob[0][4] += 22_f64 * p_1911;
-    prob[1][4] += 12_f64 * p_1911;
-    prob[6][4] += p_1911;
-let p_1912 = p_1729 * p_1_5_8;
-    prob[0][4] += 8_f64 * p_1912;
-    prob[2][4] += 2_f64 * p_1912;
-let p_1913 = p_1732 * p_1_6_8;
-    prob[0][3] += 3_f64 * p_1913;
-let p_1914 = p_1733 * p_1_5_8;
-    prob[0][4] += 15_f64 * p_1914;
-    prob[1][4] += 3_f64 * p_1914;
-let p_1915 = p_1736 * p_1_6_8;
-    prob[0][4] += 2_f64 * p_1915;
-let p_1916 = p_1523 * p_8_0_8;
-    prob[0][0] += 18_f64 * p_1916;
-    prob[1][0] += 3_f64 * p_1916;
-let p_1917 = p_1738 * p_1_5_8;
-    prob[0][1] += 15_f64 * p_1917;
-    prob[1][1] += 3_f64 * p_1917;
-let p_1918 = p_1741 * p_1_6_8;
-    prob[0][1] += 3_f64 * p_1918;
-let p_1919 = p_1742 * p_1_5_8;
-    prob[0][2] += 15_f64 * p_1919;
-    prob[1][2] += 3_f64 * p_1919;
-let p_1920 = p_1746 * p_1_6_8;
-    prob[0][2] += 3_f64 * p_1920;
-let p_1921 = p_1747 * p_1_5_8;
-    prob[0][3] += 15_f64 * p_1921;
-    prob[1][3] += 3_f64 * p_1921;
-let p_1922 = p_1750 * p_1_6_8;
-    prob[0][3] += 3_f64 * p_1922;
-let p_1923 = p_1751 * p_1_5_8;
-    prob[0][4] += 15_f64 * p_1923;
-    prob[1][4] += 3_f64 * p_1923;
-let p_1924 = p_1754 * p_1_6_8;
-    prob[0][4] += 4_f64 * p_1924;
-let p_1925 = p_1522 * p_9_0_8;
-    prob[0][0] += 4_f64 * p_1925;
-let p_1926 = p_1757 * p_1_6_8;
-    prob[0][1] += 4_f64 * p_1926;
-let p_1927 = p_1759 * p_1_6_8;
-    prob[0][2] += 4_f64 * p_1927;
-let p_1928 = p_1761 * p_1_6_8;
-    prob[0][3] += 4_f64 * p_1928;
-let p_1929 = p_1763 * p_1_6_8;
-    prob[0][4] += 4_f64 * p_1929;
-let p_1930 = p_1771 * p_1_9_9;
-let p_1931 = p_1773 * p_1_3_9;
-let p_1932 = p_1774 * p_1_2_9;
-    prob[1][0] += 4_f64 * p_1932;
-let p_1933 = p_1775 * p_1_1_9;
-    prob[1][1] += 3_f64 * p_1933;
-let p_1934 = p_1777 * p_1_4_9;
-let p_1935 = p_1778 * p_1_3_9;
-    prob[1][0] += 21_f64 * p_1935;
-    prob[2][0] += 5_f64 * p_1935;
-let p_1936 = p_1779 * p_1_2_9;
-    prob[1][1] += 18_f64 * p_1936;
-    prob[2][1] += 4_f64 * p_1936;
-let p_1937 = p_1780 * p_1_1_9;
-    prob[1][2] += 3_f64 * p_1937;
-let p_1938 = p_1782 * p_1_4_9;
-    prob[1][0] += 6_f64 * p_1938;
-    prob[2][0] += 4_f64 * p_1938;
-let p_1939 = p_1783 * p_1_3_9;
-    prob[1][1] += 10_f64 * p_1939;
-    prob[2][1] += 5_f64 * p_1939;
-let p_1940 = p_1784 * p_1_2_9;
-    prob[1][2] += 17_f64 * p_1940;
-    prob[2][2] += 4_f64 * p_1940;
-let p_1941 = p_1786 * p_1_5_9;
-    prob[1][0] += p_1941;
-    prob[2][0] += 3_f64 * p_1941;
-let p_1942 = p_1787 * p_1_4_9;
-    prob[1][1] += p_1942;
-    prob[2][1] += 3_f64 * p_1942;
-let p_1943 = p_1788 * p_1_3_9;
-    prob[1][2] += 6_f64 * p_1943;
-    prob[2][2] += 4_f64 * p_1943;
-let p_1944 = p_1791 * p_1_4_9;
-    prob[1][2] += p_1944;
-    prob[2][2] += 3_f64 * p_1944;
-let p_1945 = p_1794 * p_1_4_9;
-    prob[1][0] += 4_f64 * p_1945;
-    prob[3][0] += p_1945;
-let p_1946 = p_1795 * p_1_3_9;
-    prob[1][1] += 4_f64 * p_1946;
-    prob[3][1] += p_1946;
-let p_1947 = p_1796 * p_1_2_9;
-    prob[1][2] += p_1947;
-let p_1948 = p_1797 * p_1_1_9;
-    prob[1][3] += 3_f64 * p_1948;
-let p_1949 = p_1799 * p_1_5_9;
-    prob[1][0] += 6_f64 * p_1949;
-    prob[2][0] += 3_f64 * p_1949;
-    prob[3][0] += 3_f64 * p_1949;
-let p_1950 = p_1800 * p_1_4_9;
-    prob[1][1] += 6_f64 * p_1950;
-    prob[2][1] += 3_f64 * p_1950;
-    prob[3][1] += 3_f64 * p_1950;
-let p_1951 = p_1801 * p_1_3_9;
-    prob[1][2] += 8_f64 * p_1951;
-    prob[2][2] += p_1951;
-    prob[3][2] += p_1951;
-let p_1952 = p_1802 * p_1_2_9;
-    prob[1][3] += 17_f64 * p_1952;
-    prob[2][3] += 4_f64 * p_1952;
-let p_1953 = p_1805 * p_1_4_9;
-    prob[1][2] += 6_f64 * p_1953;
-    prob[2][2] += 3_f64 * p_1953;
-    prob[3][2] += 3_f64 * p_1953;
-let p_1954 = p_1806 * p_1_3_9;
-    prob[1][3] += 6_f64 * p_1954;
-    prob[2][3] += 4_f64 * p_1954;
-let p_1955 = p_1808 * p_1_4_9;
-    prob[1][3] += p_1955;
-    prob[2][3] += 3_f64 * p_1955;
-let p_1956 = p_1813 * p_1_3_9;
-    prob[1][3] += 4_f64 * p_1956;
-    prob[3][3] += p_1956;
-let p_1957 = p_1817 * p_1_4_9;
-    prob[1][3] += 6_f64 * p_1957;
-    prob[2][3] += 3_f64 * p_1957;
-    prob[3][3] += 3_f64 * p_1957;
-let p_1958 = p_1823 * p_1_5_9;
-    prob[1][0] += 3_f64 * p_1958;
-    prob[4][0] += p_1958;
-let p_1959 = p_1824 * p_1_4_9;
-    prob[1][1] += 3_f64 * p_1959;
-    prob[4][1] += p_1959;
-let p_1960 = p_1826 * p_1_2_9;
-    prob[1][3] += p_1960;
-let p_1961 = p_1827 * p_1_1_9;
-    prob[1][4] += 3_f64 * p_1961;
-let p_1962 = p_1830 * p_1_4_9;
-    prob[1][2] += 3_f64 * p_1962;
-    prob[4][2] += p_1962;
-let p_1963 = p_1831 * p_1_3_9;
-    prob[1][3] += 4_f64 * p_1963;
-    prob[2][3] += p_1963;
-let p_1964 = p_1832 * p_1_2_9;
-    prob[1][4] += 17_f64 * p_1964;
-    prob[2][4] += 4_f64 * p_1964;
-let p_1965 = p_1837 * p_1_3_9;
-    prob[1][4] += 6_f64 * p_1965;
-    prob[2][4] += 4_f64 * p_1965;
-let p_1966 = p_1840 * p_1_4_9;
-    prob[1][4] += p_1966;
-    prob[2][4] += 3_f64 * p_1966;
-let p_1967 = p_1845 * p_1_4_9;
-    prob[1][3] += 3_f64 * p_1967;
-    prob[4][3] += p_1967;
-let p_1968 = p_1846 * p_1_3_9;
-    prob[1][4] += 4_f64 * p_1968;
-    prob[3][4] += p_1968;
-let p_1969 = p_1849 * p_1_4_9;
-    prob[1][4] += 6_f64 * p_1969;
-    prob[2][4] += 3_f64 * p_1969;
-    prob[3][4] += 3_f64 * p_1969;
-let p_1970 = p_1856 * p_1_4_9;
-    prob[1][4] += 3_f64 * p_1970;
-    prob[4][4] += p_1970;
-let p_1971 = p_1861 * p_2_0_9;
-let p_1972 = p_1862 * p_1_6_9;
-    prob[0][0] += 22_f64 * p_1972;
-    prob[1][0] += 7_f64 * p_1972;
-let p_1973 = p_1863 * p_1_5_9;
-    prob[0][1] += 16_f64 * p_1973;
-    prob[1][1] += 6_f64 * p_1973;
-let p_1974 = p_1865 * p_1_2_9;
-    prob[1][4] += p_1974;
-let p_1975 = p_1867 * p_1_7_9;
-    prob[0][0] += 6_f64 * p_1975;
-    prob[2][0] += p_1975;
-let p_1976 = p_1868 * p_1_6_9;
-    prob[0][1] += 11_f64 * p_1976;
-    prob[1][1] += p_1976;
-    prob[2][1] += p_1976;
-let p_1977 = p_1869 * p_1_5_9;
-    prob[0][2] += 16_f64 * p_1977;
-    prob[1][2] += 6_f64 * p_1977;
-let p_1978 = p_1871 * p_1_3_9;
-    prob[1][4] += 4_f64 * p_1978;
-    prob[2][4] += p_1978;
-let p_1979 = p_1873 * p_1_6_9;
-    prob[0][2] += 5_f64 * p_1979;
-    prob[2][2] += p_1979;
-let p_1980 = p_1877 * p_1_7_9;
-    prob[0][1] += p_1980;
-let p_1981 = p_1878 * p_1_6_9;
-    prob[0][2] += 6_f64 * p_1981;
-    prob[1][2] += p_1981;
-let p_1982 = p_1879 * p_1_5_9;
-    prob[0][3] += 16_f64 * p_1982;
-    prob[1][3] += 6_f64 * p_1982;
-let p_1983 = p_1882 * p_1_6_9;
-    prob[0][3] += 5_f64 * p_1983;
-    prob[2][3] += p_1983;
-let p_1984 = p_1886 * p_1_7_9;
-    prob[0][2] += p_1984;
-let p_1985 = p_1887 * p_1_6_9;
-    prob[0][3] += 6_f64 * p_1985;
-    prob[1][3] += p_1985;
-let p_1986 = p_1888 * p_1_5_9;
-    prob[0][4] += 16_f64 * p_1986;
-    prob[1][4] += 6_f64 * p_1986;
-let p_1987 = p_1890 * p_1_6_9;
-    prob[0][4] += 5_f64 * p_1987;
-    prob[2][4] += p_1987;
-let p_1988 = p_1893 * p_1_7_9;
-    prob[0][3] += p_1988;
-let p_1989 = p_1894 * p_1_6_9;
-    prob[0][4] += 6_f64 * p_1989;
-    prob[1][4] += p_1989;
-let p_1990 = p_1772 * p_7_0_9;
-    prob[0][0] += 14_f64 * p_1990;
-    prob[1][0] += 2_f64 * p_1990;
-let p_1991 = p_1897 * p_1_6_9;
-    prob[0][1] += 12_f64 * p_1991;
-    prob[1][1] += 2_f64 * p_1991;
-let p_1992 = p_1900 * p_1_7_9;
-    prob[0][1] += 2_f64 * p_1992;
-let p_1993 = p_1901 * p_1_6_9;
-    prob[0][2] += 12_f64 * p_1993;
-    prob[1][2] += 2_f64 * p_1993;
-let p_1994 = p_1905 * p_1_7_9;
-    prob[0][2] += 2_f64 * p_1994;
-let p_1995 = p_1906 * p_1_6_9;
-    prob[0][3] += 12_f64 * p_1995;
-    prob[1][3] += 2_f64 * p_1995;
-let p_1996 = p_1909 * p_1_7_9;
-    prob[0][3] += 2_f64 * p_1996;
-let p_1997 = p_1910 * p_1_6_9;
-    prob[0][4] += 12_f64 * p_1997;
-    prob[1][4] += 2_f64 * p_1997;
-let p_1998 = p_1913 * p_1_7_9;
-    prob[0][4] += 3_f64 * p_1998;
-let p_1999 = p_1771 * p_8_0_9;
-    prob[0][0] += 3_f64 * p_1999;
-let p_2000 = p_1916 * p_1_7_9;
-    prob[0][1] += 3_f64 * p_2000;
-let p_2001 = p_1918 * p_1_7_9;
-    prob[0][2] += 3_f64 * p_2001;
-let p_2002 = p_1920 * p_1_7_9;
-    prob[0][3] += 3_f64 * p_2002;
-let p_2003 = p_1922 * p_1_7_9;
-    prob[0][4] += 3_f64 * p_2003;
-let p_2004 = p_1931 * p_1_4_10;
-    prob[1][0] += p_2004;
-let p_2005 = p_1932 * p_1_3_10;
-    prob[1][1] += p_2005;
-let p_2006 = p_1934 * p_1_5_10;
-    prob[1][0] += 4_f64 * p_2006;
-    prob[2][0] += p_2006;
-let p_2007 = p_1935 * p_1_4_10;
-    prob[1][1] += 4_f64 * p_2007;
-    prob[2][1] += p_2007;
-let p_2008 = p_1936 * p_1_3_10;
-    prob[1][2] += p_2008;
-let p_2009 = p_1939 * p_1_4_10;
-    prob[1][2] += 4_f64 * p_2009;
-    prob[2][2] += p_2009;
-let p_2010 = p_1947 * p_1_3_10;
-    prob[1][3] += p_2010;
-let p_2011 = p_1951 * p_1_4_10;
-    prob[1][3] += 4_f64 * p_2011;
-    prob[2][3] += p_2011;
-let p_2012 = p_1960 * p_1_3_10;
-    prob[1][4] += p_2012;
-let p_2013 = p_1963 * p_1_4_10;
-    prob[1][4] += 4_f64 * p_2013;
-    prob[2][4] += p_2013;
-let p_2014 = p_1930 * p_6_0_10;
-let p_2015 = p_1971 * p_1_8_10;
-    prob[0][0] += 8_f64 * p_2015;
-    prob[1][0] += p_2015;
-let p_2016 = p_1972 * p_1_7_10;
-    prob[0][1] += 7_f64 * p_2016;
-    prob[1][1] += p_2016;
-let p_2017 = p_1975 * p_1_8_10;
-    prob[0][1] += p_2017;
-let p_2018 = p_1976 * p_1_7_10;
-    prob[0][2] += 7_f64 * p_2018;
-    prob[1][2] += p_2018;
-let p_2019 = p_1980 * p_1_8_10;
-    prob[0][2] += p_2019;
-let p_2020 = p_1981 * p_1_7_10;
-    prob[0][3] += 7_f64 * p_2020;
-    prob[1][3] += p_2020;
-let p_2021 = p_1984 * p_1_8_10;
-    prob[0][3] += p_2021;
-let p_2022 = p_1985 * p_1_7_10;
-    prob[0][4] += 7_f64 * p_2022;
-    prob[1][4] += p_2022;
-let p_2023 = p_1988 * p_1_8_10;
-    prob[0][4] += p_2023;
-let p_2024 = p_1930 * p_7_0_10;
-    prob[0][0] += 2_f64 * p_2024;
-let p_2025 = p_1990 * p_1_8_10;
-    prob[0][1] += 2_f64 * p_2025;
-let p_2026 = p_1992 * p_1_8_10;
-    prob[0][2] += 2_f64 * p_2026;
-let p_2027 = p_1994 * p_1_8_10;
-    prob[0][3] += 2_f64 * p_2027;
-let p_2028 = p_1996 * p_1_8_10;
-    prob[0][4] += 2_f64 * p_2028;
-let p_2029 = p_2014 * p_1_10_11;
-    prob[0][0] += p_2029;
-let p_2030 = p_2015 * p_1_9_11;
-    prob[0][1] += p_2030;
-let p_2031 = p_2017 * p_1_9_11;
-    prob[0][2] += p_2031;
-let p_2032 = p_2019 * p_1_9_11;
-    prob[0][3] += p_2032;
-let p_2033 = p_2021 * p_1_9_11;
-    prob[0][4] += p_2033;
-
-let mut ret = [DealerProb::default(); 10];
-for (i,p) in prob.iter().enumerate() {
-    if (d[i] == 0) {
-        ret[i] = DealerProb{
-            p_17: 0.0,
-            p_18: 0.0,
-            p_19: 0.0,
-            p_20: 0.0,
-            p_21: 0.0,
-            p_bust: 1.0,
-            p_bj: 0.0
-        };
-        continue;
+impl DealerProb {
+  // The chances given the dealer doesn't have blackjack, as after a peek
+  pub fn without_blackjack(&self) -> DealerProb {
+    let no_bj = 1.0 - self.p_bj;
+    if no_bj <= 0.0 {
+      return DealerProb::default();
     }
-  let mut dp = DealerProb{
-      p_17: p[0] / d[i] as f64, 
-      p_18: p[1] / d[i] as f64, 
-      p_19: p[2] / d[i] as f64, 
-      p_20: p[3] / d[i] as f64, 
-      p_21: p[4] / d[i] as f64, 
-      p_bust: 1.0,
-      p_bj: if i == 0 && d[9] != 0 {d[9] as f64 / (t-1) as f64} else if i == 9 && d[0] != 0 {d[0] as f64 / (t-1) as f64} else {0.0}
-    };
-  dp.p_bust = dp.p_bust - dp.p_17 - dp.p_18 - dp.p_19 - dp.p_20 - dp.p_21 - dp.p_bj;
-  ret[i] = dp;
-};
-ret
-}
\ No newline at end of file
+    let mut p_low = self.p_low;
+    for p in p_low.iter_mut() {
+      *p /= no_bj;
+    }
+    DealerProb {
+      p_17: self.p_17 / no_bj,
+      p_18: self.p_18 / no_bj,
+      p_19: self.p_19 / no_bj,
+      p_20: self.p_20 / no_bj,
+      p_21: self.p_21 / no_bj,
+      p_bust: self.p_bust / no_bj,
+      p_22: self.p_22 / no_bj,
+      p_bj: 0.0,
+      p_low,
+    }
+  }
+}
@@ -0,0 +1,274 @@
+use crate::DealerProb;
+use std::collections::HashMap;
+
+// Decides whether a dealer holding at least two cards stops drawing
+pub trait StoppingRule {
+  fn stands(&self, total: u32, soft: bool) -> bool;
+}
+
+// Stand on all 17s
+pub struct StandSoft17;
+
+// Hit soft 17, stand on hard 17 and above
+pub struct HitSoft17;
+
+// Stand on any total of at least the given value, soft or hard
+pub struct StandOn(pub u32);
+
+impl StoppingRule for StandSoft17 {
+  fn stands(&self, total: u32, _soft: bool) -> bool {
+    total >= 17
+  }
+}
+
+impl StoppingRule for HitSoft17 {
+  fn stands(&self, total: u32, soft: bool) -> bool {
+    total > 17 || (total == 17 && !soft)
+  }
+}
+
+impl StoppingRule for StandOn {
+  fn stands(&self, total: u32, _soft: bool) -> bool {
+    total >= self.0
+  }
+}
+
+impl<F> StoppingRule for F
+where
+  F: Fn(u32, bool) -> bool,
+{
+  fn stands(&self, total: u32, soft: bool) -> bool {
+    self(total, soft)
+  }
+}
+
+// Final dealer totals 0..=21, then blackjack, then a bust on exactly 22.
+// Whatever is missing is some other bust.
+type Outcome = [f64; 24];
+const BLACKJACK: usize = 22;
+const TWENTY_TWO: usize = 23;
+
+struct Walker<'a, R: ?Sized> {
+  deck: &'a [usize; 10],
+  total: usize,
+  rule: &'a R,
+  // Cards the dealer holds that were never in `deck`, like known draws
+  extra: [u8; 10],
+  extra_cards: usize,
+  // The dealer's future only depends on which cards they hold, not the order
+  // they came in, so hands are memoised by composition
+  memo: HashMap<[u8; 10], Outcome>,
+}
+
+impl<'a, R: StoppingRule + ?Sized> Walker<'a, R> {
+  fn new(deck: &'a [usize; 10], rule: &'a R) -> Self {
+    Walker {
+      deck,
+      total: deck.iter().sum(),
+      rule,
+      extra: [0; 10],
+      extra_cards: 0,
+      memo: HashMap::new(),
+    }
+  }
+
+  // The memo is only good for one set of extra cards
+  fn set_extra(&mut self, extra: [u8; 10]) {
+    if extra != self.extra {
+      self.extra = extra;
+      self.extra_cards = extra.iter().map(|e| *e as usize).sum();
+      self.memo.clear();
+    }
+  }
+
+  fn walk(&mut self, drawn: &mut [u8; 10], cards: usize, hard: u32) -> Outcome {
+    let soft = drawn[0] > 0 && hard <= 11;
+    let total = if soft { hard + 10 } else { hard };
+    let mut ret = [0_f64; 24];
+    if total == 22 {
+      ret[TWENTY_TWO] = 1.0;
+    }
+    if total > 21 {
+      return ret;
+    }
+    if cards == 2 && total == 21 {
+      ret[BLACKJACK] = 1.0;
+      return ret;
+    }
+    if cards >= 2 && self.rule.stands(total, soft) {
+      ret[total as usize] = 1.0;
+      return ret;
+    }
+    if let Some(memo) = self.memo.get(drawn) {
+      return *memo;
+    }
+
+    let left = self.total + self.extra_cards - cards;
+    if left > 0 {
+      for c in 0..10 {
+        let count = self.deck[c] + self.extra[c] as usize - drawn[c] as usize;
+        if count == 0 {
+          continue;
+        }
+        let p = count as f64 / left as f64;
+        drawn[c] += 1;
+        let next = self.walk(drawn, cards + 1, hard + c as u32 + 1);
+        drawn[c] -= 1;
+        for (r, n) in ret.iter_mut().zip(next.iter()) {
+          *r += p * n;
+        }
+      }
+    }
+    self.memo.insert(*drawn, ret);
+    ret
+  }
+}
+
+pub fn calculate_dealer_prob_with<R>(d: &[usize; 10], rule: &R) -> [DealerProb; 10]
+where
+  R: StoppingRule + ?Sized,
+{
+  calculate(d, rule, None)
+}
+
+// Same again with the dealer's hole card known, given as an index into `d`,
+// which still has to count it. Up cards that leave no such hole card behind
+// come out as a certain bust, like up cards missing from the deck.
+pub fn calculate_dealer_prob_with_hole<R>(d: &[usize; 10], rule: &R, hole: usize) -> [DealerProb; 10]
+where
+  R: StoppingRule + ?Sized,
+{
+  let mut holes = [false; 10];
+  holes[hole] = true;
+  calculate(d, rule, Some(&holes))
+}
+
+// Or only known to be one of the ranks marked in `holes`, each as likely as
+// its count in `d` makes it
+pub fn calculate_dealer_prob_with_hole_in<R>(
+  d: &[usize; 10],
+  rule: &R,
+  holes: &[bool; 10],
+) -> [DealerProb; 10]
+where
+  R: StoppingRule + ?Sized,
+{
+  calculate(d, rule, Some(holes))
+}
+
+fn calculate<R>(d: &[usize; 10], rule: &R, holes: Option<&[bool; 10]>) -> [DealerProb; 10]
+where
+  R: StoppingRule + ?Sized,
+{
+  let mut walker = Walker::new(d, rule);
+  let mut ret = [DealerProb::default(); 10];
+  for (i, dp) in ret.iter_mut().enumerate() {
+    if d[i] == 0 {
+      dp.p_bust = 1.0;
+      continue;
+    }
+    let mut drawn = [0_u8; 10];
+    drawn[i] = 1;
+    let o = match holes {
+      None => walker.walk(&mut drawn, 1, i as u32 + 1),
+      Some(holes) => {
+        let mut weights = [0_f64; 10];
+        for (h, w) in weights.iter_mut().enumerate() {
+          if holes[h] {
+            *w = (d[h] - drawn[h] as usize) as f64;
+          }
+        }
+        let total: f64 = weights.iter().sum();
+        if total == 0.0 {
+          dp.p_bust = 1.0;
+          continue;
+        }
+        let mut o = [0_f64; 24];
+        for (h, w) in weights.iter().enumerate() {
+          if *w == 0.0 {
+            continue;
+          }
+          drawn[h] += 1;
+          let next = walker.walk(&mut drawn, 2, (i + h) as u32 + 2);
+          drawn[h] -= 1;
+          for (r, n) in o.iter_mut().zip(next.iter()) {
+            *r += w / total * n;
+          }
+        }
+        o
+      }
+    };
+    *dp = to_dealer_prob(&o);
+  }
+  ret
+}
+
+// Same again with the dealer's first hits known to be `draws`, in order. They
+// come off the shoe after the hole card, so `d` doesn't count them. Once they
+// run out the dealer draws from `d` as usual.
+pub fn calculate_dealer_prob_with_draws<R>(
+  d: &[usize; 10],
+  rule: &R,
+  draws: &[usize],
+) -> [DealerProb; 10]
+where
+  R: StoppingRule + ?Sized,
+{
+  let mut walker = Walker::new(d, rule);
+  let mut ret = [DealerProb::default(); 10];
+  for (i, dp) in ret.iter_mut().enumerate() {
+    let left = walker.total.saturating_sub(1);
+    if d[i] == 0 || left == 0 {
+      dp.p_bust = 1.0;
+      continue;
+    }
+    let mut o = [0_f64; 24];
+    for h in 0..10 {
+      let count = d[h] - (h == i) as usize;
+      if count == 0 {
+        continue;
+      }
+      let mut drawn = [0_u8; 10];
+      drawn[i] += 1;
+      drawn[h] += 1;
+      let mut cards = 2;
+      let mut hard = (i + h) as u32 + 2;
+      let mut extra = [0_u8; 10];
+      for &c in draws {
+        let soft = drawn[0] > 0 && hard <= 11;
+        let total = if soft { hard + 10 } else { hard };
+        if total > 21 || (cards == 2 && total == 21) || rule.stands(total, soft) {
+          break;
+        }
+        drawn[c] += 1;
+        extra[c] += 1;
+        cards += 1;
+        hard += c as u32 + 1;
+      }
+      walker.set_extra(extra);
+      let next = walker.walk(&mut drawn, cards, hard);
+      let p = count as f64 / left as f64;
+      for (r, n) in o.iter_mut().zip(next.iter()) {
+        *r += p * n;
+      }
+    }
+    *dp = to_dealer_prob(&o);
+  }
+  ret
+}
+
+fn to_dealer_prob(o: &Outcome) -> DealerProb {
+  let mut p_low = [0_f64; 17];
+  p_low.copy_from_slice(&o[..17]);
+  DealerProb {
+    p_17: o[17],
+    p_18: o[18],
+    p_19: o[19],
+    p_20: o[20],
+    p_21: o[21],
+    p_bust: 1.0 - o[..TWENTY_TWO].iter().sum::<f64>(),
+    p_22: o[TWENTY_TWO],
+    p_bj: o[BLACKJACK],
+    p_low,
+  }
+}
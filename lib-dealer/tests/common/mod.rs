@@ -0,0 +1,16 @@
+use lib_dealer::DealerProb;
+
+pub fn assert_close(a: &DealerProb, b: &DealerProb) {
+  let pairs = [
+    (a.p_17, b.p_17),
+    (a.p_18, b.p_18),
+    (a.p_19, b.p_19),
+    (a.p_20, b.p_20),
+    (a.p_21, b.p_21),
+    (a.p_bust, b.p_bust),
+    (a.p_bj, b.p_bj),
+  ];
+  for (x, y) in pairs.iter().cloned().chain(a.p_low.iter().cloned().zip(b.p_low.iter().cloned())) {
+    assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+  }
+}
@@ -0,0 +1,268 @@
+mod common;
+
+use common::assert_close;
+use lib_dealer::generated::*;
+use lib_dealer::*;
+
+fn decks() -> Vec<[usize; 10]> {
+  vec![
+    [4, 4, 4, 4, 4, 4, 4, 4, 4, 16],
+    [24, 24, 24, 24, 24, 24, 24, 24, 24, 96],
+    [2, 0, 3, 1, 0, 5, 2, 1, 1, 3],
+    [0, 0, 0, 0, 0, 0, 0, 1, 3, 2],
+  ]
+}
+
+fn assert_all_close(a: &[DealerProb; 10], b: &[DealerProb; 10]) {
+  for (a, b) in a.iter().zip(b.iter()) {
+    assert_close(a, b);
+  }
+}
+
+// What the checked-in unrolled calculate_dealer_prob gave before the generated
+// s17 replaced it, as p_17 to p_21, p_bust and p_bj for each up card
+const UNROLLED: [([usize; 10], [[f64; 7]; 10]); 2] = [
+  (
+    [4, 4, 4, 4, 4, 4, 4, 4, 4, 16],
+    [
+      [
+        0.12612760478844642,
+        0.1310030551059321,
+        0.12948620543349973,
+        0.13155333073155817,
+        0.051564646924187975,
+        0.1165396668202971,
+        0.3137254901960784,
+      ],
+      [
+        0.1389758329816944,
+        0.13176227076179256,
+        0.131815453426022,
+        0.12394809967438498,
+        0.12052579999851118,
+        0.35297254315759496,
+        0.0,
+      ],
+      [
+        0.1303131848315813,
+        0.13094638612354662,
+        0.12376109486546633,
+        0.12334456949789817,
+        0.11604681572217114,
+        0.37558794895933656,
+        0.0,
+      ],
+      [
+        0.1309726514887625,
+        0.11416293951400726,
+        0.12067875418066631,
+        0.11628648799961813,
+        0.11509609619105543,
+        0.4028030706258904,
+        0.0,
+      ],
+      [
+        0.11968729038443507,
+        0.12348305862492076,
+        0.11690947571246697,
+        0.10469365813771471,
+        0.10632136429637969,
+        0.42890515284408287,
+        0.0,
+      ],
+      [
+        0.16694766812059395,
+        0.10645402905927319,
+        0.10719186053213833,
+        0.10070493166664622,
+        0.09787847663196511,
+        0.42082303398938326,
+        0.0,
+      ],
+      [
+        0.3723448664078644,
+        0.13858338154873415,
+        0.0773344312748226,
+        0.07889666587161126,
+        0.07298698778514388,
+        0.2598536671118237,
+        0.0,
+      ],
+      [
+        0.13085746416975672,
+        0.3629893657123657,
+        0.12944463829318348,
+        0.06828976376242762,
+        0.06979142147145635,
+        0.2386273465908102,
+        0.0,
+      ],
+      [
+        0.12188622720133377,
+        0.10392095413558577,
+        0.35739125005537214,
+        0.12225027908172213,
+        0.061108829594125655,
+        0.23344245993186047,
+        0.0,
+      ],
+      [
+        0.11441819280903848,
+        0.11287896102011571,
+        0.11466240520259909,
+        0.3288790989723056,
+        0.03646613378623112,
+        0.21426383566069046,
+        0.0784313725490196,
+      ],
+    ],
+  ),
+  (
+    [2, 0, 3, 1, 0, 5, 2, 1, 1, 3],
+    [
+      [
+        0.3228991596638655,
+        0.1789996767937944,
+        0.08116650506356386,
+        0.13508134022839904,
+        0.06844295410471882,
+        0.036939775910364236,
+        0.17647058823529413,
+      ],
+      [
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+        0.0,
+      ],
+      [
+        0.1322963800904977,
+        0.11088935574229693,
+        0.1919790993320404,
+        0.18187621202327078,
+        0.10629848093083384,
+        0.27666047188106035,
+        0.0,
+      ],
+      [
+        0.170203081232493,
+        0.11421299288946345,
+        0.11384669252316308,
+        0.17304998922645984,
+        0.17226756087050202,
+        0.25641968325791864,
+        0.0,
+      ],
+      [
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+        0.0,
+      ],
+      [
+        0.19465632406808875,
+        0.09402607196724842,
+        0.1706232492997199,
+        0.0996417797888386,
+        0.07866839043309629,
+        0.36238418444300813,
+        0.0,
+      ],
+      [
+        0.2700280112044818,
+        0.15481307907778497,
+        0.12798696401637577,
+        0.10539215686274508,
+        0.08078808446455503,
+        0.26099170437405744,
+        0.0,
+      ],
+      [
+        0.17219887955182073,
+        0.27689075630252097,
+        0.14549666020254257,
+        0.09448125404007757,
+        0.13403361344537812,
+        0.17689883645766002,
+        0.0,
+      ],
+      [
+        0.08249299719887955,
+        0.11197478991596639,
+        0.27689075630252097,
+        0.15217086834733892,
+        0.08739495798319327,
+        0.2890756302521007,
+        0.0,
+      ],
+      [
+        0.18235294117647058,
+        0.06197478991596638,
+        0.17079831932773107,
+        0.1852941176470588,
+        0.02429971988795518,
+        0.2576330532212885,
+        0.11764705882352941,
+      ],
+    ],
+  ),
+];
+
+#[test]
+fn s17_matches_unrolled() {
+  for (d, expected) in UNROLLED.iter() {
+    for (p, e) in calculate_dealer_prob(d).iter().zip(expected.iter()) {
+      let got = [p.p_17, p.p_18, p.p_19, p.p_20, p.p_21, p.p_bust, p.p_bj];
+      // The products are only summed in a different order
+      for (x, y) in got.iter().zip(e.iter()) {
+        assert!((x - y).abs() < 1e-15, "{:?} != {:?}", got, e);
+      }
+    }
+  }
+}
+
+#[test]
+fn h17_matches_recursive() {
+  for d in decks() {
+    assert_all_close(
+      &calculate_dealer_prob_h17(&d),
+      &calculate_dealer_prob_with(&d, &HitSoft17),
+    );
+  }
+}
+
+#[test]
+fn s16_matches_recursive() {
+  for d in decks() {
+    assert_all_close(
+      &calculate_dealer_prob_s16(&d),
+      &calculate_dealer_prob_with(&d, &StandOn(16)),
+    );
+  }
+}
+
+#[test]
+fn p_22_matches_recursive() {
+  for d in decks() {
+    let pairs = [
+      (calculate_dealer_prob(&d), calculate_dealer_prob_with(&d, &StandSoft17)),
+      (calculate_dealer_prob_s17(&d), calculate_dealer_prob_with(&d, &StandSoft17)),
+      (calculate_dealer_prob_h17(&d), calculate_dealer_prob_with(&d, &HitSoft17)),
+    ];
+    for (generated, recursive) in pairs.iter() {
+      for (a, b) in generated.iter().zip(recursive.iter()) {
+        assert!((a.p_22 - b.p_22).abs() < 1e-12, "{:?} != {:?}", a, b);
+        assert!(a.p_22 <= a.p_bust + 1e-12);
+      }
+    }
+  }
+  // Only a ten after a twelve gets there
+  let p = calculate_dealer_prob_with(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 2], &StandSoft17);
+  assert!((p[1].p_22 - 1.0).abs() < 1e-12);
+}
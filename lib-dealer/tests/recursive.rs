@@ -0,0 +1,229 @@
+mod common;
+
+use common::assert_close;
+use lib_dealer::*;
+
+fn decks() -> Vec<[usize; 10]> {
+  vec![
+    [4, 4, 4, 4, 4, 4, 4, 4, 4, 16],
+    [24, 24, 24, 24, 24, 24, 24, 24, 24, 96],
+    [1, 1, 1, 1, 1, 1, 1, 1, 4, 12],
+    [2, 0, 3, 1, 0, 5, 2, 1, 1, 3],
+    [0, 0, 0, 0, 0, 0, 0, 1, 3, 2],
+    [3, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  ]
+}
+
+// Plays out every order the dealer could draw in, one card at a time and with
+// nothing memoised, as a reference that shares no code with either engine.
+// The deck mustn't run out before the dealer finishes.
+fn brute_force(d: &[usize; 10], stands: &dyn Fn(u32, bool) -> bool) -> [DealerProb; 10] {
+  fn draw(
+    rest: &mut [usize; 10],
+    hard: u32,
+    has_ace: bool,
+    cards: u32,
+    w: f64,
+    stands: &dyn Fn(u32, bool) -> bool,
+    p: &mut DealerProb,
+  ) {
+    if cards >= 2 {
+      let soft = has_ace && hard <= 11;
+      let total = if soft { hard + 10 } else { hard };
+      if total > 21 {
+        p.p_bust += w;
+        if total == 22 {
+          p.p_22 += w;
+        }
+        return;
+      }
+      if cards == 2 && total == 21 {
+        p.p_bj += w;
+        return;
+      }
+      if stands(total, soft) {
+        match total {
+          17 => p.p_17 += w,
+          18 => p.p_18 += w,
+          19 => p.p_19 += w,
+          20 => p.p_20 += w,
+          21 => p.p_21 += w,
+          _ => p.p_low[total as usize] += w,
+        }
+        return;
+      }
+    }
+    let n: usize = rest.iter().sum();
+    for r in 0..10 {
+      if rest[r] == 0 {
+        continue;
+      }
+      let w = w * rest[r] as f64 / n as f64;
+      rest[r] -= 1;
+      draw(rest, hard + r as u32 + 1, has_ace || r == 0, cards + 1, w, stands, p);
+      rest[r] += 1;
+    }
+  }
+
+  let mut ret = [DealerProb::default(); 10];
+  for up in 0..10 {
+    if d[up] == 0 {
+      ret[up].p_bust = 1.0;
+      continue;
+    }
+    let mut rest = *d;
+    rest[up] -= 1;
+    draw(&mut rest, up as u32 + 1, up == 0, 1, 1.0, stands, &mut ret[up]);
+  }
+  ret
+}
+
+fn assert_same(a: &[DealerProb; 10], b: &[DealerProb; 10]) {
+  for (a, b) in a.iter().zip(b.iter()) {
+    assert_close(a, b);
+    assert!((a.p_22 - b.p_22).abs() < 1e-12, "{:?} != {:?}", a, b);
+    for (x, y) in a.p_low.iter().zip(b.p_low.iter()) {
+      assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+    }
+  }
+}
+
+#[test]
+fn matches_brute_force() {
+  let s17 = |total: u32, _soft: bool| total >= 17;
+  let h17 = |total: u32, soft: bool| total > 17 || (total == 17 && !soft);
+  let s16 = |total: u32, _soft: bool| total >= 16;
+  for d in decks().iter().take(3) {
+    let s17_ref = brute_force(d, &s17);
+    assert_same(&calculate_dealer_prob(d), &s17_ref);
+    assert_same(&calculate_dealer_prob_with(d, &StandSoft17), &s17_ref);
+    let h17_ref = brute_force(d, &h17);
+    assert_same(&calculate_dealer_prob_h17(d), &h17_ref);
+    assert_same(&calculate_dealer_prob_with(d, &HitSoft17), &h17_ref);
+    let s16_ref = brute_force(d, &s16);
+    assert_same(&generated::calculate_dealer_prob_s16(d), &s16_ref);
+    assert_same(&calculate_dealer_prob_with(d, &StandOn(16)), &s16_ref);
+  }
+}
+
+#[test]
+fn matches_unrolled() {
+  for d in decks() {
+    let unrolled = calculate_dealer_prob(&d);
+    let recursive = calculate_dealer_prob_with(&d, &StandSoft17);
+    for (a, b) in unrolled.iter().zip(recursive.iter()) {
+      assert_close(a, b);
+    }
+  }
+}
+
+#[test]
+fn closure_rule() {
+  let d = decks()[0];
+  let rule = |total: u32, soft: bool| total > 17 || (total == 17 && !soft);
+  assert_same(&calculate_dealer_prob_with(&d, &rule), &brute_force(&d, &rule));
+}
+
+#[test]
+fn stand_on_16() {
+  let d = decks()[0];
+  for p in calculate_dealer_prob_with(&d, &StandOn(16)).iter() {
+    let total = p.p_17 + p.p_18 + p.p_19 + p.p_20 + p.p_21 + p.p_bust + p.p_bj;
+    assert!((total + p.p_low.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert!(p.p_low[16] > 0.0);
+    assert!(p.p_low[..16].iter().all(|x| *x == 0.0));
+  }
+}
+
+#[test]
+fn known_hole_card() {
+  for d in decks() {
+    let total: usize = d.iter().sum();
+    let unknown = calculate_dealer_prob_with(&d, &StandSoft17);
+    let known: Vec<_> = (0..10)
+      .map(|h| calculate_dealer_prob_with_hole(&d, &StandSoft17, h))
+      .collect();
+    // Averaging over every hole card gets back to not knowing it
+    for up in 0..10 {
+      if d[up] == 0 || total < 2 {
+        continue;
+      }
+      let mut mixed = DealerProb::default();
+      for (h, k) in known.iter().enumerate() {
+        let w = (d[h] - (h == up) as usize) as f64 / (total - 1) as f64;
+        mixed.p_17 += w * k[up].p_17;
+        mixed.p_18 += w * k[up].p_18;
+        mixed.p_19 += w * k[up].p_19;
+        mixed.p_20 += w * k[up].p_20;
+        mixed.p_21 += w * k[up].p_21;
+        mixed.p_bust += w * k[up].p_bust;
+        mixed.p_bj += w * k[up].p_bj;
+      }
+      assert_close(&mixed, &unknown[up]);
+    }
+  }
+  let bj = calculate_dealer_prob_with_hole(&decks()[0], &StandSoft17, 9);
+  assert_eq!(bj[0].p_bj, 1.0);
+}
+
+#[test]
+fn partial_hole_card() {
+  let d = decks()[0];
+  // Ruling out a ten under an ace leaves no blackjack
+  let mut not_ten = [true; 10];
+  not_ten[9] = false;
+  let peeked = calculate_dealer_prob_with_hole_in(&d, &StandSoft17, &not_ten);
+  assert_eq!(peeked[0].p_bj, 0.0);
+  let known = calculate_dealer_prob_with_hole(&d, &StandSoft17, 4);
+  let mut five = [false; 10];
+  five[4] = true;
+  let only_five = calculate_dealer_prob_with_hole_in(&d, &StandSoft17, &five);
+  for (a, b) in known.iter().zip(only_five.iter()) {
+    assert_close(a, b);
+  }
+  // Allowing anything is the same as knowing nothing
+  let any = calculate_dealer_prob_with_hole_in(&d, &StandSoft17, &[true; 10]);
+  for (a, b) in any.iter().zip(calculate_dealer_prob_with(&d, &StandSoft17).iter()) {
+    assert_close(a, b);
+  }
+}
+
+#[test]
+fn known_draws() {
+  for d in decks() {
+    let total: usize = d.iter().sum();
+    let unknown = calculate_dealer_prob_with(&d, &StandSoft17);
+    let none = calculate_dealer_prob_with_draws(&d, &StandSoft17, &[]);
+    for (a, b) in none.iter().zip(unknown.iter()) {
+      assert_close(a, b);
+    }
+    // Averaging over the card after the hole card gets back to not knowing it
+    for up in 0..10 {
+      if d[up] == 0 || total < 3 {
+        continue;
+      }
+      let mut mixed = DealerProb::default();
+      for c in 0..10 {
+        let w = (d[c] - (c == up) as usize) as f64 / (total - 1) as f64;
+        if w == 0.0 {
+          continue;
+        }
+        let mut rest = d;
+        rest[c] -= 1;
+        let k = calculate_dealer_prob_with_draws(&rest, &StandSoft17, &[c])[up];
+        mixed.p_17 += w * k.p_17;
+        mixed.p_18 += w * k.p_18;
+        mixed.p_19 += w * k.p_19;
+        mixed.p_20 += w * k.p_20;
+        mixed.p_21 += w * k.p_21;
+        mixed.p_bust += w * k.p_bust;
+        mixed.p_bj += w * k.p_bj;
+      }
+      assert_close(&mixed, &unknown[up]);
+    }
+  }
+  // A six under a ten that's known to draw a five makes 21
+  let d = [0, 0, 0, 0, 0, 4, 0, 0, 0, 4];
+  let p = calculate_dealer_prob_with_draws(&d, &StandSoft17, &[4]);
+  assert!((p[9].p_21 - 4.0 / 7.0).abs() < 1e-12);
+}
@@ -1,24 +1,134 @@
+use crate::rules::{DealerPolicy, RuleSet};
 use crate::types::{Card, CardMap, Deck};
 
 use num_traits::FromPrimitive;
-use std::collections::HashMap;
+use rustc_hash::FxHashMap;
+use std::sync::{Arc, RwLock};
 
-use lib_dealer::{calculate_dealer_prob as cdp, DealerProb};
+use lib_dealer::{
+  calculate_dealer_prob, calculate_dealer_prob_h17, calculate_dealer_prob_with_draws,
+  calculate_dealer_prob_with_hole_in, DealerProb, HitSoft17, StandSoft17, StoppingRule,
+};
 
-pub struct DealerProbCalculator(HashMap<Deck, CardMap<DealerProb>>);
+// What's known about the dealer's hole card
+#[derive(Clone, Copy)]
+enum Hole {
+  Unknown,
+  // Dealt face up, so not in the decks passed to calculate
+  Exposed(Card),
+  // Still in the decks, but known to be one of the marked ranks
+  Among([bool; 10]),
+}
+
+type Cache = FxHashMap<(Deck, u32), Arc<CardMap<DealerProb>>>;
+
+pub struct DealerProbCalculator {
+  // Keyed by the player's total too when the policy reads it. Shared by
+  // every thread evaluating hands.
+  cache: RwLock<Cache>,
+  cdp: fn(&[usize; 10]) -> [DealerProb; 10],
+  hits_soft_17: bool,
+  policy: Option<Arc<dyn DealerPolicy>>,
+  hole: Hole,
+  // The dealer's first hits, when they're known
+  draws: Vec<usize>,
+}
 
 impl DealerProbCalculator {
-  pub fn new() -> DealerProbCalculator {
-    DealerProbCalculator(HashMap::new())
+  pub fn new(rules: &RuleSet) -> DealerProbCalculator {
+    DealerProbCalculator {
+      cache: RwLock::new(FxHashMap::default()),
+      cdp: if rules.dealer_hits_soft_17 {
+        calculate_dealer_prob_h17
+      } else {
+        calculate_dealer_prob
+      },
+      hits_soft_17: rules.dealer_hits_soft_17,
+      policy: rules.dealer_policy.clone(),
+      hole: Hole::Unknown,
+      draws: Vec::new(),
+    }
+  }
+
+  pub fn with_hole(rules: &RuleSet, hole: Card) -> DealerProbCalculator {
+    DealerProbCalculator {
+      hole: Hole::Exposed(hole),
+      ..DealerProbCalculator::new(rules)
+    }
+  }
+
+  pub fn with_hole_in(rules: &RuleSet, hole: impl Fn(Card) -> bool) -> DealerProbCalculator {
+    let mut holes = [false; 10];
+    for (i, h) in holes.iter_mut().enumerate() {
+      *h = hole(Card::from_usize(i + 1).unwrap());
+    }
+    DealerProbCalculator {
+      hole: Hole::Among(holes),
+      ..DealerProbCalculator::new(rules)
+    }
+  }
+
+  // With the hole card unknown, but the cards the dealer hits with known to
+  // start with `draws`, in order. They aren't in the decks passed to
+  // calculate.
+  pub fn with_draws(rules: &RuleSet, draws: &[Card]) -> DealerProbCalculator {
+    DealerProbCalculator {
+      draws: draws.iter().map(|c| *c as usize - 1).collect(),
+      ..DealerProbCalculator::new(rules)
+    }
   }
 
-  pub fn calculate(&mut self, deck: &Deck) -> &CardMap<DealerProb> {
-    self.0.entry(deck.clone()).or_insert_with(|| {
-      let mut ret = CardMap::new();
-      for (i, p) in cdp(<&[usize; 10]>::from(deck)).iter().enumerate() {
-        ret.set(Card::from_usize(i + 1).unwrap(), *p);
+  // For what doesn't depend on the player's hand, like blackjacks
+  pub fn calculate(&self, deck: &Deck) -> Arc<CardMap<DealerProb>> {
+    self.calculate_against(deck, 0)
+  }
+
+  pub fn calculate_against(&self, deck: &Deck, player_total: u32) -> Arc<CardMap<DealerProb>> {
+    let player_total = match &self.policy {
+      Some(policy) if policy.reads_player() => player_total,
+      _ => 0,
+    };
+    let key = (deck.clone(), player_total);
+    if let Some(probs) = self.cache.read().unwrap().get(&key) {
+      return probs.clone();
+    }
+    // Worked out without holding the lock, so two threads might both do it
+    let probs = Arc::new(self.compute(deck, player_total));
+    self.cache.write().unwrap().entry(key).or_insert(probs).clone()
+  }
+
+  fn compute(&self, deck: &Deck, player_total: u32) -> CardMap<DealerProb> {
+    let policy = &self.policy;
+    let hits_soft_17 = self.hits_soft_17;
+    let stands = |total: u32, soft: bool| match policy {
+      Some(policy) => policy.stands_against(total, soft, player_total),
+      None if hits_soft_17 => HitSoft17.stands(total, soft),
+      None => StandSoft17.stands(total, soft),
+    };
+    let (deck, holes) = match self.hole {
+      Hole::Unknown if self.draws.is_empty() && policy.is_none() => {
+        return to_card_map((self.cdp)(&deck.counts()));
       }
-      ret
-    })
+      Hole::Unknown => {
+        let d = deck.counts();
+        return to_card_map(calculate_dealer_prob_with_draws(&d, &stands, &self.draws));
+      }
+      Hole::Exposed(hole) => {
+        let mut holes = [false; 10];
+        holes[hole as usize - 1] = true;
+        (deck + hole, holes)
+      }
+      Hole::Among(holes) => (deck.clone(), holes),
+    };
+    let d = deck.counts();
+    to_card_map(calculate_dealer_prob_with_hole_in(&d, &stands, &holes))
+  }
+}
+
+fn to_card_map(probs: [DealerProb; 10]) -> CardMap<DealerProb> {
+  let mut ret = CardMap::new();
+  for (i, p) in probs.iter().enumerate() {
+    ret.set(Card::from_usize(i + 1).unwrap(), *p);
   }
-}
\ No newline at end of file
+  ret
+}
@@ -0,0 +1,228 @@
+use crate::types::{Card, Deck, DeckIterator, Hand, HandValue};
+use crate::HandEV;
+
+use num_traits::FromPrimitive;
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
+use std::ops::{Index, Range};
+use std::sync::Arc;
+
+// Every hand of two or more cards that can be drawn from a deck, each stored
+// after all the hands it can draw to, so evaluating them in order only ever
+// reads hands that are already done. The one-card hands come last, as roots to
+// step from, and are never evaluated.
+pub struct HandGraph {
+  shape: Arc<Shape>,
+  // Filled in a level at a time by process
+  evs: Vec<HandEV>,
+}
+
+// Which hands there are and how they connect, which a filter keeping every
+// hand can share
+struct Shape {
+  hands: Vec<(Hand, HandValue)>,
+  // Where hands[i] goes on drawing each rank, unless that busts
+  next: Vec<[Option<usize>; 10]>,
+  // Runs of hands that can't draw to one another, leaving out the roots
+  levels: Vec<Range<usize>>,
+  index: FxHashMap<Hand, usize>,
+}
+
+fn generate_hand(
+  hands: &mut Vec<(Hand, HandValue)>,
+  current_hand: &mut Hand,
+  iter: &DeckIterator,
+) {
+  let mut i = *iter;
+  let mut card: Option<Card> = None;
+  loop {
+    card = match (i.next(), card) {
+      (Some(iter_card), None) => Some(iter_card),
+      (Some(iter_card), Some(prev_card)) if iter_card != prev_card => Some(iter_card),
+      (None, _) => break,
+      _ => continue,
+    };
+    match current_hand.get_hand_value() + card.unwrap() {
+      HandValue::Hard(x) if x > 21 => continue,
+      v => {
+        *current_hand += card.unwrap();
+        hands.push((current_hand.clone(), v));
+        if v != HandValue::Hard(21) {
+          generate_hand(hands, current_hand, &i);
+        };
+        *current_hand -= card.unwrap();
+      }
+    };
+  }
+}
+
+// The most cards hands[i] can still draw without busting
+fn depth(next: &[[Option<usize>; 10]], depths: &mut [Option<usize>], i: usize) -> usize {
+  if let Some(d) = depths[i] {
+    return d;
+  }
+  let d = next[i]
+    .iter()
+    .flatten()
+    .map(|n| depth(next, depths, *n) + 1)
+    .max()
+    .unwrap_or(0);
+  depths[i] = Some(d);
+  d
+}
+
+impl HandGraph {
+  pub fn generate(deck: &Deck) -> HandGraph {
+    let mut hands = Vec::new();
+    generate_hand(&mut hands, &mut Hand::new(), &deck.iter());
+    let index: FxHashMap<Hand, usize> = hands
+      .iter()
+      .enumerate()
+      .map(|(i, (h, _))| (h.clone(), i))
+      .collect();
+    let next: Vec<[Option<usize>; 10]> = hands
+      .iter()
+      .map(|(h, _)| {
+        let mut next = [None; 10];
+        for (c, n) in next.iter_mut().enumerate() {
+          *n = index
+            .get(&(h + Card::from_usize(c + 1).unwrap()))
+            .cloned();
+        }
+        next
+      })
+      .collect();
+
+    // Hands that can draw more cards go later, and everything on the same
+    // depth can be evaluated together
+    let mut depths = vec![None; hands.len()];
+    for i in 0..hands.len() {
+      depth(&next, &mut depths, i);
+    }
+    let mut order: Vec<usize> = (0..hands.len())
+      .filter(|i| hands[*i].0.get_count() >= 2)
+      .collect();
+    order.sort_by_key(|i| depths[*i]);
+    let levels: Vec<_> = order.iter().map(|i| depths[*i].unwrap()).collect();
+    order.extend((0..hands.len()).filter(|i| hands[*i].0.get_count() == 1));
+    let mut hands: Vec<_> = hands.into_iter().map(Some).collect();
+    let hands = order.iter().map(|i| hands[*i].take().unwrap()).collect();
+    HandGraph::build(hands, &order, &next, &levels)
+  }
+
+  // The hands `keep` picks out, with nothing evaluated
+  pub fn filter(&self, keep: impl Fn(&Hand) -> bool) -> HandGraph {
+    let mut order = Vec::new();
+    let mut levels = Vec::new();
+    for (l, level) in self.shape.levels.iter().enumerate() {
+      for i in level.clone() {
+        if keep(&self.shape.hands[i].0) {
+          order.push(i);
+          levels.push(l);
+        }
+      }
+    }
+    let roots = self.shape.levels.last().map_or(0, |l| l.end)..self.shape.hands.len();
+    order.extend(roots.filter(|i| keep(&self.shape.hands[*i].0)));
+    if order.len() == self.shape.hands.len() {
+      return HandGraph {
+        shape: self.shape.clone(),
+        evs: Vec::new(),
+      };
+    }
+    let hands = order.iter().map(|i| self.shape.hands[*i].clone()).collect();
+    HandGraph::build(hands, &order, &self.shape.next, &levels)
+  }
+
+  // hands[k] was at order[k] in whatever `next` indexes, and goes in the
+  // run for levels[k], or is a root past the end of levels
+  fn build(
+    hands: Vec<(Hand, HandValue)>,
+    order: &[usize],
+    next: &[[Option<usize>; 10]],
+    levels: &[usize],
+  ) -> HandGraph {
+    let mut moved = vec![None; next.len()];
+    for (k, i) in order.iter().enumerate() {
+      moved[*i] = Some(k);
+    }
+    let next = order
+      .iter()
+      .map(|i| {
+        let mut n = next[*i];
+        for n in n.iter_mut() {
+          *n = n.and_then(|n| moved[n]);
+        }
+        n
+      })
+      .collect();
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for (k, level) in levels.iter().enumerate() {
+      match runs.last_mut() {
+        Some(run) if levels[run.start] == *level => run.end = k + 1,
+        _ => runs.push(k..k + 1),
+      }
+    }
+    let index = hands
+      .iter()
+      .enumerate()
+      .map(|(k, (h, _))| (h.clone(), k))
+      .collect();
+    HandGraph {
+      shape: Arc::new(Shape {
+        hands,
+        next,
+        levels: runs,
+        index,
+      }),
+      evs: Vec::new(),
+    }
+  }
+
+  pub fn hand(&self, i: usize) -> (&Hand, HandValue) {
+    let (hand, hand_value) = &self.shape.hands[i];
+    (hand, *hand_value)
+  }
+
+  pub fn find(&self, hand: &Hand) -> Option<usize> {
+    self.shape.index.get(hand).cloned()
+  }
+
+  // The one-card hand of `card`, to step from with next
+  pub fn root(&self, card: Card) -> Option<usize> {
+    self.find(&Hand::from([card]))
+  }
+
+  // Where hands[i] goes on drawing `card`
+  pub fn next(&self, i: usize, card: Card) -> Option<usize> {
+    self.shape.next[i][card as usize - 1]
+  }
+
+  // Evaluates every hand, a level at a time so each one can read the hands
+  // it draws to, and the hands within a level in parallel
+  pub fn process<F>(&mut self, evaluate: F)
+  where
+    F: Fn(&HandGraph, usize) -> HandEV + Sync,
+  {
+    let shape = self.shape.clone();
+    self.evs.reserve_exact(shape.hands.len());
+    for level in shape.levels.iter().cloned() {
+      let graph = &*self;
+      let evs: Vec<HandEV> = level.into_par_iter().map(|i| evaluate(graph, i)).collect();
+      self.evs.extend(evs);
+    }
+  }
+
+  pub fn into_map(self) -> HashMap<Hand, HandEV> {
+    self.evs.into_iter().map(|h| (h.hand.clone(), h)).collect()
+  }
+}
+
+impl Index<usize> for HandGraph {
+  type Output = HandEV;
+
+  fn index(&self, i: usize) -> &HandEV {
+    &self.evs[i]
+  }
+}
@@ -1,23 +1,26 @@
-extern crate indexmap;
 extern crate strum_macros;
 
 mod dealer_prob;
+mod hand_graph;
+mod rules;
 mod types;
 
 use lib_dealer::DealerProb;
-use std::cell::RefCell;
-use std::cmp;
-
-use indexmap::map::IndexMap;
 
 use dealer_prob::DealerProbCalculator;
+use hand_graph::HandGraph;
+use rustc_hash::FxHashMap;
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::ops::Deref;
+use std::sync::RwLock;
 
+pub use lib_dealer::{StandOn, StoppingRule};
+pub use rules::{
+    BlackjackLoss, BlackjackTies, DealerPolicy, Doubling, HitToBeat, Peek, ReadsPlayer, RuleSet,
+    Surrender, Ties,
+};
 pub use types::{Card, CardMap, Deck, Hand, HandValue};
 
-use types::DeckIterator;
-
 #[derive(Debug, PartialEq)]
 pub struct HandEV {
     hand: Hand,
@@ -26,137 +29,328 @@ pub struct HandEV {
     pub hit: Option<CardMap<f64>>,
     pub double: Option<CardMap<f64>>,
     pub split: Option<CardMap<f64>>,
+    pub surrender: Option<CardMap<f64>>,
+    pub early_surrender: Option<CardMap<f64>>,
     other_split_ev: Option<CardMap<f64>>,
 }
 
-fn generate_hand(
-    all_hands: &mut IndexMap<Hand, RefCell<HandEV>>,
-    current_hand: &mut Hand,
-    iter: &DeckIterator,
-) {
-    let mut i = *iter;
-    let mut card: Option<Card> = None;
-    loop {
-        card = match (i.next(), card) {
-            (Some(iter_card), None) => Some(iter_card),
-            (Some(iter_card), Some(prev_card)) if iter_card != prev_card => Some(iter_card),
-            (None, _) => break,
-            _ => continue,
-        };
-        match current_hand.get_hand_value() + card.unwrap() {
-            HandValue::Hard(x) if x > 21 => continue,
-            v @ _ => {
-                *current_hand += card.unwrap();
-                if current_hand.get_count() >= 2 {
-                    all_hands.insert(
-                        current_hand.clone(),
-                        RefCell::new(HandEV {
-                            hand: current_hand.clone(),
-                            hand_value: v,
-                            stand: CardMap::new(),
-                            hit: None,
-                            double: None,
-                            split: None,
-                            other_split_ev: None,
-                        }),
-                    );
-                }
-                if v != HandValue::Hard(21) {
-                    generate_hand(all_hands, current_hand, &i);
-                };
-                *current_hand -= card.unwrap();
-            }
-        };
+// How dealer blackjacks are treated in the EVs
+#[derive(Clone, Copy, PartialEq)]
+enum DealerBlackjack {
+    // Counted in full, as seen before the hand is dealt
+    Possible,
+    // Ruled out for up cards the dealer peeks under, as seen mid-hand
+    Checked,
+}
+
+impl DealerBlackjack {
+    fn excluded(self, rules: &RuleSet, up_card: Card) -> bool {
+        match self {
+            DealerBlackjack::Possible => false,
+            DealerBlackjack::Checked => rules.peek.checks(up_card),
+        }
+    }
+
+    // The dealer's chances as seen in this mode, given no blackjack once one
+    // has been ruled out
+    fn dealer_prob<'a>(
+        self,
+        rules: &RuleSet,
+        up_card: Card,
+        p: &'a DealerProb,
+    ) -> Cow<'a, DealerProb> {
+        if self.excluded(rules, up_card) {
+            Cow::Owned(p.without_blackjack())
+        } else {
+            Cow::Borrowed(p)
+        }
     }
 }
 
-fn generate_all_hands(deck: &Deck) -> IndexMap<Hand, RefCell<HandEV>> {
-    let mut ret = IndexMap::new();
-    generate_hand(&mut ret, &mut Hand::new(), &deck.iter());
-    ret
+// Whether losing a hand costs its bet, or it was put up for free and only
+// counts when it wins
+#[derive(Clone, Copy, PartialEq)]
+enum Stake {
+    Paid,
+    Free,
+}
+
+impl Stake {
+    fn lost(self) -> f64 {
+        match self {
+            Stake::Paid => -1.0,
+            Stake::Free => 0.0,
+        }
+    }
+}
+
+// How a hand came about, which decides what it can be paid as
+#[derive(Clone, Copy, PartialEq)]
+enum Origin {
+    Dealt,
+    Split,
+    Doubled,
+    // Made by swapping second cards in Blackjack Switch
+    Switched,
+}
+
+fn is_charlie(rules: &RuleSet, hand: &Hand) -> bool {
+    rules.charlie.is_some_and(|n| hand.get_count() >= n)
+}
+
+// What a winning 21 pays when it qualifies for one of the Spanish 21 bonuses
+fn get_bonus_payout(rules: &RuleSet, hand: &Hand, origin: Origin) -> Option<f64> {
+    if !rules.twenty_one_bonuses || origin == Origin::Doubled {
+        return None;
+    }
+    let six_seven_eight = [Card::Six, Card::Seven, Card::Eight]
+        .iter()
+        .all(|c| hand.get_count_of_card(*c) == 1);
+    match hand.get_count() {
+        3 if six_seven_eight || hand.get_count_of_card(Card::Seven) == 3 => Some(1.5),
+        5 => Some(1.5),
+        6 => Some(2.0),
+        n if n >= 7 => Some(3.0),
+        _ => None,
+    }
 }
 
+// What a natural is worth against a dealer with blackjack probability p_bj
+fn get_natural_ev(rules: &RuleSet, p_bj: f64) -> f64 {
+    let tied = match rules.blackjack_ties {
+        BlackjackTies::Push => 0.0,
+        BlackjackTies::PlayerWins => rules.blackjack_payout * p_bj,
+        BlackjackTies::DealerWins => -p_bj,
+    };
+    rules.blackjack_payout * (1.0 - p_bj) + tied
+}
+
+#[allow(clippy::too_many_arguments)]
 fn get_stand_ev(
-    dealer_calc: &mut DealerProbCalculator,
+    dealer_calc: &DealerProbCalculator,
+    rules: &RuleSet,
     deck: &Deck,
     hand: &Hand,
     hand_value: HandValue,
-    is_split: bool,
-    no_blackjack: bool,
+    origin: Origin,
+    dealer_bj: DealerBlackjack,
+    stake: Stake,
 ) -> CardMap<f64> {
     let mut ev = CardMap::new();
-    for (
-        c,
-        DealerProb {
+    for (c, dealer_prob) in dealer_calc
+        .calculate_against(deck, u32::from(hand_value))
+        .iter()
+    {
+        if deck.get_count_of_card(c) == 0 {
+            continue;
+        }
+
+        let DealerProb {
             p_17,
             p_18,
             p_19,
             p_20,
             p_21,
             p_bust,
+            p_22,
             p_bj,
-        },
-    ) in dealer_calc.calculate(deck).iter()
-    {
-        if deck.get_count_of_card(c) == 0 {
+            ref p_low,
+        } = *dealer_bj.dealer_prob(rules, c, dealer_prob);
+        // Only a dealer policy can stand below 17, which beats lower totals and
+        // loses to higher ones
+        let (low_won, low_tied, low_lost) = if rules.dealer_policy.is_some() {
+            let total = u32::from(hand_value) as usize;
+            (
+                p_low.iter().take(total).sum(),
+                p_low.get(total).copied().unwrap_or(0.0),
+                p_low.iter().skip(total + 1).sum(),
+            )
+        } else {
+            (0.0, 0.0, 0.0)
+        };
+        if origin == Origin::Dealt && hand.is_blackjack() {
+            ev.set(c, get_natural_ev(rules, p_bj));
             continue;
         }
-
-
-        let p_bj = if no_blackjack { 0.0 } else { *p_bj };
-        if !is_split && hand.is_blackjack() {
-            ev.set(c, 1.5 * (1.0 - p_bj));
+        if is_charlie(rules, hand) {
+            let p_low: f64 = p_low.iter().sum();
+            let won = (p_17 + p_18 + p_19 + p_20 + p_21 + p_bust + p_low) * rules.charlie_payout;
+            ev.set(
+                c,
+                match stake {
+                    Stake::Paid => won - p_bj,
+                    Stake::Free => won,
+                },
+            );
             continue;
         }
 
+        let p_bust = if rules.push_on_dealer_22 {
+            p_bust - p_22
+        } else {
+            p_bust
+        };
+        if let HandValue::Hard(21) | HandValue::Soft(21) = hand_value {
+            let bonus = get_bonus_payout(rules, hand, origin);
+            if rules.player_21_wins || bonus.is_some() {
+                let p_21 = if rules.player_21_wins { p_21 } else { 0.0 };
+                let won = (p_17 + p_18 + p_19 + p_20 + p_21 + p_bust + low_won)
+                    * bonus.unwrap_or(1.0);
+                ev.set(
+                    c,
+                    match stake {
+                        Stake::Paid => won - p_bj,
+                        Stake::Free => won,
+                    },
+                );
+                continue;
+            }
+        }
         ev.set(
             c,
-            p_bust - p_bj
-                + match hand_value {
-                    HandValue::Hard(17) | HandValue::Soft(17) => -p_18 - p_19 - p_20 - p_21,
-                    HandValue::Hard(18) | HandValue::Soft(18) => p_17 - p_19 - p_20 - p_21,
-                    HandValue::Hard(19) | HandValue::Soft(19) => p_17 + p_18 - p_20 - p_21,
-                    HandValue::Hard(20) | HandValue::Soft(20) => p_17 + p_18 + p_19 - p_21,
-                    HandValue::Hard(21) | HandValue::Soft(21) => p_17 + p_18 + p_19 + p_20,
-                    _ => -p_17 - p_18 - p_19 - p_20 - p_21,
-                },
+            match stake {
+                Stake::Paid => {
+                    p_bust - p_bj
+                        + match hand_value {
+                            HandValue::Hard(17) | HandValue::Soft(17) => {
+                                -p_18 - p_19 - p_20 - p_21
+                            }
+                            HandValue::Hard(18) | HandValue::Soft(18) => {
+                                p_17 - p_19 - p_20 - p_21
+                            }
+                            HandValue::Hard(19) | HandValue::Soft(19) => {
+                                p_17 + p_18 - p_20 - p_21
+                            }
+                            HandValue::Hard(20) | HandValue::Soft(20) => {
+                                p_17 + p_18 + p_19 - p_21
+                            }
+                            HandValue::Hard(21) | HandValue::Soft(21) => {
+                                p_17 + p_18 + p_19 + p_20
+                            }
+                            _ => -p_17 - p_18 - p_19 - p_20 - p_21,
+                        }
+                        + low_won
+                        - low_lost
+                }
+                Stake::Free => {
+                    p_bust
+                        + match hand_value {
+                            HandValue::Hard(18) | HandValue::Soft(18) => p_17,
+                            HandValue::Hard(19) | HandValue::Soft(19) => p_17 + p_18,
+                            HandValue::Hard(20) | HandValue::Soft(20) => p_17 + p_18 + p_19,
+                            HandValue::Hard(21) | HandValue::Soft(21) => {
+                                p_17 + p_18 + p_19 + p_20
+                            }
+                            _ => 0.0,
+                        }
+                        + low_won
+                }
+            },
         );
+        // A tie the dealer takes loses the bet instead of pushing
+        if let (Stake::Paid, HandValue::Hard(x) | HandValue::Soft(x)) = (stake, hand_value) {
+            if rules.ties.dealer_wins(x) {
+                let p_tie = match x {
+                    17 => p_17,
+                    18 => p_18,
+                    19 => p_19,
+                    20 => p_20,
+                    21 => p_21,
+                    _ => low_tied,
+                };
+                ev.set(c, ev[c].unwrap() - p_tie);
+            }
+        }
     }
 
     ev
 }
 
-fn get_hit_ev(
+fn get_surrender_ev(
+    dealer_calc: &DealerProbCalculator,
+    rules: &RuleSet,
     deck: &Deck,
-    all_hands: &IndexMap<Hand, RefCell<HandEV>>,
     hand: &Hand,
-    hand_value: HandValue,
-    split_ev: Option<&CardMap<f64>>,
-) -> CardMap<f64> {
+    dealer_bj: DealerBlackjack,
+) -> Option<CardMap<f64>> {
+    if rules.surrender != Surrender::Late || hand.get_count() != 2 || hand.is_blackjack() {
+        return None;
+    }
+
     let mut ev = CardMap::new();
+    for (c, dealer_prob) in dealer_calc.calculate(deck).iter() {
+        if deck.get_count_of_card(c) == 0 {
+            continue;
+        }
+        // Late surrender is only offered once the dealer has checked for
+        // blackjack, which still takes the whole bet
+        let p_bj = dealer_bj.dealer_prob(rules, c, dealer_prob).p_bj;
+        ev.set(c, -0.5 * (1.0 - p_bj) - p_bj);
+    }
+    Some(ev)
+}
 
+fn get_early_surrender_ev(rules: &RuleSet, deck: &Deck, hand: &Hand) -> Option<CardMap<f64>> {
+    if rules.surrender != Surrender::Early || hand.get_count() != 2 || hand.is_blackjack() {
+        return None;
+    }
+
+    // Surrendering before the peek loses half the bet whatever the hole card
+    // is, so this is only comparable with EVs that count dealer blackjacks
+    let mut ev = CardMap::new();
     for up_card in deck.rank_iter() {
+        ev.set(up_card, -0.5);
+    }
+    Some(ev)
+}
 
-        if hand_value == HandValue::Hard(21) {
+fn get_hit_ev(
+    rules: &RuleSet,
+    deck: &Deck,
+    all_hands: &HandGraph,
+    index: usize,
+    split_ev: Option<&CardMap<f64>>,
+    stake: Stake,
+) -> CardMap<f64> {
+    let (hand, hand_value) = all_hands.hand(index);
+    let mut ev = CardMap::new();
+    if hand_value == HandValue::Hard(21) || is_charlie(rules, hand) {
+        for up_card in deck.rank_iter() {
             ev.set(up_card, -1.0);
-            continue;
         }
+        return ev;
+    }
+
+    // Where each card goes, looked up once for all the up cards
+    let mut hit_hands = [None; 10];
+    for card in deck.rank_iter() {
+        if let HandValue::Hard(x) = hand_value + card {
+            if x > 21 {
+                continue;
+            }
+        }
+        hit_hands[card as usize - 1] = Some(&all_hands[all_hands.next(index, card).unwrap()]);
+    }
+
+    for up_card in deck.rank_iter() {
         let mut possible_card_count = 0;
-        let new_deck = (deck - up_card).unwrap();
-        for card in new_deck.rank_iter() {
-            let card_count = new_deck.get_count_of_card(card);
-            match hand_value + card {
-                HandValue::Hard(x) if x > 21 => {
+        for card in deck.rank_iter() {
+            let card_count = deck.get_count_of_card(card) - usize::from(card == up_card);
+            if card_count == 0 {
+                continue;
+            }
+            match hit_hands[card as usize - 1] {
+                None => {
                     possible_card_count += card_count;
                     ev.set(
                         up_card,
                         ev[up_card].unwrap_or(0.0)
-                            + (-1.0 + split_ev.map_or(0.0, |o| o[up_card].unwrap_or(0.0)))
+                            + (stake.lost()
+                                + split_ev.map_or(0.0, |o| o[up_card].unwrap_or(0.0)))
                                 * card_count as f64,
                     );
                 }
-                _ => {
-                    let hit_hand = all_hands.get(&(hand + card)).unwrap().borrow();
+                Some(hit_hand) => {
                     possible_card_count += card_count;
                     match (
                         hit_hand.hit.as_ref().unwrap()[up_card],
@@ -187,38 +381,78 @@ fn get_hit_ev(
     ev
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_double_ev(
+    dealer_calc: &DealerProbCalculator,
+    rules: &RuleSet,
     deck: &Deck,
-    all_hands: &IndexMap<Hand, RefCell<HandEV>>,
-    hand: &Hand,
-    hand_value: HandValue,
+    all_hands: &HandGraph,
+    index: usize,
     split_ev: Option<&CardMap<f64>>,
-    no_blackjack: bool,
+    doubling: Doubling,
+    dealer_bj: DealerBlackjack,
+    stake: Stake,
 ) -> Option<CardMap<f64>> {
-    if hand.get_count() != 2 || hand.is_blackjack() {
+    let (hand, hand_value) = all_hands.hand(index);
+    let double_stake = if rules.free_double.allows(hand_value) {
+        Stake::Free
+    } else {
+        Stake::Paid
+    };
+    if hand.get_count() != 2
+        || hand.is_blackjack()
+        || !(doubling.allows(hand_value) || double_stake == Stake::Free)
+    {
         return None;
     };
 
+    // The hand's own stand EVs only do for both bets if they share a stake
+    // and no bonus could have been paid on the undoubled hand
+    let mut double_stand = CardMap::new();
+    if double_stake != stake || rules.twenty_one_bonuses {
+        for card in deck.rank_iter() {
+            let double_hand = hand + card;
+            let double_value = hand_value + card;
+            if let HandValue::Hard(x) = double_value {
+                if x > 21 {
+                    continue;
+                }
+            }
+            let stand = |stake| {
+                get_stand_ev(
+                    dealer_calc,
+                    rules,
+                    &(deck - card).unwrap(),
+                    &double_hand,
+                    double_value,
+                    Origin::Doubled,
+                    dealer_bj,
+                    stake,
+                )
+            };
+            let base = stand(stake);
+            let extra = if double_stake == stake {
+                base.clone()
+            } else {
+                stand(double_stake)
+            };
+            double_stand.set(card, (base, extra));
+        }
+    }
+
     let mut ev = CardMap::new();
+    let dealer_probs = dealer_calc.calculate(deck);
 
     for up_card in deck.rank_iter() {
         let new_deck = (deck - up_card).unwrap();
-        if !no_blackjack {
-            match up_card {
-                Card::Ace => {
-                    ev.set(
-                        Card::Ace,
-                        new_deck.get_count_of_card(Card::Ten) as f64 / new_deck.get_count() as f64,
-                    );
-                }
-                Card::Ten => {
-                    ev.set(
-                        Card::Ten,
-                        new_deck.get_count_of_card(Card::Ace) as f64 / new_deck.get_count() as f64,
-                    );
-                }
-                _ => (),
-            }
+        // The doubled half of the bet comes back on a dealer blackjack unless
+        // it went unchecked and the table takes everything
+        if double_stake == Stake::Paid
+            && !dealer_bj.excluded(rules, up_card)
+            && (rules.peek.checks(up_card)
+                || rules.unchecked_blackjack_loss == BlackjackLoss::OriginalBet)
+        {
+            ev.set(up_card, dealer_probs[up_card].unwrap().p_bj);
         }
 
         for card in new_deck.rank_iter() {
@@ -227,11 +461,35 @@ fn get_double_ev(
                 ev[up_card].unwrap_or(0.0)
                     + match hand_value + card {
                         HandValue::Hard(x) if x > 21 => {
-                            -2.0 + split_ev.map_or(0.0, |o| o[up_card].unwrap_or(0.0))
+                            stake.lost()
+                                + double_stake.lost()
+                                + split_ev.map_or(0.0, |o| o[up_card].unwrap_or(0.0))
                         }
                         _ => {
-                            let hit_hand = all_hands.get(&(hand + card)).unwrap().borrow();
-                            hit_hand.stand[up_card].unwrap() * 2.0
+                            let hit_hand = &all_hands[all_hands.next(index, card).unwrap()];
+                            let stand = hit_hand.stand[up_card].unwrap();
+                            let doubled = match &double_stand[card] {
+                                Some((b, e)) => b[up_card].unwrap() + e[up_card].unwrap(),
+                                None => stand * 2.0,
+                            };
+                            // Rescuing gives up the original bet to get the
+                            // doubled one back, but not from a dealer blackjack
+                            let doubled = if rules.double_rescue {
+                                let p_bj = if dealer_bj.excluded(rules, up_card) {
+                                    0.0
+                                } else {
+                                    dealer_calc.calculate(&(deck - card).unwrap())[up_card]
+                                        .unwrap()
+                                        .p_bj
+                                };
+                                doubled.max(
+                                    stake.lost() * (1.0 - p_bj)
+                                        + (stake.lost() + double_stake.lost()) * p_bj,
+                                )
+                            } else {
+                                doubled
+                            };
+                            doubled
                                 + hit_hand
                                     .other_split_ev
                                     .as_ref()
@@ -239,270 +497,371 @@ fn get_double_ev(
                         }
                     } * (new_deck.get_count_of_card(card) as f64 / new_deck.get_count() as f64),
             )
-
         }
     }
     Some(ev)
 }
 
+#[derive(Clone, Copy)]
+struct Split {
+    pair_card: Card,
+    // Hands still to be played, including the current one
+    hands: usize,
+    // How many more times a pair may be split again
+    resplits: usize,
+    // Whether the hands after this one see the cards it drew. Only the first
+    // level is exact, later hands are approximated by independent copies.
+    exact: bool,
+    // Whether every hand but the original one was put up for free
+    free: bool,
+}
+
+// Past a deck's worth of cards, what the first hand of a split draws barely
+// moves the odds for the hands after it. Rather than a pass over every split
+// hand for each first hand, the copies are played once from the deck at the
+// split.
+const EXACT_SPLIT_CARDS: usize = 52;
+
+// The EV of one inexact copy for each deck and number of resplits left, which
+// the other paths to the same cards reuse. Only for one pair card and dealer
+// blackjack mode.
+type SplitCache = RwLock<FxHashMap<(Deck, usize), CardMap<f64>>>;
+
+fn times_hands(mut ev: CardMap<f64>, hands: usize) -> CardMap<f64> {
+    if hands > 1 {
+        for (_, x) in ev.iter_mut() {
+            *x *= hands as f64;
+        }
+    }
+    ev
+}
 
 fn get_split_ev_inner(
-    dealer_calc: &mut DealerProbCalculator,
+    dealer_calc: &DealerProbCalculator,
+    rules: &RuleSet,
     deck: &Deck,
-    all_hands: &IndexMap<Hand, RefCell<HandEV>>,
-    pair_card: Card,
-    recurse: bool,
-    no_blackjack: bool,
+    all_hands: &HandGraph,
+    split: Split,
+    dealer_bj: DealerBlackjack,
+    cache: &SplitCache,
 ) -> CardMap<f64> {
-
+    // Without resplits every copy is drawn from a different deck
+    let cached = !split.exact && rules.max_split_hands > 2;
+    let key = (deck.clone(), split.resplits);
+    if cached {
+        if let Some(ev) = cache.read().unwrap().get(&key) {
+            return times_hands(ev.clone(), split.hands);
+        }
+    }
+    let pair_card = split.pair_card;
+    let copies = Split {
+        hands: split.hands - 1,
+        exact: false,
+        ..split
+    };
+    let has_copies = split.exact && split.hands > 1;
+    let shared_copies = if has_copies && deck.get_count() > EXACT_SPLIT_CARDS {
+        Some(get_split_ev_inner(
+            dealer_calc,
+            rules,
+            deck,
+            all_hands,
+            copies,
+            dealer_bj,
+            cache,
+        ))
+    } else {
+        None
+    };
+    // The exact level plays the original bet, the copies are the new hands
+    let stake = if split.free && !split.exact {
+        Stake::Free
+    } else {
+        Stake::Paid
+    };
     let mut ev: CardMap<f64> = CardMap::new();
     let deck = &(deck + pair_card);
-    let split_hands: IndexMap<Hand, RefCell<HandEV>> = all_hands
-        .iter()
-        .filter_map(|(h, hev)| {
-            if (deck - h).is_some() && h.get_count_of_card(pair_card) > 0 {
-                Some((
-                    h.clone(),
-                    RefCell::new(HandEV {
-                        hand: h.clone(),
-                        hand_value: hev.borrow().hand_value,
-                        stand: CardMap::default(),
-                        hit: None,
-                        double: None,
-                        split: None,
-                        other_split_ev: None,
-                    }),
-                ))
-            } else {
-                None
-            }
-        })
-        .collect();
+    let mut split_hands =
+        all_hands.filter(|h| (deck - h).is_some() && h.get_count_of_card(pair_card) > 0);
 
-    for hand_ev in split_hands.values() {
-        let stand;
+    split_hands.process(|split_hands, index| {
+        let (hand, hand_value) = split_hands.hand(index);
+        let new_deck = (deck - hand).unwrap();
+        let stand = get_stand_ev(
+            dealer_calc,
+            rules,
+            &new_deck,
+            hand,
+            hand_value,
+            Origin::Split,
+            dealer_bj,
+            stake,
+        );
+
+        let other_split_ev = match &shared_copies {
+            Some(ev) => Some(ev.clone()),
+            None if has_copies => Some(get_split_ev_inner(
+                dealer_calc,
+                rules,
+                &new_deck,
+                split_hands,
+                copies,
+                dealer_bj,
+                cache,
+            )),
+            None => None,
+        };
         let mut hit = None;
         let mut double = None;
-        let mut other_split_ev = None;
-        {
-            let hand_ev = hand_ev.borrow();
-            let HandEV {
-                hand, hand_value, ..
-            } = hand_ev.deref();
-            let new_deck = (deck - &*hand).unwrap();
-            stand = get_stand_ev(
+        if pair_card != Card::Ace {
+            hit = Some(get_hit_ev(
+                rules,
+                &new_deck,
+                split_hands,
+                index,
+                other_split_ev.as_ref(),
+                stake,
+            ));
+            double = get_double_ev(
                 dealer_calc,
+                rules,
                 &new_deck,
-                &hand,
-                *hand_value,
-                true,
-                no_blackjack,
+                split_hands,
+                index,
+                other_split_ev.as_ref(),
+                rules.double_after_split,
+                dealer_bj,
+                stake,
             );
-
-
-            if recurse {
-                other_split_ev = Some(get_split_ev_inner(
-                    dealer_calc,
-                    &new_deck,
-                    &split_hands,
-                    pair_card,
-                    false,
-                    no_blackjack,
-                ));
-            }
-            if pair_card != Card::Ace {
-                hit = Some(get_hit_ev(
-                    &new_deck,
-                    &split_hands,
-                    &hand,
-                    *hand_value,
-                    other_split_ev.as_ref(),
-                ));
-                double = get_double_ev(
-                    &new_deck,
-                    &split_hands,
-                    &hand,
-                    *hand_value,
-                    other_split_ev.as_ref(),
-                    no_blackjack,
-                );
-            }
         }
-        let mut hand_ev = hand_ev.borrow_mut();
-        hand_ev.stand = stand;
-        hand_ev.hit = hit;
-        hand_ev.double = double;
-        hand_ev.other_split_ev = other_split_ev;
-    }
+        HandEV {
+            hand: hand.clone(),
+            hand_value,
+            stand,
+            hit,
+            double,
+            split: None,
+            surrender: None,
+            early_surrender: None,
+            other_split_ev,
+        }
+    });
     let deck = &(deck - pair_card).unwrap();
+
+    // Drawing another pair card starts a new hand instead, which takes every
+    // later hand with it when exact and is just one more copy otherwise
+    let resplit_ev = match deck - pair_card {
+        Some(resplit_deck)
+            if split.resplits > 0 && (pair_card != Card::Ace || rules.resplit_aces) =>
+        {
+            let resplit = Split {
+                hands: if split.exact { split.hands + 1 } else { 2 },
+                resplits: split.resplits - 1,
+                ..split
+            };
+            let mut resplit_ev = get_split_ev_inner(
+                dealer_calc,
+                rules,
+                &resplit_deck,
+                all_hands,
+                resplit,
+                dealer_bj,
+                cache,
+            );
+            refund_split_blackjack(
+                dealer_calc,
+                rules,
+                &resplit_deck,
+                resplit,
+                dealer_bj,
+                &mut resplit_ev,
+            );
+            Some(resplit_ev)
+        }
+        _ => None,
+    };
+
+    let root = split_hands.root(pair_card).unwrap();
     for up_card in deck.rank_iter() {
         let new_deck = (deck - up_card).unwrap();
         for player_card in new_deck.rank_iter() {
-            let hand_ev = split_hands
-                .get(&Hand::from([pair_card, player_card]))
-                .unwrap()
-                .borrow();
-            if hand_ev.stand[up_card] == None {
+            let hand_ev = &split_hands[split_hands.next(root, player_card).unwrap()];
+            if hand_ev.stand[up_card].is_none() {
                 continue;
             }
+            let play_ev = match hand_ev {
+                HandEV {
+                    stand,
+                    hit: Some(h),
+                    double: Some(d),
+                    other_split_ev,
+                    ..
+                } => (stand[up_card].unwrap()
+                    + other_split_ev
+                        .as_ref()
+                        .map_or(0.0, |o| o[up_card].unwrap_or(0.0)))
+                .max(h[up_card].unwrap_or(f64::MIN))
+                .max(d[up_card].unwrap_or(f64::MIN)),
+                HandEV {
+                    stand,
+                    hit: Some(h),
+                    other_split_ev,
+                    ..
+                } => (stand[up_card].unwrap()
+                    + other_split_ev
+                        .as_ref()
+                        .map_or(0.0, |o| o[up_card].unwrap_or(0.0)))
+                .max(h[up_card].unwrap_or(f64::MIN)),
+                HandEV {
+                    stand,
+                    other_split_ev,
+                    ..
+                } => {
+                    stand[up_card].unwrap()
+                        + other_split_ev
+                            .as_ref()
+                            .map_or(0.0, |o| o[up_card].unwrap_or(0.0))
+                }
+            };
+            let play_ev = match &resplit_ev {
+                Some(r) if player_card == pair_card => {
+                    play_ev.max(r[up_card].unwrap_or(f64::MIN))
+                }
+                _ => play_ev,
+            };
             ev.set(
                 up_card,
                 ev[up_card].unwrap_or(0.0)
                     + (new_deck.get_count_of_card(player_card) as f64
                         / new_deck.get_count() as f64)
-                        * match hand_ev.deref() {
-                            HandEV {
-                                stand,
-                                hit: Some(h),
-                                double: Some(d),
-                                other_split_ev,
-                                ..
-                            } => (stand[up_card].unwrap()
-                                + other_split_ev
-                                    .as_ref()
-                                    .map_or(0.0, |o| o[up_card].unwrap_or(0.0)))
-                            .max(h[up_card].unwrap_or(std::f64::MIN))
-                            .max(d[up_card].unwrap_or(std::f64::MIN)),
-                            HandEV {
-                                stand,
-                                hit: Some(h),
-                                other_split_ev,
-                                ..
-                            } => (stand[up_card].unwrap()
-                                + other_split_ev
-                                    .as_ref()
-                                    .map_or(0.0, |o| o[up_card].unwrap_or(0.0)))
-                            .max(h[up_card].unwrap_or(std::f64::MIN)),
-                            HandEV {
-                                stand,
-                                other_split_ev,
-                                ..
-                            } => {
-                                (stand[up_card].unwrap()
-                                    + other_split_ev
-                                        .as_ref()
-                                        .map_or(0.0, |o| o[up_card].unwrap_or(0.0)))
-                            }
-                        },
+                        * play_ev,
             )
         }
     }
-    ev
+    if cached {
+        cache.write().unwrap().insert(key, ev.clone());
+    }
+    if split.exact {
+        ev
+    } else {
+        times_hands(ev, split.hands)
+    }
+}
+
+// Every paid split hand loses its own bet to a dealer blackjack in the EVs, but
+// only the original bet is taken unless the dealer never checked and takes all
+// bets, so hand one back for each paid hand a split adds
+fn refund_split_blackjack(
+    dealer_calc: &DealerProbCalculator,
+    rules: &RuleSet,
+    deck: &Deck,
+    split: Split,
+    dealer_bj: DealerBlackjack,
+    ev: &mut CardMap<f64>,
+) {
+    if split.free {
+        return;
+    }
+    let dealer_probs = dealer_calc.calculate(deck);
+    for (up_card, x) in ev.iter_mut() {
+        if dealer_bj.excluded(rules, up_card)
+            || (!rules.peek.checks(up_card)
+                && rules.unchecked_blackjack_loss == BlackjackLoss::AllBets)
+        {
+            continue;
+        }
+        *x += dealer_probs[up_card].unwrap().p_bj;
+    }
 }
 
 fn get_split_ev(
-    dealer_calc: &mut DealerProbCalculator,
+    dealer_calc: &DealerProbCalculator,
+    rules: &RuleSet,
     deck: &Deck,
-    all_hands: &IndexMap<Hand, RefCell<HandEV>>,
+    all_hands: &HandGraph,
     hand: &Hand,
-    no_blackjack: bool,
+    dealer_bj: DealerBlackjack,
 ) -> Option<CardMap<f64>> {
-
-    let pair_card = hand.iter().nth(0).unwrap();
-    if hand.get_count() != 2 || pair_card != hand.iter().nth(1).unwrap() {
+    let pair_card = hand.iter().next().unwrap();
+    if hand.get_count() != 2
+        || pair_card != hand.iter().nth(1).unwrap()
+        || rules.max_split_hands < 2
+    {
         return None;
     };
-    let mut ev = get_split_ev_inner(dealer_calc, deck, all_hands, pair_card, true, no_blackjack);
-    if !no_blackjack {
-        // Account for dealer blackjack
-        match (
-            deck.get_count_of_card(Card::Ten),
-            deck.get_count_of_card(Card::Ace),
-            deck.get_count(),
-        ) {
-            (t, a, d) if t > 0 && a > 0 => {
-                ev.set(
-                    Card::Ten,
-                    ev[Card::Ten].unwrap() + a as f64 / (d - 1) as f64,
-                );
-                ev.set(
-                    Card::Ace,
-                    ev[Card::Ace].unwrap() + t as f64 / (d - 1) as f64,
-                );
-            }
-            _ => (),
-        }
-
-    }
+    let split = Split {
+        pair_card,
+        hands: 2,
+        resplits: rules.max_split_hands - 2,
+        exact: true,
+        free: rules.free_split && pair_card != Card::Ten,
+    };
+    let cache = RwLock::new(FxHashMap::default());
+    let mut ev = get_split_ev_inner(dealer_calc, rules, deck, all_hands, split, dealer_bj, &cache);
+    refund_split_blackjack(dealer_calc, rules, deck, split, dealer_bj, &mut ev);
 
     Some(ev)
 }
 
-fn _process_hand(
-    dealer_calc: &mut DealerProbCalculator,
-    deck: &Deck,
-    all_hands: &IndexMap<Hand, RefCell<HandEV>>,
-    hand_ev: &RefCell<HandEV>,
+fn process_hands(
+    dealer_calc: &DealerProbCalculator,
+    rules: &RuleSet,
+    starting_deck: &Deck,
+    hands: &mut HandGraph,
+    dealer_bj: DealerBlackjack,
 ) {
-    let stand;
-    let hit;
-    let double;
-    let split;
-    {
-        let hand_ev = hand_ev.borrow();
-        let HandEV {
-            hand, hand_value, ..
-        } = hand_ev.deref();
-
-        let deck = (deck - hand).unwrap();
-        stand = get_stand_ev(dealer_calc, &deck, hand, *hand_value, false, true);
-        hit = get_hit_ev(&deck, all_hands, hand, *hand_value, None);
-        double = get_double_ev(&deck, all_hands, hand, *hand_value, None, true);
-        split = get_split_ev(dealer_calc, &deck, all_hands, hand, true);
-    }
-
-    let mut hand_ev = hand_ev.borrow_mut();
-    hand_ev.stand = stand;
-    hand_ev.hit = Some(hit);
-    hand_ev.double = double;
-    hand_ev.split = split;
-}
-
-pub fn compute_all_hand_ev(starting_deck: &Deck) -> HashMap<Hand, HandEV> {
-    let mut dealer_calc = DealerProbCalculator::new();
-    let mut hands = generate_all_hands(&starting_deck);
-    hands.sort_by(|_, a, _, b| {
-        match (a.borrow().hand_value, b.borrow().hand_value) {
-            // We must process all the soft values before doing any of the hard
-            // values <= 10, because a hard 10 + ace is a soft 21
-            (HandValue::Soft(_), HandValue::Hard(x)) if x <= 10 => cmp::Ordering::Greater,
-            (HandValue::Hard(x), HandValue::Soft(_)) if x <= 10 => cmp::Ordering::Less,
-            (h_a, h_b) => h_a.cmp(&h_b),
-        }
-        .reverse()
-    });
-    for hand in hands.values() {
-        let stand;
-        let hit;
-        let double;
-        let split;
-        {
-            let hand_ev = hand.borrow();
-            let HandEV {
-                hand, hand_value, ..
-            } = hand_ev.deref();
-
-            let deck = &(starting_deck - hand).unwrap();
-            stand = get_stand_ev(&mut dealer_calc, &deck, hand, *hand_value, false, false);
-            hit = get_hit_ev(&deck, &hands, hand, *hand_value, None);
-            double = get_double_ev(&deck, &hands, hand, *hand_value, None, false);
-            split = get_split_ev(&mut dealer_calc, &deck, &hands, hand, false);
+    hands.process(|hands, index| {
+        let (hand, hand_value) = hands.hand(index);
+        let deck = &(starting_deck - hand).unwrap();
+        HandEV {
+            hand: hand.clone(),
+            hand_value,
+            stand: get_stand_ev(
+                dealer_calc,
+                rules,
+                deck,
+                hand,
+                hand_value,
+                Origin::Dealt,
+                dealer_bj,
+                Stake::Paid,
+            ),
+            hit: Some(get_hit_ev(rules, deck, hands, index, None, Stake::Paid)),
+            double: get_double_ev(
+                dealer_calc,
+                rules,
+                deck,
+                hands,
+                index,
+                None,
+                rules.double,
+                dealer_bj,
+                Stake::Paid,
+            ),
+            split: get_split_ev(dealer_calc, rules, deck, hands, hand, dealer_bj),
+            surrender: get_surrender_ev(dealer_calc, rules, deck, hand, dealer_bj),
+            early_surrender: get_early_surrender_ev(rules, deck, hand),
+            other_split_ev: None,
         }
+    });
+}
 
-        let mut hand_ev = hand.borrow_mut();
-        hand_ev.stand = stand;
-        hand_ev.hit = Some(hit);
-        hand_ev.double = double;
-        hand_ev.split = split;
-    }
-    hands
-        .into_iter()
-        .map(|(h, hev)| (h, hev.into_inner()))
-        .collect::<HashMap<Hand, HandEV>>()
+pub fn compute_all_hand_ev(starting_deck: &Deck, rules: &RuleSet) -> HashMap<Hand, HandEV> {
+    let dealer_calc = DealerProbCalculator::new(rules);
+    let mut hands = HandGraph::generate(starting_deck);
+    process_hands(
+        &dealer_calc,
+        rules,
+        starting_deck,
+        &mut hands,
+        DealerBlackjack::Possible,
+    );
+    hands.into_map()
 }
 
 //0.0015485589837292632 for standard deck
 //0.0032576242968898536 if split BJ pays out 3:2
+//-0.0003623624850279811 if dealer hits soft 17
 
 pub fn compute_overall_prob(deck: &Deck, evs: &HashMap<Hand, HandEV>) -> f64 {
     let mut ret = 0.0;
@@ -522,24 +881,29 @@ pub fn compute_overall_prob(deck: &Deck, evs: &HashMap<Hand, HandEV>) -> f64 {
                                     ev.stand[up_card].unwrap(),
                                     ev.hit
                                         .as_ref()
-                                        .map_or(std::f64::MIN, |f| f[up_card].unwrap()),
+                                        .map_or(f64::MIN, |f| f[up_card].unwrap()),
                                     ev.double
                                         .as_ref()
-                                        .map_or(std::f64::MIN, |f| f[up_card].unwrap()),
+                                        .map_or(f64::MIN, |f| f[up_card].unwrap()),
                                     ev.split
                                         .as_ref()
-                                        .map_or(std::f64::MIN, |f| f[up_card].unwrap()),
+                                        .map_or(f64::MIN, |f| f[up_card].unwrap()),
+                                    ev.surrender
+                                        .as_ref()
+                                        .map_or(f64::MIN, |f| f[up_card].unwrap()),
+                                    ev.early_surrender
+                                        .as_ref()
+                                        .map_or(f64::MIN, |f| f[up_card].unwrap()),
                                 ]
                                 .iter()
                                 .cloned()
-                                .fold(std::f64::MIN, f64::max)
+                                .fold(f64::MIN, f64::max)
                         } else {
                             continue;
                         }
                     }
                 }
             }
-
         }
         ret += upcard_ev * deck.get_card_prob(&up_card);
     }
@@ -552,9 +916,34 @@ pub struct SpecificHandEV {
     pub hit: Option<f64>,
     pub double: Option<f64>,
     pub split: Option<f64>,
+    pub surrender: Option<f64>,
+    pub early_surrender: Option<f64>,
+    // Change in EV from taking insurance, or even money on a blackjack,
+    // against an ace before the first card is played
+    pub insurance: Option<f64>,
+    pub even_money: Option<f64>,
     dealer_card: Card,
     current_hand: Hand,
     all_evs: HashMap<Hand, HandEV>,
+    next_cards: Option<NextCards>,
+    early_surrender_deck: Option<EarlySurrenderDeck>,
+}
+
+// What create_with_next_cards was given, so drawing the next known card can
+// carry on from it
+#[derive(Debug)]
+struct NextCards {
+    remaining_deck: Deck,
+    cards: Vec<Card>,
+    rules: RuleSet,
+}
+
+// What create was given when the first decision came before the peek, so
+// drawing a card can rebuild the EVs as seen once the dealer has checked
+#[derive(Debug)]
+struct EarlySurrenderDeck {
+    remaining_deck: Deck,
+    rules: RuleSet,
 }
 
 impl SpecificHandEV {
@@ -570,69 +959,468 @@ impl SpecificHandEV {
         self.split = ev
             .and_then(|x| x.split.as_ref())
             .and_then(|x| x[self.dealer_card]);
+        self.surrender = ev
+            .and_then(|x| x.surrender.as_ref())
+            .and_then(|x| x[self.dealer_card]);
+        self.early_surrender = ev
+            .and_then(|x| x.early_surrender.as_ref())
+            .and_then(|x| x[self.dealer_card]);
     }
 
-    pub fn create(remaining_deck: &Deck, hand: &Hand, dealer_card: Card) -> SpecificHandEV {
-        let mut dealer_calc = DealerProbCalculator::new();
-        let starting_deck = &(remaining_deck + hand) + dealer_card;
-        let mut hands = generate_all_hands(&starting_deck);
-        if hand.get_count() == 2 && hand.iter().nth(0).unwrap() == hand.iter().nth(1).unwrap() {
-            hands.retain(|h, _| Deck::from(&[hand.iter().nth(0).unwrap()]).is_subset(h));
+    pub fn create(
+        remaining_deck: &Deck,
+        hand: &Hand,
+        dealer_card: Card,
+        rules: &RuleSet,
+    ) -> SpecificHandEV {
+        // With early surrender the first decision comes before the dealer
+        // checks for blackjack, so it has to be made on unconditional EVs
+        let dealer_bj = if rules.surrender == Surrender::Early && hand.get_count() == 2 {
+            DealerBlackjack::Possible
         } else {
-            hands.retain(|h, _| hand.is_subset(h));
-        }
-        hands.sort_by(|_, a, _, b| {
-            match (a.borrow().hand_value, b.borrow().hand_value) {
-                // We must process all the soft values before doing any of the hard
-                // values <= 10, because a hard 10 + ace is a soft 21
-                (HandValue::Soft(_), HandValue::Hard(x)) if x <= 10 => cmp::Ordering::Greater,
-                (HandValue::Hard(x), HandValue::Soft(_)) if x <= 10 => cmp::Ordering::Less,
-                (h_a, h_b) => h_a.cmp(&h_b),
-            }
-            .reverse()
-        });
-        for hand in hands.values() {
-            let stand;
-            let hit;
-            let double;
-            let split;
-            {
-                let hand_ev = hand.borrow();
-                let HandEV {
-                    hand, hand_value, ..
-                } = hand_ev.deref();
-
-                let deck = remaining_deck + dealer_card;
-                stand = get_stand_ev(&mut dealer_calc, &deck, hand, *hand_value, false, true);
-                hit = get_hit_ev(&deck, &hands, hand, *hand_value, None);
-                double = get_double_ev(&deck, &hands, hand, *hand_value, None, true);
-                split = get_split_ev(&mut dealer_calc, &deck, &hands, hand, true);
+            DealerBlackjack::Checked
+        };
+        let mut ret = SpecificHandEV::create_with(
+            DealerProbCalculator::new(rules),
+            remaining_deck,
+            hand,
+            dealer_card,
+            rules,
+            dealer_bj,
+        );
+        if dealer_bj == DealerBlackjack::Possible {
+            ret.early_surrender_deck = Some(EarlySurrenderDeck {
+                remaining_deck: remaining_deck.clone(),
+                rules: rules.clone(),
+            });
+        }
+        if dealer_card == Card::Ace && hand.get_count() == 2 {
+            // The hole card comes out of what's left after the player's cards
+            let p_ten = remaining_deck.get_card_prob(&Card::Ten);
+            // Half a bet paying 2:1
+            ret.insurance = Some(0.5 * (2.0 * p_ten - (1.0 - p_ten)));
+            if hand.is_blackjack() {
+                ret.even_money = Some(1.0 - get_natural_ev(rules, p_ten));
             }
+        }
+        ret
+    }
+
+    // Double Exposure, where both dealer cards are dealt face up. The hole
+    // card, dealer_cards[1], must not be in remaining_deck. Use with
+    // RuleSet::double_exposure or similar.
+    pub fn create_double_exposure(
+        remaining_deck: &Deck,
+        hand: &Hand,
+        dealer_cards: [Card; 2],
+        rules: &RuleSet,
+    ) -> SpecificHandEV {
+        // A dealer blackjack is in plain sight, so it's counted like any
+        // other dealer hand
+        SpecificHandEV::create_with(
+            DealerProbCalculator::with_hole(rules, dealer_cards[1]),
+            remaining_deck,
+            hand,
+            dealer_cards[0],
+            rules,
+            DealerBlackjack::Possible,
+        )
+    }
 
-            let mut hand_ev = hand.borrow_mut();
-            hand_ev.stand = stand;
-            hand_ev.hit = Some(hit);
-            hand_ev.double = double;
-            hand_ev.split = split;
+    // With partial knowledge of the hole card, which is still in
+    // remaining_deck, such as it not being a ten or being a 2 to 6 from a
+    // tell. That's all that's assumed about it, so a peek that found nothing
+    // has to be part of it. When only one rank left in the deck fits, the
+    // hole card is as good as seen and taken out of the player's draws.
+    // Otherwise they still come from the whole remaining deck, ignoring the
+    // slight shift knowing about the hole card makes to them.
+    pub fn create_with_hole(
+        remaining_deck: &Deck,
+        hand: &Hand,
+        dealer_card: Card,
+        hole: impl Fn(Card) -> bool,
+        rules: &RuleSet,
+    ) -> SpecificHandEV {
+        let mut fits = remaining_deck.rank_iter().filter(|c| hole(*c));
+        if let (Some(card), None) = (fits.next(), fits.next()) {
+            return SpecificHandEV::create_double_exposure(
+                &(remaining_deck - card).unwrap(),
+                hand,
+                [dealer_card, card],
+                rules,
+            );
         }
+        SpecificHandEV::create_with(
+            DealerProbCalculator::with_hole_in(rules, hole),
+            remaining_deck,
+            hand,
+            dealer_card,
+            rules,
+            DealerBlackjack::Possible,
+        )
+    }
+
+    fn create_with(
+        dealer_calc: DealerProbCalculator,
+        remaining_deck: &Deck,
+        hand: &Hand,
+        dealer_card: Card,
+        rules: &RuleSet,
+        dealer_bj: DealerBlackjack,
+    ) -> SpecificHandEV {
+        let starting_deck = &(remaining_deck + hand) + dealer_card;
+        let first_card = hand.iter().next().unwrap();
+        let mut hands = if hand.get_count() == 2 && first_card == hand.iter().nth(1).unwrap() {
+            HandGraph::generate(&starting_deck).filter(|h| Deck::from([first_card]).is_subset(h))
+        } else {
+            HandGraph::generate(&starting_deck).filter(|h| hand.is_subset(h))
+        };
+        process_hands(&dealer_calc, rules, &starting_deck, &mut hands, dealer_bj);
         let mut ret = SpecificHandEV {
             stand: None,
             hit: None,
             double: None,
             split: None,
+            surrender: None,
+            early_surrender: None,
+            insurance: None,
+            even_money: None,
             dealer_card,
             current_hand: hand.clone(),
-            all_evs: hands
-                .into_iter()
-                .map(|(h, hev)| (h, hev.into_inner()))
-                .collect::<HashMap<Hand, HandEV>>(),
+            all_evs: hands.into_map(),
+            next_cards: None,
+            early_surrender_deck: None,
         };
         ret.update_probs();
         ret
     }
 
+    // With the next cards off the shoe known, in order, such as from tracking
+    // them through the shuffle. remaining_deck still counts next_cards. The
+    // player draws them first, then the dealer hits with whatever is left of
+    // them before going on to the random deck. Stand, hit, double and split
+    // use them, as seen once the dealer has checked for blackjack, with
+    // doubles always paid for and a pair split to two hands at most.
+    pub fn create_with_next_cards(
+        remaining_deck: &Deck,
+        hand: &Hand,
+        dealer_card: Card,
+        next_cards: &[Card],
+        rules: &RuleSet,
+    ) -> SpecificHandEV {
+        let mut rest = remaining_deck.clone();
+        rest.remove_cards(next_cards);
+        let mut ret = SpecificHandEV::create(&rest, hand, dealer_card, rules);
+        if next_cards.is_empty() {
+            return ret;
+        }
+        let mut known = KnownCards::new(rules, &rest, dealer_card, next_cards);
+        ret.stand = Some(known.stand(&rest, hand, 0, Origin::Dealt));
+        if ret.hit.is_some() {
+            ret.hit = Some(known.hit(&mut Vec::new(), hand, Origin::Dealt, 0, 0));
+        }
+        if ret.double.is_some() {
+            ret.double = Some(known.double(&mut Vec::new(), hand, 0, 0));
+        }
+        if ret.split.is_some() {
+            known.pair_card = hand.iter().next();
+            ret.split = Some(known.finish(&mut Vec::new(), 2, 0));
+        }
+        ret.next_cards = Some(NextCards {
+            remaining_deck: remaining_deck.clone(),
+            cards: next_cards.to_vec(),
+            rules: rules.clone(),
+        });
+        ret
+    }
+
+    // Drawing anything but the next known card forgets the known cards
     pub fn add_card_to_hand(&mut self, card: Card) {
+        if let Some(next) = self.next_cards.take() {
+            if next.cards.first() == Some(&card) {
+                *self = SpecificHandEV::create_with_next_cards(
+                    &(&next.remaining_deck - card).unwrap(),
+                    &(&self.current_hand + card),
+                    self.dealer_card,
+                    &next.cards[1..],
+                    &next.rules,
+                );
+                return;
+            }
+        }
+        // Past the first decision the dealer has peeked, which the table
+        // built for early surrender didn't assume
+        if let Some(early) = self.early_surrender_deck.take() {
+            *self = SpecificHandEV::create(
+                &(&early.remaining_deck - card).unwrap(),
+                &(&self.current_hand + card),
+                self.dealer_card,
+                &early.rules,
+            );
+            return;
+        }
         self.current_hand += card;
+        self.insurance = None;
+        self.even_money = None;
         self.update_probs();
     }
 }
+
+// Hands standing until the dealer plays, with how many bets are on them
+type Stood = Vec<(Hand, f64, Origin)>;
+
+// Plays a hand out for create_with_next_cards, taking the known cards in
+// order. Once they run out, what's left is valued on the random deck like
+// compute_all_hand_ev does.
+struct KnownCards<'a> {
+    rules: &'a RuleSet,
+    // The unseen cards other than the known ones
+    rest: Deck,
+    dealer_card: Card,
+    next: &'a [Card],
+    // Indexed by how many known cards the player took, leaving the dealer
+    // to draw the others first
+    dealer_calcs: Vec<DealerProbCalculator>,
+    // Set while playing out a split
+    pair_card: Option<Card>,
+}
+
+impl<'a> KnownCards<'a> {
+    fn new(rules: &'a RuleSet, rest: &Deck, dealer_card: Card, next: &'a [Card]) -> Self {
+        KnownCards {
+            rules,
+            rest: rest.clone(),
+            dealer_card,
+            next,
+            dealer_calcs: (0..=next.len())
+                .map(|j| DealerProbCalculator::with_draws(rules, &next[j..]))
+                .collect(),
+            pair_card: None,
+        }
+    }
+
+    fn stand(&mut self, rest: &Deck, hand: &Hand, j: usize, origin: Origin) -> f64 {
+        get_stand_ev(
+            &self.dealer_calcs[j],
+            self.rules,
+            &(rest + self.dealer_card),
+            hand,
+            hand.get_hand_value(),
+            origin,
+            DealerBlackjack::Checked,
+            Stake::Paid,
+        )[self.dealer_card]
+            .unwrap()
+    }
+
+    fn may_hit(&self, hand: &Hand, origin: Origin) -> bool {
+        let split_aces = origin == Origin::Split && self.pair_card == Some(Card::Ace);
+        !split_aces
+            && !is_charlie(self.rules, hand)
+            && hand.get_hand_value() != HandValue::Hard(21)
+    }
+
+    fn may_double(&self, hand: &Hand, origin: Origin) -> bool {
+        let doubling = match origin {
+            Origin::Split => self.rules.double_after_split,
+            _ => self.rules.double,
+        };
+        hand.get_count() == 2
+            && !hand.is_blackjack()
+            && self.may_hit(hand, origin)
+            && doubling.allows(hand.get_hand_value())
+    }
+
+    // Best EV of playing `hand`, then `pending` more split hands, with `j`
+    // known cards gone. Hands in `done` are counted once the dealer plays.
+    fn play(
+        &mut self,
+        done: &mut Stood,
+        hand: &Hand,
+        origin: Origin,
+        pending: usize,
+        j: usize,
+    ) -> f64 {
+        if j == self.next.len() {
+            let rest = self.rest.clone();
+            return self.finish(done, pending, j) + self.random_ev(&rest, hand, origin);
+        }
+        done.push((hand.clone(), 1.0, origin));
+        let mut ev = self.finish(done, pending, j);
+        done.pop();
+        if self.may_hit(hand, origin) {
+            ev = ev.max(self.hit(done, hand, origin, pending, j));
+        }
+        if self.may_double(hand, origin) {
+            ev = ev.max(self.double(done, hand, pending, j));
+        }
+        ev
+    }
+
+    fn hit(
+        &mut self,
+        done: &mut Stood,
+        hand: &Hand,
+        origin: Origin,
+        pending: usize,
+        j: usize,
+    ) -> f64 {
+        let hand = hand + self.next[j];
+        match hand.get_hand_value() {
+            HandValue::Hard(x) if x > 21 => -1.0 + self.finish(done, pending, j + 1),
+            _ => self.play(done, &hand, origin, pending, j + 1),
+        }
+    }
+
+    fn double(&mut self, done: &mut Stood, hand: &Hand, pending: usize, j: usize) -> f64 {
+        let hand = hand + self.next[j];
+        match hand.get_hand_value() {
+            HandValue::Hard(x) if x > 21 => -2.0 + self.finish(done, pending, j + 1),
+            _ => {
+                done.push((hand, 2.0, Origin::Doubled));
+                let ev = self.finish(done, pending, j + 1);
+                done.pop();
+                ev
+            }
+        }
+    }
+
+    // Deals the next split hand its second card, or settles with the dealer
+    fn finish(&mut self, done: &mut Stood, pending: usize, j: usize) -> f64 {
+        if pending > 0 && j < self.next.len() {
+            let hand = Hand::from([self.pair_card.unwrap(), self.next[j]]);
+            return self.play(done, &hand, Origin::Split, pending - 1, j + 1);
+        }
+        let rest = self.rest.clone();
+        let mut ev = 0.0;
+        for (hand, bets, origin) in done.iter() {
+            ev += bets * self.stand(&rest, hand, j, *origin);
+        }
+        if pending > 0 {
+            ev += pending as f64 * self.random_split_ev();
+        }
+        ev
+    }
+
+    // What `hand` is worth drawing from the random deck `rest`
+    fn random_ev(&mut self, rest: &Deck, hand: &Hand, origin: Origin) -> f64 {
+        let j = self.next.len();
+        let mut ev = self.stand(rest, hand, j, origin);
+        if self.may_hit(hand, origin) {
+            let hit = SpecificHandEV::create_with(
+                DealerProbCalculator::new(self.rules),
+                rest,
+                hand,
+                self.dealer_card,
+                self.rules,
+                DealerBlackjack::Checked,
+            )
+            .hit;
+            ev = ev.max(hit.unwrap_or(f64::MIN));
+        }
+        if self.may_double(hand, origin) {
+            let mut double = 0.0;
+            for card in rest.rank_iter() {
+                let doubled = hand + card;
+                double += rest.get_card_prob(&card)
+                    * match doubled.get_hand_value() {
+                        HandValue::Hard(x) if x > 21 => -2.0,
+                        _ => {
+                            let rest = (rest - card).unwrap();
+                            2.0 * self.stand(&rest, &doubled, j, Origin::Doubled)
+                        }
+                    };
+            }
+            ev = ev.max(double);
+        }
+        ev
+    }
+
+    // A split hand yet to get its second card from the random deck
+    fn random_split_ev(&mut self) -> f64 {
+        let rest = self.rest.clone();
+        let pair_card = self.pair_card.unwrap();
+        let mut ev = 0.0;
+        for card in rest.rank_iter() {
+            let hand = Hand::from([pair_card, card]);
+            ev += rest.get_card_prob(&card)
+                * self.random_ev(&(&rest - card).unwrap(), &hand, Origin::Split);
+        }
+        ev
+    }
+}
+
+// The two ways of playing a pair of Blackjack Switch hands, with EVs on the
+// same footing as SpecificHandEV
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwitchEV {
+    pub keep: f64,
+    pub switch: f64,
+}
+
+impl SwitchEV {
+    pub fn should_switch(&self) -> bool {
+        self.switch > self.keep
+    }
+
+    pub fn ev(&self) -> f64 {
+        self.keep.max(self.switch)
+    }
+}
+
+fn get_switch_hand_ev(
+    remaining_deck: &Deck,
+    hand: &Hand,
+    dealer_card: Card,
+    rules: &RuleSet,
+    switched: bool,
+) -> f64 {
+    if switched && hand.is_blackjack() {
+        // Nothing beats standing on it, and it's only paid as a 21
+        let dealer_calc = DealerProbCalculator::new(rules);
+        return get_stand_ev(
+            &dealer_calc,
+            rules,
+            &(remaining_deck + dealer_card),
+            hand,
+            hand.get_hand_value(),
+            Origin::Switched,
+            DealerBlackjack::Checked,
+            Stake::Paid,
+        )[dealer_card]
+            .unwrap();
+    }
+    let ev = SpecificHandEV::create(remaining_deck, hand, dealer_card, rules);
+    [
+        ev.stand,
+        ev.hit,
+        ev.double,
+        ev.split,
+        ev.surrender,
+        ev.early_surrender,
+    ]
+    .iter()
+    .flatten()
+    .fold(f64::MIN, |a, b| a.max(*b))
+}
+
+// Evaluates keeping or swapping the second cards of two hands, given in the
+// order they were dealt. remaining_deck excludes all four player cards and
+// the dealer's up card. Each hand is played as if the other one had already
+// been dealt, so the cards it draws aren't seen by the other.
+pub fn compute_switch_ev(
+    remaining_deck: &Deck,
+    first: [Card; 2],
+    second: [Card; 2],
+    dealer_card: Card,
+    rules: &RuleSet,
+) -> SwitchEV {
+    let pair_ev = |a: [Card; 2], b: [Card; 2], switched| {
+        get_switch_hand_ev(remaining_deck, &Hand::from(a), dealer_card, rules, switched)
+            + get_switch_hand_ev(remaining_deck, &Hand::from(b), dealer_card, rules, switched)
+    };
+    SwitchEV {
+        keep: pair_ev(first, second, false),
+        switch: pair_ev([first[0], second[1]], [second[0], first[1]], true),
+    }
+}
@@ -1,10 +1,16 @@
-use lib_blackjack::{Card, Deck, Hand, SpecificHandEV};
+use lib_blackjack::{Card, Deck, Hand, RuleSet, SpecificHandEV};
 
 fn main() {
   let mut deck = Deck::generate(1);
   deck.remove_cards(&[Card::Ace, Card::Ace, Card::Ten]);
   println!(
     "{:?}",
-    SpecificHandEV::create(&deck, &Hand::from(&[Card::Ace, Card::Ace]), Card::Ten).split
+    SpecificHandEV::create(
+      &deck,
+      &Hand::from(&[Card::Ace, Card::Ace]),
+      Card::Ten,
+      &RuleSet::default()
+    )
+    .split
   );
 }
\ No newline at end of file
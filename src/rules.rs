@@ -0,0 +1,311 @@
+use crate::types::{Card, HandValue};
+
+use lib_dealer::StoppingRule;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Surrender {
+  None,
+  // Forfeit half the bet on the first two cards, once the dealer has checked
+  // for blackjack
+  Late,
+  // Forfeit half the bet on the first two cards before the dealer checks for
+  // blackjack
+  Early,
+}
+
+// Up cards the dealer checks for blackjack before anyone plays
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Peek {
+  AceAndTen,
+  AceOnly,
+  // European no hole card
+  NoHoleCard,
+}
+
+impl Peek {
+  pub fn checks(self, up_card: Card) -> bool {
+    matches!(
+      (self, up_card),
+      (Peek::AceAndTen, Card::Ace) | (Peek::AceAndTen, Card::Ten) | (Peek::AceOnly, Card::Ace)
+    )
+  }
+}
+
+// What a dealer blackjack that wasn't checked for takes from a player who has
+// doubled or split
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlackjackLoss {
+  // OBO, doubles and splits are refunded
+  OriginalBet,
+  // OBBO, everything on the table is lost
+  AllBets,
+}
+
+// Who takes a hand when the player and dealer finish on the same total. Two
+// blackjacks are settled by BlackjackTies instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ties {
+  Push,
+  DealerWins,
+  // Only ties on a total in the inclusive range go to the dealer
+  DealerWinsBetween { min: u32, max: u32 },
+}
+
+// Who takes it when the player and dealer both have blackjack
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlackjackTies {
+  Push,
+  PlayerWins,
+  DealerWins,
+}
+
+impl Ties {
+  pub fn dealer_wins(self, total: u32) -> bool {
+    match self {
+      Ties::Push => false,
+      Ties::DealerWins => true,
+      Ties::DealerWinsBetween { min, max } => min <= total && total <= max,
+    }
+  }
+}
+
+// Which two-card hands may double down
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Doubling {
+  Never,
+  AnyTwo,
+  // Only hard totals in the inclusive range, e.g. 10 and 11, plus every soft
+  // hand, A2 through A9, if `soft` is set
+  Totals { min: u32, max: u32, soft: bool },
+}
+
+impl Doubling {
+  // Common European restriction
+  pub const NINE_TO_ELEVEN: Doubling = Doubling::Totals {
+    min: 9,
+    max: 11,
+    soft: false,
+  };
+  // Reno style
+  pub const TEN_OR_ELEVEN: Doubling = Doubling::Totals {
+    min: 10,
+    max: 11,
+    soft: false,
+  };
+
+  pub fn allows(self, hand_value: HandValue) -> bool {
+    match (self, hand_value) {
+      (Doubling::Never, _) => false,
+      (Doubling::AnyTwo, _) => true,
+      (Doubling::Totals { min, max, .. }, HandValue::Hard(x)) => min <= x && x <= max,
+      (Doubling::Totals { soft, .. }, HandValue::Soft(_)) => soft,
+    }
+  }
+}
+
+// How the dealer plays out their hand, for dealers that don't just stand on
+// 17 or hit soft 17
+pub trait DealerPolicy: Send + Sync {
+  // Whether a dealer holding at least two cards stops drawing, when settling
+  // a player hand on player_total
+  fn stands_against(&self, total: u32, soft: bool, player_total: u32) -> bool;
+
+  // Policies that look at player_total have the dealer's outcomes worked out
+  // for every player total, rather than once per deck
+  fn reads_player(&self) -> bool {
+    false
+  }
+}
+
+// Any lib_dealer stopping rule, closures on the dealer's total and softness
+// included, plays the same whatever the player holds
+impl<R> DealerPolicy for R
+where
+  R: StoppingRule + Send + Sync,
+{
+  fn stands_against(&self, total: u32, soft: bool, _player_total: u32) -> bool {
+    self.stands(total, soft)
+  }
+}
+
+// Keep drawing until ahead of the player, or on 21. With several hands the
+// dealer is taken to be playing against each one alone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HitToBeat;
+
+impl DealerPolicy for HitToBeat {
+  fn stands_against(&self, total: u32, _soft: bool, player_total: u32) -> bool {
+    total > player_total || total >= 21
+  }
+
+  fn reads_player(&self) -> bool {
+    true
+  }
+}
+
+// A closure taking the dealer's total, whether it's soft and the player's
+// total, for policies that play differently depending on the player's hand
+pub struct ReadsPlayer<F>(pub F);
+
+impl<F> DealerPolicy for ReadsPlayer<F>
+where
+  F: Fn(u32, bool, u32) -> bool + Send + Sync,
+{
+  fn stands_against(&self, total: u32, soft: bool, player_total: u32) -> bool {
+    (self.0)(total, soft, player_total)
+  }
+
+  fn reads_player(&self) -> bool {
+    true
+  }
+}
+
+impl fmt::Debug for dyn DealerPolicy {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("DealerPolicy")
+  }
+}
+
+// Policies can't be looked inside, so each is only equal to itself
+impl PartialEq for dyn DealerPolicy {
+  fn eq(&self, other: &Self) -> bool {
+    std::ptr::eq(self as *const Self as *const u8, other as *const Self as *const u8)
+  }
+}
+
+// Table rules consulted by the EV engine. The default reproduces the game the
+// engine was originally written for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleSet {
+  // Dealer draws to a soft 17 instead of standing on all 17s
+  pub dealer_hits_soft_17: bool,
+  // Plays the dealer's hand instead of dealer_hits_soft_17
+  pub dealer_policy: Option<Arc<dyn DealerPolicy>>,
+  // Winnings per unit bet on a natural, e.g. 1.5 for 3:2 or 1.2 for 6:5
+  pub blackjack_payout: f64,
+  pub peek: Peek,
+  pub unchecked_blackjack_loss: BlackjackLoss,
+  pub double: Doubling,
+  pub surrender: Surrender,
+  // Most hands a player can hold by splitting and resplitting a pair
+  pub max_split_hands: usize,
+  pub resplit_aces: bool,
+  pub double_after_split: Doubling,
+  pub ties: Ties,
+  pub blackjack_ties: BlackjackTies,
+  // A hand reaching this many cards without busting wins outright, paying
+  // charlie_payout
+  pub charlie: Option<usize>,
+  pub charlie_payout: f64,
+  // A dealer finishing on 22 pushes every hand except a player blackjack
+  pub push_on_dealer_22: bool,
+  // Hands that may double without putting up the extra bet, which then only
+  // counts if it wins
+  pub free_double: Doubling,
+  // Every pair but tens may be split, and resplit, with the new hands' bets
+  // put up for free
+  pub free_split: bool,
+  // A player 21 beats a dealer 21, though not a dealer blackjack
+  pub player_21_wins: bool,
+  // Spanish 21 bonuses, paid at their mixed suit rates as suits aren't
+  // tracked: 3:2 for 6-7-8, 7-7-7 and five card 21s, 2:1 for six cards and
+  // 3:1 for seven or more. Doubled hands are paid at evens.
+  pub twenty_one_bonuses: bool,
+  // A doubled hand may be surrendered for the original bet after its card
+  pub double_rescue: bool,
+}
+
+impl Default for RuleSet {
+  fn default() -> Self {
+    RuleSet {
+      dealer_hits_soft_17: false,
+      dealer_policy: None,
+      blackjack_payout: 1.5,
+      peek: Peek::AceAndTen,
+      unchecked_blackjack_loss: BlackjackLoss::AllBets,
+      double: Doubling::AnyTwo,
+      surrender: Surrender::None,
+      max_split_hands: 2,
+      resplit_aces: false,
+      double_after_split: Doubling::AnyTwo,
+      ties: Ties::Push,
+      blackjack_ties: BlackjackTies::Push,
+      charlie: None,
+      charlie_payout: 1.0,
+      push_on_dealer_22: false,
+      free_double: Doubling::Never,
+      free_split: false,
+      player_21_wins: false,
+      twenty_one_bonuses: false,
+      double_rescue: false,
+    }
+  }
+}
+
+impl RuleSet {
+  // Free Bet blackjack as it's usually dealt
+  pub fn free_bet() -> RuleSet {
+    RuleSet {
+      dealer_hits_soft_17: true,
+      max_split_hands: 4,
+      push_on_dealer_22: true,
+      free_double: Doubling::NINE_TO_ELEVEN,
+      free_split: true,
+      ..RuleSet::default()
+    }
+  }
+
+  // Pontoon, where the dealer wins ties and five card tricks pay 2:1. The
+  // rule forcing players to hit below 15 isn't modelled.
+  pub fn pontoon() -> RuleSet {
+    RuleSet {
+      dealer_hits_soft_17: true,
+      blackjack_payout: 2.0,
+      ties: Ties::DealerWins,
+      blackjack_ties: BlackjackTies::DealerWins,
+      charlie: Some(5),
+      charlie_payout: 2.0,
+      ..RuleSet::default()
+    }
+  }
+
+  // Blackjack Switch, where swapping cards between two hands is paid for by
+  // naturals paying evens and a dealer 22 pushing. See compute_switch_ev.
+  pub fn blackjack_switch() -> RuleSet {
+    RuleSet {
+      dealer_hits_soft_17: true,
+      blackjack_payout: 1.0,
+      push_on_dealer_22: true,
+      ..RuleSet::default()
+    }
+  }
+
+  // Double Exposure, which makes up for both dealer cards being dealt face up
+  // with naturals paying evens and the dealer winning ties. See
+  // SpecificHandEV::create_double_exposure.
+  pub fn double_exposure() -> RuleSet {
+    RuleSet {
+      blackjack_payout: 1.0,
+      ties: Ties::DealerWins,
+      ..RuleSet::default()
+    }
+  }
+
+  // Spanish 21 as it's usually dealt, to be played with Deck::generate_spanish.
+  // Doubling on more than two cards isn't modelled.
+  pub fn spanish_21() -> RuleSet {
+    RuleSet {
+      dealer_hits_soft_17: true,
+      surrender: Surrender::Late,
+      max_split_hands: 4,
+      resplit_aces: true,
+      blackjack_ties: BlackjackTies::PlayerWins,
+      player_21_wins: true,
+      twenty_one_bonuses: true,
+      double_rescue: true,
+      ..RuleSet::default()
+    }
+  }
+}
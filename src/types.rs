@@ -22,65 +22,119 @@ pub enum Card {
   Ten,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+// Ten bits of count for each rank, lowest rank first, and the total in the
+// bits above. Small enough to hash and copy as a single word.
+#[derive(Clone, Default, Hash, PartialEq, Eq)]
 pub struct Deck {
-  cards: [usize; 10],
-  card_count: usize,
+  cards: u128,
 }
 
+const RANK_BITS: usize = 10;
+const RANK_MAX: usize = (1 << RANK_BITS) - 1;
+const COUNT_SHIFT: usize = RANK_BITS * 10;
+// The highest bit of every rank's count
+const RANK_TOPS: u128 = {
+  let mut tops = 0;
+  let mut rank = 0;
+  while rank < 10 {
+    tops |= 1 << (rank * RANK_BITS + RANK_BITS - 1);
+    rank += 1;
+  }
+  tops
+};
+
 #[derive(Clone, Copy)]
 pub struct DeckIterator<'a>(&'a Deck, usize, usize);
 
 pub struct RankIterator<'a>(&'a Deck, usize);
 
+fn rank_bit(rank: usize) -> u128 {
+  1 << (rank * RANK_BITS)
+}
+
+// Whether some rank of a - b would have to borrow from the one above
+fn borrows(a: u128, b: u128) -> bool {
+  let d = a.wrapping_sub(b);
+  ((!a & b) | (!(a ^ b) & d)) & RANK_TOPS != 0
+}
+
+// Whether some rank of a + b would carry into the one above
+fn carries(a: u128, b: u128) -> bool {
+  let s = a.wrapping_add(b);
+  ((a & b) | ((a | b) & !s)) & RANK_TOPS != 0
+}
+
 impl Deck {
   pub fn new() -> Self {
-    Deck {
-      cards: [0; 10],
-      card_count: 0,
-    }
+    Deck { cards: 0 }
   }
 
   pub fn generate(deck_count: usize) -> Self {
     let mut cards = [deck_count * 4; 10];
     cards[9] *= 4;
-    Self {
-      cards,
-      card_count: deck_count * 52,
-    }
+    Self::from_counts(&cards)
   }
 
-  pub fn iter<'a>(&'a self) -> DeckIterator<'a> {
+  // Spanish decks, which have their four ten spots removed
+  pub fn generate_spanish(deck_count: usize) -> Self {
+    let mut cards = [deck_count * 4; 10];
+    cards[9] *= 3;
+    Self::from_counts(&cards)
+  }
+
+  pub fn iter(&self) -> DeckIterator<'_> {
     DeckIterator(self, 0, 0)
   }
 
-  pub fn rank_iter<'a>(&'a self) -> RankIterator<'a> {
+  pub fn rank_iter(&self) -> RankIterator<'_> {
     RankIterator(self, 0)
   }
 
+  fn from_counts(cards: &[usize; 10]) -> Self {
+    let mut ret = Deck::new();
+    for (i, c) in cards.iter().enumerate() {
+      if *c > RANK_MAX {
+        panic!("Deck overflow");
+      }
+      ret.cards += (*c as u128) * (rank_bit(i) + rank_bit(10));
+    }
+    ret
+  }
+
+  fn rank_count(&self, rank: usize) -> usize {
+    (self.cards >> (rank * RANK_BITS)) as usize & RANK_MAX
+  }
+
+  // How many of each rank are left, aces first
+  pub fn counts(&self) -> [usize; 10] {
+    let mut cards = [0; 10];
+    for (i, c) in cards.iter_mut().enumerate() {
+      *c = self.rank_count(i);
+    }
+    cards
+  }
+
   pub fn get_count_of_card(&self, card: Card) -> usize {
-    self.cards[card as usize - 1]
+    self.rank_count(card as usize - 1)
   }
 
   pub fn get_count(&self) -> usize {
-    self.card_count
+    (self.cards >> COUNT_SHIFT) as usize
   }
 
   pub fn is_subset(&self, other: &Self) -> bool {
-    self.card_count <= other.card_count && self.cards.iter().zip(&other.cards).all(|(s, o)| o >= s)
+    !borrows(other.cards, self.cards)
   }
 
   pub fn remove_cards(&mut self, cards: &[Card]) {
     for card in cards {
-      self.cards[*card as usize - 1] -= 1;
-      self.card_count -= 1;
+      *self -= *card;
     }
   }
 
   pub fn add_cards(&mut self, cards: &[Card]) {
     for card in cards {
-      self.cards[*card as usize - 1] += 1;
-      self.card_count += 1;
+      *self += *card;
     }
   }
 
@@ -93,41 +147,41 @@ impl Deck {
   }
 
   pub fn get_card_prob(&self, card: &Card) -> f64 {
-    self.cards[*card as usize - 1] as f64 / self.card_count as f64
+    self.get_count_of_card(*card) as f64 / self.get_count() as f64
+  }
+}
+
+impl fmt::Debug for Deck {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Deck")
+      .field("cards", &self.counts())
+      .field("card_count", &self.get_count())
+      .finish()
   }
 }
 
 impl ops::Sub for &Deck {
   type Output = Option<Deck>;
   fn sub(self, rhs: Self) -> Self::Output {
-    if self.card_count < rhs.card_count {
+    if !rhs.is_subset(self) {
       return None;
     }
-
-    let mut ret = Deck {
-      cards: [0; 10],
-      card_count: self.card_count - rhs.card_count,
-    };
-    for i in 0..self.cards.len() {
-      if self.cards[i] < rhs.cards[i] {
-        return None;
-      }
-      ret.cards[i] = self.cards[i] - rhs.cards[i];
-    }
-    Some(ret)
+    // No rank borrows from the one above, so the total comes out right too
+    Some(Deck {
+      cards: self.cards - rhs.cards,
+    })
   }
 }
 
 impl ops::Sub<Card> for &Deck {
   type Output = Option<Deck>;
   fn sub(self, rhs: Card) -> Self::Output {
-    if self.cards[rhs as usize - 1] == 0 {
+    if self.get_count_of_card(rhs) == 0 {
       return None;
     }
 
     let mut ret = self.clone();
-    ret.card_count -= 1;
-    ret.cards[rhs as usize - 1] -= 1;
+    ret -= rhs;
     Some(ret)
   }
 }
@@ -135,10 +189,8 @@ impl ops::Sub<Card> for &Deck {
 impl ops::Add<Card> for &Deck {
   type Output = Deck;
   fn add(self, rhs: Card) -> Self::Output {
-
     let mut ret = self.clone();
-    ret.card_count += 1;
-    ret.cards[rhs as usize - 1] += 1;
+    ret += rhs;
     ret
   }
 }
@@ -146,36 +198,40 @@ impl ops::Add<Card> for &Deck {
 impl ops::Add for &Deck {
   type Output = Deck;
   fn add(self, rhs: &Deck) -> Self::Output {
-
-    let mut ret = self.clone();
-    ret.card_count += rhs.card_count;
-    
-    for i in 0..self.cards.len() {
-      ret.cards[i] = self.cards[i] + rhs.cards[i];
+    if carries(self.cards, rhs.cards) {
+      panic!("Deck overflow");
+    }
+    Deck {
+      cards: self.cards + rhs.cards,
     }
-    ret
   }
 }
 
 impl ops::AddAssign<Card> for Deck {
   fn add_assign(&mut self, rhs: Card) {
-    self.cards[rhs as usize - 1] += 1;
-    self.card_count += 1;
+    let rank = rhs as usize - 1;
+    if self.rank_count(rank) == RANK_MAX {
+      panic!("Deck overflow");
+    }
+    self.cards += rank_bit(rank) + rank_bit(10);
   }
 }
 
 impl ops::SubAssign<Card> for Deck {
   fn sub_assign(&mut self, rhs: Card) {
-    self.cards[rhs as usize - 1] = self.cards[rhs as usize - 1]
-      .checked_sub(1)
-      .expect("Deck underflow");
-    self.card_count = self.card_count.checked_sub(1).expect("Deck underflow");
+    let rank = rhs as usize - 1;
+    if self.rank_count(rank) == 0 {
+      panic!("Deck underflow");
+    }
+    self.cards -= rank_bit(rank) + rank_bit(10);
   }
 }
 
-impl<'a> From<&'a Deck> for &'a [usize; 10] {
-  fn from(deck: &Deck) -> &[usize; 10] {
-    &deck.cards
+// The counts aren't stored as an array any more, so this hands back a copy
+// where it used to lend out a &[usize; 10]
+impl From<&Deck> for [usize; 10] {
+  fn from(deck: &Deck) -> [usize; 10] {
+    deck.counts()
   }
 }
 
@@ -194,9 +250,9 @@ impl<'a> Iterator for RankIterator<'a> {
   type Item = Card;
 
   fn next(&mut self) -> Option<Self::Item> {
-    let Self(Deck { cards, .. }, rank) = self;
+    let Self(deck, rank) = self;
 
-    while *rank < cards.len() && cards[*rank] == 0 {
+    while *rank < 10 && deck.rank_count(*rank) == 0 {
       *rank += 1;
     }
 
@@ -210,20 +266,20 @@ impl<'a> Iterator for DeckIterator<'a> {
   type Item = Card;
 
   fn next(&mut self) -> Option<Self::Item> {
-    let Self(Deck { cards, .. }, rank, count) = self;
+    let Self(deck, rank, count) = self;
 
-    while *rank < cards.len() && cards[*rank] == 0 {
+    while *rank < 10 && deck.rank_count(*rank) == 0 {
       *rank += 1;
       *count = 0;
     }
 
-    if (*rank) >= cards.len() {
+    if (*rank) >= 10 {
       return None;
     }
 
     let ret = Card::from_usize(*rank + 1).unwrap();
     *count += 1;
-    if *count == cards[*rank] {
+    if *count == deck.rank_count(*rank) {
       *rank += 1;
       *count = 0;
     }
@@ -315,11 +371,7 @@ pub type Hand = Deck;
 
 impl Hand {
   pub fn is_blackjack(&self) -> bool {
-    *self
-      == Hand {
-        cards: [1, 0, 0, 0, 0, 0, 0, 0, 0, 1],
-        card_count: 2,
-      }
+    *self == Hand::from([Card::Ace, Card::Ten])
   }
 }
 
@@ -339,24 +391,20 @@ impl<T> CardMap<T> {
     self.array[card as usize - 1] = Some(val)
   }
 
-  pub fn iter<'a>(&'a self) -> impl Iterator<Item = (Card, &'a T)> {
-    self.array.iter().enumerate().filter_map(|(i, x)| {
-      if let Some(y) = x {
-        Some((Card::from_usize(i + 1).unwrap(), y))
-      } else {
-        None
-      }
-    })
+  pub fn iter(&self) -> impl Iterator<Item = (Card, &T)> {
+    self
+      .array
+      .iter()
+      .enumerate()
+      .filter_map(|(i, x)| x.as_ref().map(|y| (Card::from_usize(i + 1).unwrap(), y)))
   }
 
-  pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (Card, &'a mut T)> {
-    self.array.iter_mut().enumerate().filter_map(|(i, x)| {
-      if let Some(y) = x {
-        Some((Card::from_usize(i + 1).unwrap(), y))
-      } else {
-        None
-      }
-    })
+  pub fn iter_mut(&mut self) -> impl Iterator<Item = (Card, &mut T)> {
+    self
+      .array
+      .iter_mut()
+      .enumerate()
+      .filter_map(|(i, x)| x.as_mut().map(|y| (Card::from_usize(i + 1).unwrap(), y)))
   }
 }
 
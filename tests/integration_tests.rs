@@ -1,4 +1,5 @@
 use lib_blackjack::*;
+use std::sync::Arc;
 
 fn create_standard_deck() -> Deck {
   Deck::from([
@@ -29,45 +30,54 @@ fn create_standard_deck() -> Deck {
   ])
 }
 
+// What's left of a deck once the given cards are dealt from it
+fn remaining(deck: &Deck, cards: &[Card]) -> Deck {
+  let mut deck = deck.clone();
+  deck.remove_cards(cards);
+  deck
+}
+
+// Recorded from the generated dealer functions, which differ from the
+// unrolled one these were first taken from only by rounding
 #[test]
 fn standard() {
-  let ev = compute_all_hand_ev(&create_standard_deck());
+  let ev = compute_all_hand_ev(&create_standard_deck(), &RuleSet::default());
   let four_six = ev.get(&Hand::from([Card::Four, Card::Six])).unwrap();
   let mut stand = CardMap::new();
   stand.set(Card::Ace, -0.7835282651072124);
-  stand.set(Card::Two, -0.03219159008632694);
-  stand.set(Card::Three, 0.40888331940963524);
+  stand.set(Card::Two, -0.03219159008632677);
+  stand.set(Card::Three, 0.4088833194096353);
   stand.set(Card::Five, 0.6329156223893067);
   stand.set(Card::Seven, -0.5289612921191869);
-  stand.set(Card::Eight, -0.8101503759398495);
+  stand.set(Card::Eight, -0.8101503759398494);
   stand.set(Card::Nine, -0.7619604566972985);
   stand.set(Card::Ten, -0.7737399053188528);
   assert_eq!(four_six.stand, stand);
   let mut hit = CardMap::new();
   hit.set(Card::Ace, -0.48962848297213635);
-  hit.set(Card::Two, 0.33716562648451204);
+  hit.set(Card::Two, 0.3371656264845119);
   hit.set(Card::Three, 0.6647902435828132);
   hit.set(Card::Five, 0.9166396383114649);
   hit.set(Card::Seven, 0.6908848919684832);
-  hit.set(Card::Eight, 0.5671630055530985);
-  hit.set(Card::Nine, 0.3955726735138499);
-  hit.set(Card::Ten, -0.003441201041820277);
+  hit.set(Card::Eight, 0.5671630055530984);
+  hit.set(Card::Nine, 0.39557267351385);
+  hit.set(Card::Ten, -0.0034412010418203194);
   assert_eq!(*four_six.hit.as_ref().unwrap(), hit);
   let mut double = CardMap::new();
-  double.set(Card::Ace, -0.41047553524333746);
-  double.set(Card::Two, 0.6743312529690241);
+  double.set(Card::Ace, -0.41047553524333724);
+  double.set(Card::Two, 0.6743312529690239);
   double.set(Card::Three, 1.3295804871656265);
   double.set(Card::Five, 1.8332792766229298);
-  double.set(Card::Seven, 1.37245237276197);
-  double.set(Card::Eight, 1.1029223385260538);
-  double.set(Card::Nine, 0.7633782167837894);
+  double.set(Card::Seven, 1.3724523727619702);
+  double.set(Card::Eight, 1.1029223385260536);
+  double.set(Card::Nine, 0.7633782167837895);
   double.set(Card::Ten, 0.008256752993595018);
   assert_eq!(*four_six.double.as_ref().unwrap(), double);
 }
 
 #[test]
 fn split() {
-  let ev = compute_all_hand_ev(&create_standard_deck());
+  let ev = compute_all_hand_ev(&create_standard_deck(), &RuleSet::default());
   let nine_nine = ev.get(&Hand::from([Card::Nine, Card::Nine])).unwrap();
   println!("{:?}", nine_nine);
   assert_eq!(
@@ -76,16 +86,32 @@ fn split() {
   );
 }
 
+#[test]
+fn split_dealer_blackjack() {
+  let deck = Deck::generate(1);
+  let ev = compute_all_hand_ev(&deck, &RuleSet::default());
+  // A dealer blackjack only takes the original bet from a split pair
+  let eight_eight = ev.get(&Hand::from([Card::Eight, Card::Eight])).unwrap();
+  let split = eight_eight.split.as_ref().unwrap();
+  assert_eq!(split[Card::Ace], Some(-0.552503518124263));
+  assert_eq!(split[Card::Six], Some(0.3412356272625562));
+  assert_eq!(split[Card::Ten], Some(-0.49709241745910726));
+  assert_eq!(compute_overall_prob(&deck, &ev), 0.0015485589837292701);
+}
+
 #[test]
 fn small_deck() {
-  let mut ev = compute_all_hand_ev(&Deck::from([
-    Card::Eight,
-    Card::Nine,
-    Card::Nine,
-    Card::Nine,
-    Card::Ten,
-    Card::Ten,
-  ]));
+  let mut ev = compute_all_hand_ev(
+    &Deck::from([
+      Card::Eight,
+      Card::Nine,
+      Card::Nine,
+      Card::Nine,
+      Card::Ten,
+      Card::Ten,
+    ]),
+    &RuleSet::default(),
+  );
   let mut split_compare = CardMap::new();
   split_compare.set(Card::Eight, 1.333);
   split_compare.set(Card::Nine, 0.0);
@@ -100,4 +126,819 @@ fn small_deck() {
     *ev = (*ev * 1000.0).round() / 1000.0;
   }
   assert_eq!(*split, split_compare);
-}
\ No newline at end of file
+}
+
+// Every hand SpecificHandEV looks ahead to is played from what its own cards
+// leave, just like in the full table
+#[test]
+fn specific_hand_decks() {
+  let deck = create_standard_deck();
+  let table = compute_all_hand_ev(&deck, &RuleSet::default());
+  let five = Hand::from([Card::Two, Card::Three]);
+  let remaining = (&(&deck - &five).unwrap() - Card::Six).unwrap();
+  let mut specific = SpecificHandEV::create(&remaining, &five, Card::Six, &RuleSet::default());
+  let close = |a: Option<f64>, b: Option<f64>| (a.unwrap() - b.unwrap()).abs() < 1e-12;
+  let evs = table.get(&five).unwrap();
+  assert!(close(specific.stand, evs.stand[Card::Six]));
+  assert!(close(specific.hit, evs.hit.as_ref().unwrap()[Card::Six]));
+  assert!(close(specific.double, evs.double.as_ref().unwrap()[Card::Six]));
+
+  specific.add_card_to_hand(Card::Four);
+  let evs = table.get(&(&five + Card::Four)).unwrap();
+  assert!(close(specific.stand, evs.stand[Card::Six]));
+  assert!(close(specific.hit, evs.hit.as_ref().unwrap()[Card::Six]));
+}
+
+#[test]
+fn dealer_hits_soft_17() {
+  let deck = create_standard_deck();
+  let h17 = RuleSet {
+    dealer_hits_soft_17: true,
+    ..RuleSet::default()
+  };
+  let s17_ev = compute_all_hand_ev(&deck, &RuleSet::default());
+  let h17_ev = compute_all_hand_ev(&deck, &h17);
+  // A six and an ace is a sure loss for the dealer against 18 unless they draw
+  let eighteen = Hand::from([Card::Eight, Card::Ten]);
+  let s17_stand = &s17_ev.get(&eighteen).unwrap().stand;
+  let h17_stand = &h17_ev.get(&eighteen).unwrap().stand;
+  assert!(h17_stand[Card::Six].unwrap() < s17_stand[Card::Six].unwrap());
+}
+
+#[test]
+fn blackjack_payout() {
+  let deck = create_standard_deck();
+  let six_five = RuleSet {
+    blackjack_payout: 1.2,
+    ..RuleSet::default()
+  };
+  let three_two_ev = compute_all_hand_ev(&deck, &RuleSet::default());
+  let six_five_ev = compute_all_hand_ev(&deck, &six_five);
+  let natural = Hand::from([Card::Ace, Card::Ten]);
+  for (card, ev) in three_two_ev.get(&natural).unwrap().stand.iter() {
+    let six_five_stand = six_five_ev.get(&natural).unwrap().stand[card].unwrap();
+    assert!((six_five_stand - ev * 1.2 / 1.5).abs() < 1e-12);
+  }
+  assert!(
+    compute_overall_prob(&deck, &six_five_ev) < compute_overall_prob(&deck, &three_two_ev)
+  );
+}
+
+#[test]
+fn late_surrender() {
+  let deck = create_standard_deck();
+  let late = RuleSet {
+    surrender: Surrender::Late,
+    ..RuleSet::default()
+  };
+  let no_surrender_ev = compute_all_hand_ev(&deck, &RuleSet::default());
+  let late_ev = compute_all_hand_ev(&deck, &late);
+  let sixteen = late_ev.get(&Hand::from([Card::Six, Card::Ten])).unwrap();
+  assert!(no_surrender_ev
+    .get(&Hand::from([Card::Six, Card::Ten]))
+    .unwrap()
+    .surrender
+    .is_none());
+  let surrender = sixteen.surrender.as_ref().unwrap();
+  // Against a ten the dealer peeks first, so a dealer blackjack still costs
+  // the whole bet
+  let p_bj = 1.0 / 21.0;
+  assert!((surrender[Card::Ten].unwrap() - (-0.5 * (1.0 - p_bj) - p_bj)).abs() < 1e-12);
+  assert_eq!(surrender[Card::Five].unwrap(), -0.5);
+  assert!(surrender[Card::Ten].unwrap() > sixteen.stand[Card::Ten].unwrap());
+  assert!(compute_overall_prob(&deck, &late_ev) > compute_overall_prob(&deck, &no_surrender_ev));
+}
+
+#[test]
+fn early_surrender() {
+  let deck = create_standard_deck();
+  let rules = |surrender| RuleSet {
+    surrender,
+    ..RuleSet::default()
+  };
+  let late_ev = compute_all_hand_ev(&deck, &rules(Surrender::Late));
+  let early_ev = compute_all_hand_ev(&deck, &rules(Surrender::Early));
+  let sixteen = Hand::from([Card::Six, Card::Ten]);
+  let late = late_ev.get(&sixteen).unwrap();
+  let early = early_ev.get(&sixteen).unwrap();
+  assert!(early.surrender.is_none());
+  let early_surrender = early.early_surrender.as_ref().unwrap();
+  assert_eq!(early_surrender[Card::Ten].unwrap(), -0.5);
+  assert!(early_surrender[Card::Ten].unwrap() > late.surrender.as_ref().unwrap()[Card::Ten].unwrap());
+  assert!(compute_overall_prob(&deck, &early_ev) > compute_overall_prob(&deck, &late_ev));
+
+  let d = remaining(&deck, &[Card::Six, Card::Ten, Card::Ten]);
+  let specific = SpecificHandEV::create(&d, &sixteen, Card::Ten, &rules(Surrender::Early));
+  assert_eq!(specific.early_surrender, Some(-0.5));
+  assert_eq!(specific.stand, early.stand[Card::Ten]);
+
+  // Once a card is drawn the dealer has peeked, so what's left is played as
+  // if the hand had been dealt with three cards
+  let deck = Deck::generate(1);
+  let five = Hand::from([Card::Two, Card::Three]);
+  let mut drawn = SpecificHandEV::create(
+    &remaining(&deck, &[Card::Two, Card::Three, Card::Ten]),
+    &five,
+    Card::Ten,
+    &rules(Surrender::Early),
+  );
+  drawn.add_card_to_hand(Card::Four);
+  let dealt = SpecificHandEV::create(
+    &remaining(&deck, &[Card::Two, Card::Three, Card::Four, Card::Ten]),
+    &(&five + Card::Four),
+    Card::Ten,
+    &rules(Surrender::Early),
+  );
+  assert_eq!(drawn.stand, dealt.stand);
+  assert_eq!(drawn.hit, dealt.hit);
+}
+
+#[test]
+fn resplit() {
+  let deck = create_standard_deck();
+  let nines = Hand::from([Card::Nine, Card::Nine]);
+  let two_hands = compute_all_hand_ev(&deck, &RuleSet::default());
+  let four_hands = compute_all_hand_ev(
+    &deck,
+    &RuleSet {
+      max_split_hands: 4,
+      ..RuleSet::default()
+    },
+  );
+  let two_hands = two_hands.get(&nines).unwrap().split.as_ref().unwrap();
+  let four_hands = four_hands.get(&nines).unwrap().split.as_ref().unwrap();
+  for (card, ev) in two_hands.iter() {
+    assert!(four_hands[card].unwrap() >= *ev);
+  }
+  assert!(four_hands[Card::Six].unwrap() > two_hands[Card::Six].unwrap());
+}
+
+#[test]
+fn double_after_split() {
+  let deck = create_standard_deck();
+  let nines = Hand::from([Card::Nine, Card::Nine]);
+  let split_ev = |double_after_split| {
+    compute_all_hand_ev(
+      &deck,
+      &RuleSet {
+        double_after_split,
+        ..RuleSet::default()
+      },
+    )
+    .remove(&nines)
+    .unwrap()
+    .split
+    .unwrap()
+  };
+  let das = split_ev(Doubling::AnyTwo);
+  let ndas = split_ev(Doubling::Never);
+  let ten_eleven = split_ev(Doubling::Totals {
+    min: 10,
+    max: 11,
+    soft: false,
+  });
+  for (card, ev) in das.iter() {
+    assert!(ten_eleven[card].unwrap() <= *ev);
+    assert!(ndas[card].unwrap() <= ten_eleven[card].unwrap());
+  }
+  // A nine and a two makes eleven, which is worth doubling against a five
+  assert!(ndas[Card::Five].unwrap() < ten_eleven[Card::Five].unwrap());
+}
+
+#[test]
+fn restricted_doubling() {
+  let deck = create_standard_deck();
+  let evs = |double| {
+    compute_all_hand_ev(
+      &deck,
+      &RuleSet {
+        double,
+        ..RuleSet::default()
+      },
+    )
+  };
+  let any_two = evs(Doubling::AnyTwo);
+  let nine_to_eleven = evs(Doubling::NINE_TO_ELEVEN);
+  let ten_or_eleven = evs(Doubling::TEN_OR_ELEVEN);
+  let nine = Hand::from([Card::Two, Card::Seven]);
+  assert!(any_two.get(&nine).unwrap().double.is_some());
+  assert!(nine_to_eleven.get(&nine).unwrap().double.is_some());
+  assert!(ten_or_eleven.get(&nine).unwrap().double.is_none());
+  let soft_eighteen = Hand::from([Card::Ace, Card::Seven]);
+  assert!(nine_to_eleven.get(&soft_eighteen).unwrap().double.is_none());
+  // Soft doubling takes in every soft hand, so A7, A8 and A9 alike
+  let with_soft = Doubling::Totals {
+    min: 9,
+    max: 11,
+    soft: true,
+  };
+  for soft_total in [18, 19, 20].iter() {
+    let hand_value = HandValue::Soft(*soft_total);
+    assert!(with_soft.allows(hand_value));
+    assert!(!Doubling::NINE_TO_ELEVEN.allows(hand_value));
+  }
+  assert!(!with_soft.allows(HandValue::Hard(12)));
+
+  let any_two = compute_overall_prob(&deck, &any_two);
+  let nine_to_eleven = compute_overall_prob(&deck, &nine_to_eleven);
+  let ten_or_eleven = compute_overall_prob(&deck, &ten_or_eleven);
+  assert!(any_two > nine_to_eleven);
+  assert!(nine_to_eleven > ten_or_eleven);
+}
+
+#[test]
+fn no_hole_card() {
+  let deck = create_standard_deck();
+  let evs = |peek, unchecked_blackjack_loss| {
+    compute_all_hand_ev(
+      &deck,
+      &RuleSet {
+        peek,
+        unchecked_blackjack_loss,
+        ..RuleSet::default()
+      },
+    )
+  };
+  let peek = evs(Peek::AceAndTen, BlackjackLoss::AllBets);
+  let obo = evs(Peek::NoHoleCard, BlackjackLoss::OriginalBet);
+  let obbo = evs(Peek::NoHoleCard, BlackjackLoss::AllBets);
+  let ace_only = evs(Peek::AceOnly, BlackjackLoss::AllBets);
+  let eleven = Hand::from([Card::Two, Card::Nine]);
+  let nines = Hand::from([Card::Nine, Card::Nine]);
+  for hand in [&eleven, &nines].iter() {
+    assert_eq!(peek.get(hand).unwrap().double, obo.get(hand).unwrap().double);
+    assert_eq!(peek.get(hand).unwrap().split, obo.get(hand).unwrap().split);
+  }
+  // Losing the doubled bet to an unchecked blackjack only matters against
+  // an ace or a ten
+  let double = |evs: &std::collections::HashMap<Hand, HandEV>, card| {
+    evs.get(&eleven).unwrap().double.as_ref().unwrap()[card].unwrap()
+  };
+  assert!(double(&obbo, Card::Ten) < double(&peek, Card::Ten));
+  assert!(double(&obbo, Card::Ace) < double(&peek, Card::Ace));
+  assert_eq!(double(&obbo, Card::Five), double(&peek, Card::Five));
+  assert!(double(&ace_only, Card::Ten) < double(&peek, Card::Ten));
+  assert_eq!(double(&ace_only, Card::Ace), double(&peek, Card::Ace));
+  let split = |evs: &std::collections::HashMap<Hand, HandEV>| {
+    evs.get(&nines).unwrap().split.as_ref().unwrap()[Card::Ten].unwrap()
+  };
+  assert!(split(&obbo) < split(&peek));
+  assert!(compute_overall_prob(&deck, &obbo) < compute_overall_prob(&deck, &peek));
+
+  // Mid-hand a peeked ten can't be hiding a blackjack, an unpeeked one can
+  let hand = Hand::from([Card::Nine, Card::Nine]);
+  let remaining = (&(&deck - &hand).unwrap() - Card::Ten).unwrap();
+  let stand = |peek| {
+    SpecificHandEV::create(
+      &remaining,
+      &hand,
+      Card::Ten,
+      &RuleSet {
+        peek,
+        ..RuleSet::default()
+      },
+    )
+    .stand
+  };
+  assert!(stand(Peek::NoHoleCard) < stand(Peek::AceAndTen));
+
+  // Once the peek has come up empty the dealer's other hands are all that's
+  // left, so 16 against a ten is a hit and surrender loses exactly half
+  let sixteen = Hand::from([Card::Six, Card::Ten]);
+  let mut remaining = Deck::generate(1);
+  remaining.remove_cards(&[Card::Six, Card::Ten, Card::Ten]);
+  let late = RuleSet {
+    surrender: Surrender::Late,
+    ..RuleSet::default()
+  };
+  let checked = SpecificHandEV::create(&remaining, &sixteen, Card::Ten, &late);
+  assert!((checked.stand.unwrap() - -0.5429518538245023).abs() < 1e-12);
+  assert!((checked.hit.unwrap() - -0.5071033260810113).abs() < 1e-12);
+  assert_eq!(checked.surrender, Some(-0.5));
+}
+
+#[test]
+fn insurance() {
+  let deck = create_standard_deck();
+  let specific = |hand: &Hand, dealer_card, remaining: &Deck| {
+    SpecificHandEV::create(remaining, hand, dealer_card, &RuleSet::default())
+  };
+  let hand = Hand::from([Card::Nine, Card::Nine]);
+  let remaining = (&(&deck - &hand).unwrap() - Card::Ace).unwrap();
+  // 12 tens in 21 cards is well over the third needed to break even
+  let ev = specific(&hand, Card::Ace, &remaining);
+  assert!((ev.insurance.unwrap() - (1.5 * 12.0 / 21.0 - 0.5)).abs() < 1e-12);
+  assert!(ev.even_money.is_none());
+  assert!(specific(&hand, Card::Ten, &(&(&deck - &hand).unwrap() - Card::Ten).unwrap())
+    .insurance
+    .is_none());
+
+  let blackjack = Hand::from([Card::Ten, Card::Ace]);
+  let poor = Deck::from([Card::Ten, Card::Two, Card::Three, Card::Four, Card::Five]);
+  let mut ev = specific(&blackjack, Card::Ace, &poor);
+  assert!(ev.insurance.unwrap() < 0.0);
+  assert!((ev.even_money.unwrap() - (1.0 - 1.5 * 0.8)).abs() < 1e-12);
+  ev.add_card_to_hand(Card::Two);
+  assert!(ev.insurance.is_none());
+}
+
+#[test]
+fn charlie() {
+  let deck = &create_standard_deck()
+    + &Deck::from([Card::Two, Card::Two, Card::Three, Card::Three]);
+  let no_charlie = compute_all_hand_ev(&deck, &RuleSet::default());
+  let five_card = compute_all_hand_ev(
+    &deck,
+    &RuleSet {
+      charlie: Some(5),
+      ..RuleSet::default()
+    },
+  );
+  let five = Hand::from([Card::Two, Card::Two, Card::Two, Card::Three, Card::Three]);
+  let ev = five_card.get(&five).unwrap();
+  for (card, stand) in ev.stand.iter() {
+    assert!(*stand > no_charlie.get(&five).unwrap().stand[card].unwrap());
+  }
+  // Nothing to lose but a dealer blackjack, which the up card rules out here
+  assert_eq!(ev.stand[Card::Five].unwrap(), 1.0);
+
+  // A fifth card that doesn't bust wins, which makes 15 worth hitting
+  let four = Hand::from([Card::Two, Card::Two, Card::Three, Card::Eight]);
+  let ev = five_card.get(&four).unwrap();
+  assert!(ev.hit.as_ref().unwrap()[Card::Nine].unwrap() > ev.stand[Card::Nine].unwrap());
+  let ev = no_charlie.get(&four).unwrap();
+  assert!(ev.hit.as_ref().unwrap()[Card::Nine].unwrap() < ev.stand[Card::Nine].unwrap());
+  assert!(compute_overall_prob(&deck, &five_card) > compute_overall_prob(&deck, &no_charlie));
+}
+
+#[test]
+fn free_bet() {
+  let deck = create_standard_deck();
+  let evs = |rules: &RuleSet| compute_all_hand_ev(&deck, rules);
+  let standard = evs(&RuleSet::default());
+  let push_22 = evs(&RuleSet {
+    push_on_dealer_22: true,
+    ..RuleSet::default()
+  });
+  let twenty = Hand::from([Card::Ten, Card::Ten]);
+  let stand = |evs: &std::collections::HashMap<Hand, HandEV>, card| {
+    evs.get(&twenty).unwrap().stand[card].unwrap()
+  };
+  assert!(stand(&push_22, Card::Six) < stand(&standard, Card::Six));
+  assert!(compute_overall_prob(&deck, &push_22) < compute_overall_prob(&deck, &standard));
+  // A natural still gets paid against a dealer 22
+  let blackjack = Hand::from([Card::Ace, Card::Ten]);
+  assert_eq!(
+    push_22.get(&blackjack).unwrap().stand,
+    standard.get(&blackjack).unwrap().stand
+  );
+
+  // Losing a free double or a free split hand costs nothing extra, so they
+  // can only be worth more than their paid versions
+  let free = evs(&RuleSet {
+    push_on_dealer_22: true,
+    free_double: Doubling::NINE_TO_ELEVEN,
+    free_split: true,
+    ..RuleSet::default()
+  });
+  let eleven = Hand::from([Card::Two, Card::Nine]);
+  let nines = Hand::from([Card::Nine, Card::Nine]);
+  let double = |evs: &std::collections::HashMap<Hand, HandEV>| {
+    evs.get(&eleven).unwrap().double.clone().unwrap()
+  };
+  let split = |evs: &std::collections::HashMap<Hand, HandEV>, hand| {
+    evs.get(hand).unwrap().split.clone().unwrap()
+  };
+  for (card, ev) in double(&free).iter() {
+    assert!(*ev > double(&push_22)[card].unwrap());
+  }
+  for (card, ev) in split(&free, &nines).iter() {
+    assert!(*ev > split(&push_22, &nines)[card].unwrap());
+  }
+  // Tens are never split for free
+  assert_eq!(split(&free, &twenty), split(&push_22, &twenty));
+  assert!(compute_overall_prob(&deck, &free) > compute_overall_prob(&deck, &push_22));
+
+  let free_bet = evs(&RuleSet::free_bet());
+  let paid = evs(&RuleSet {
+    free_double: Doubling::Never,
+    free_split: false,
+    ..RuleSet::free_bet()
+  });
+  assert!(compute_overall_prob(&deck, &free_bet) > compute_overall_prob(&deck, &paid));
+}
+
+#[test]
+fn spanish_21() {
+  let spanish = Deck::generate_spanish(2);
+  assert_eq!(spanish.get_count(), 96);
+  assert_eq!(spanish.get_count_of_card(Card::Ten), 24);
+  assert_eq!(spanish.get_count_of_card(Card::Five), 8);
+
+  let deck = &create_standard_deck() + &Deck::from([Card::Six, Card::Seven, Card::Seven]);
+  let evs = |rules: &RuleSet| compute_all_hand_ev(&deck, rules);
+  let standard = evs(&RuleSet::default());
+  let wins = evs(&RuleSet {
+    player_21_wins: true,
+    ..RuleSet::default()
+  });
+  let bonuses = evs(&RuleSet {
+    player_21_wins: true,
+    twenty_one_bonuses: true,
+    ..RuleSet::default()
+  });
+  let stand = |evs: &std::collections::HashMap<Hand, HandEV>, hand: &Hand, card| {
+    evs.get(hand).unwrap().stand[card].unwrap()
+  };
+  let soft_21 = Hand::from([Card::Ace, Card::Two, Card::Eight]);
+  assert!(stand(&wins, &soft_21, Card::Seven) > stand(&standard, &soft_21, Card::Seven));
+  // Nothing beats it against a six, so the bonus is paid on every outcome
+  let six_seven_eight = Hand::from([Card::Six, Card::Seven, Card::Eight]);
+  assert!(
+    (stand(&bonuses, &six_seven_eight, Card::Six) - 1.5 * stand(&wins, &six_seven_eight, Card::Six))
+      .abs()
+      < 1e-12
+  );
+  assert_eq!(
+    stand(&bonuses, &soft_21, Card::Six),
+    stand(&wins, &soft_21, Card::Six)
+  );
+  // Doubling into 6-7-8 only pays evens
+  let six_seven = Hand::from([Card::Six, Card::Seven]);
+  assert_eq!(
+    bonuses.get(&six_seven).unwrap().double,
+    wins.get(&six_seven).unwrap().double
+  );
+
+  let rescue = evs(&RuleSet {
+    double_rescue: true,
+    ..RuleSet::default()
+  });
+  let double = |evs: &std::collections::HashMap<Hand, HandEV>, hand| {
+    evs.get(hand).unwrap().double.clone().unwrap()
+  };
+  let twelve = Hand::from([Card::Two, Card::Ten]);
+  for (card, ev) in double(&rescue, &twelve).iter() {
+    assert!(*ev >= double(&standard, &twelve)[card].unwrap());
+  }
+  assert!(double(&rescue, &twelve)[Card::Ten].unwrap() > double(&standard, &twelve)[Card::Ten].unwrap());
+
+  let pack = evs(&RuleSet {
+    max_split_hands: 2,
+    ..RuleSet::spanish_21()
+  });
+  let no_bonuses = evs(&RuleSet {
+    max_split_hands: 2,
+    twenty_one_bonuses: false,
+    ..RuleSet::spanish_21()
+  });
+  assert!(compute_overall_prob(&deck, &pack) > compute_overall_prob(&deck, &no_bonuses));
+
+  // A player natural beats a dealer natural, so it's paid whatever the hole
+  // card is and even money is never worth taking
+  let aces = &deck + &Deck::from([Card::Ace, Card::Ace]);
+  let natural_evs = |blackjack_ties| {
+    compute_all_hand_ev(
+      &aces,
+      &RuleSet {
+        max_split_hands: 2,
+        blackjack_ties,
+        ..RuleSet::spanish_21()
+      },
+    )
+  };
+  let natural = Hand::from([Card::Ace, Card::Ten]);
+  let wins = natural_evs(BlackjackTies::PlayerWins);
+  let pushes = natural_evs(BlackjackTies::Push);
+  for (card, ev) in wins.get(&natural).unwrap().stand.iter() {
+    assert!((ev - 1.5).abs() < 1e-12, "{:?} {}", card, ev);
+  }
+  assert!(pushes.get(&natural).unwrap().stand[Card::Ace].unwrap() < 1.5);
+  assert!(pushes.get(&natural).unwrap().stand[Card::Ten].unwrap() < 1.5);
+  let remaining = (&(&aces - &natural).unwrap() - Card::Ace).unwrap();
+  let specific = SpecificHandEV::create(&remaining, &natural, Card::Ace, &RuleSet::spanish_21());
+  assert!((specific.even_money.unwrap() - -0.5).abs() < 1e-12);
+}
+
+#[test]
+fn dealer_wins_ties() {
+  let deck = &create_standard_deck() + &Deck::from([Card::Two, Card::Two, Card::Three, Card::Three]);
+  let evs = |ties| {
+    compute_all_hand_ev(
+      &deck,
+      &RuleSet {
+        ties,
+        ..RuleSet::default()
+      },
+    )
+  };
+  let push = evs(Ties::Push);
+  let all = evs(Ties::DealerWins);
+  let high = evs(Ties::DealerWinsBetween { min: 19, max: 21 });
+  let stand = |evs: &std::collections::HashMap<Hand, HandEV>, hand: &Hand| {
+    evs.get(hand).unwrap().stand[Card::Six].unwrap()
+  };
+  let eighteen = Hand::from([Card::Eight, Card::Ten]);
+  let twenty = Hand::from([Card::Ten, Card::Ten]);
+  let sixteen = Hand::from([Card::Seven, Card::Nine]);
+  assert!(stand(&all, &eighteen) < stand(&push, &eighteen));
+  assert_eq!(stand(&high, &eighteen), stand(&push, &eighteen));
+  assert!(stand(&high, &twenty) < stand(&push, &twenty));
+  // The dealer never stops below 17, so there's nothing to tie with
+  assert_eq!(stand(&all, &sixteen), stand(&push, &sixteen));
+  assert!(compute_overall_prob(&deck, &all) < compute_overall_prob(&deck, &high));
+  assert!(compute_overall_prob(&deck, &high) < compute_overall_prob(&deck, &push));
+
+  let pontoon = compute_all_hand_ev(&deck, &RuleSet::pontoon());
+  let trick = Hand::from([Card::Two, Card::Two, Card::Two, Card::Three, Card::Three]);
+  assert_eq!(pontoon.get(&trick).unwrap().stand[Card::Five].unwrap(), 2.0);
+  let pontoon_pushes = compute_all_hand_ev(
+    &deck,
+    &RuleSet {
+      ties: Ties::Push,
+      ..RuleSet::pontoon()
+    },
+  );
+  assert!(compute_overall_prob(&deck, &pontoon) < compute_overall_prob(&deck, &pontoon_pushes));
+
+  // Tied totals don't decide two naturals, only Pontoon's dealer takes those
+  let aces = &deck + &Deck::from([Card::Ace, Card::Ace]);
+  let natural = Hand::from([Card::Ace, Card::Ten]);
+  let natural_stand = |rules: &RuleSet| {
+    compute_all_hand_ev(&aces, rules).get(&natural).unwrap().stand[Card::Ten].unwrap()
+  };
+  let p_bj = 2.0 / 27.0;
+  let high = RuleSet {
+    ties: Ties::DealerWinsBetween { min: 19, max: 21 },
+    ..RuleSet::default()
+  };
+  assert!((natural_stand(&high) - 1.5 * (1.0 - p_bj)).abs() < 1e-12);
+  assert!((natural_stand(&RuleSet::pontoon()) - (2.0 * (1.0 - p_bj) - p_bj)).abs() < 1e-12);
+}
+
+#[test]
+fn blackjack_switch() {
+  let rules = RuleSet::blackjack_switch();
+  let deck = create_standard_deck();
+
+  // 16 and 15 become 20 and 11
+  let d = remaining(&deck, &[Card::Ten, Card::Six, Card::Five, Card::Ten, Card::Seven]);
+  let ev = compute_switch_ev(&d, [Card::Ten, Card::Six], [Card::Five, Card::Ten], Card::Seven, &rules);
+  assert!(ev.should_switch());
+  let best = |hand: Hand| {
+    let ev = SpecificHandEV::create(&d, &hand, Card::Seven, &rules);
+    [ev.stand, ev.hit, ev.double, ev.split]
+      .iter()
+      .flatten()
+      .fold(f64::MIN, |a, b| a.max(*b))
+  };
+  let switched = best(Hand::from([Card::Ten, Card::Ten])) + best(Hand::from([Card::Five, Card::Six]));
+  assert!((ev.switch - switched).abs() < 1e-12);
+  assert_eq!(ev.ev(), ev.switch);
+
+  // Swapping an ace onto a ten only makes a 21, which a dealer 21 pushes
+  let d = remaining(&deck, &[Card::Ace, Card::Nine, Card::Eight, Card::Ten, Card::Seven]);
+  let ev = compute_switch_ev(&d, [Card::Ace, Card::Nine], [Card::Eight, Card::Ten], Card::Seven, &rules);
+  let natural = compute_switch_ev(&d, [Card::Ace, Card::Ten], [Card::Eight, Card::Nine], Card::Seven, &rules);
+  assert!(ev.switch < natural.keep);
+  // With naturals paying evens, 20 and 18 are worth more than one and a 17
+  assert!(natural.should_switch());
+}
+
+#[test]
+fn double_exposure() {
+  let rules = RuleSet::double_exposure();
+  let deck = create_standard_deck();
+
+  // A dealer 20 can't be beaten standing on 19 or 20
+  let d = remaining(&deck, &[Card::Nine, Card::Ten, Card::Ten, Card::Ten]);
+  let nineteen = Hand::from([Card::Nine, Card::Ten]);
+  let ev = SpecificHandEV::create_double_exposure(&d, &nineteen, [Card::Ten, Card::Ten], &rules);
+  assert_eq!(ev.stand, Some(-1.0));
+  assert!(ev.hit.unwrap() > -1.0);
+  assert!(ev.insurance.is_none());
+
+  // A twelve stands against a visible 16, but hits against a ten up card
+  let twelve = Hand::from([Card::Two, Card::Ten]);
+  let d = remaining(&deck, &[Card::Two, Card::Ten, Card::Ten, Card::Six]);
+  let exposed = SpecificHandEV::create_double_exposure(&d, &twelve, [Card::Ten, Card::Six], &rules);
+  assert!(exposed.stand.unwrap() > exposed.hit.unwrap());
+  let hidden = SpecificHandEV::create(&(&d + Card::Six), &twelve, Card::Ten, &rules);
+  assert!(hidden.stand.unwrap() < hidden.hit.unwrap());
+  assert!(exposed.stand.unwrap() > hidden.stand.unwrap());
+
+  // Nothing gets away from a visible natural
+  let d = remaining(&deck, &[Card::Nine, Card::Ten, Card::Ace, Card::Ten]);
+  let ev = SpecificHandEV::create_double_exposure(&d, &nineteen, [Card::Ace, Card::Ten], &rules);
+  assert_eq!(ev.stand, Some(-1.0));
+  assert!((ev.hit.unwrap() + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn partial_hole_card() {
+  let rules = RuleSet::default();
+  let deck = create_standard_deck();
+  let thirteen = Hand::from([Card::Three, Card::Ten]);
+  let d = remaining(&deck, &[Card::Three, Card::Ten, Card::Ten]);
+
+  // A peek that found no ace under a ten is what the checked EVs assume
+  let not_ace = |c| c != Card::Ace;
+  let late = RuleSet {
+    surrender: Surrender::Late,
+    ..RuleSet::default()
+  };
+  let peeked = SpecificHandEV::create_with_hole(&d, &thirteen, Card::Ten, not_ace, &late);
+  let checked = SpecificHandEV::create(&d, &thirteen, Card::Ten, &late);
+  let same = |a: Option<f64>, b: Option<f64>| (a.unwrap() - b.unwrap()).abs() < 1e-12;
+  assert!(same(peeked.stand, checked.stand));
+  assert!(same(peeked.hit, checked.hit));
+  assert!(same(peeked.double, checked.double));
+  assert!(same(peeked.surrender, checked.surrender));
+  let nines = Hand::from([Card::Nine, Card::Nine]);
+  let d_nines = remaining(&deck, &[Card::Nine, Card::Nine, Card::Ten]);
+  let peeked_nines = SpecificHandEV::create_with_hole(&d_nines, &nines, Card::Ten, not_ace, &late);
+  let checked_nines = SpecificHandEV::create(&d_nines, &nines, Card::Ten, &late);
+  assert!(same(peeked_nines.split, checked_nines.split));
+
+  // Knowing the exact card is the same as seeing it
+  let six = SpecificHandEV::create_with_hole(&d, &thirteen, Card::Ten, |c| c == Card::Six, &rules);
+  let exposed = SpecificHandEV::create_double_exposure(
+    &remaining(&deck, &[Card::Three, Card::Ten, Card::Ten, Card::Six]),
+    &thirteen,
+    [Card::Ten, Card::Six],
+    &rules,
+  );
+  assert!(same(six.stand, exposed.stand));
+  assert!(same(six.hit, exposed.hit));
+  assert!(same(six.double, exposed.double));
+
+  // A tell for a small hole card leaves the dealer a stiff to draw to
+  let small = |c| Card::Two <= c && c <= Card::Six;
+  let tell = SpecificHandEV::create_with_hole(&d, &thirteen, Card::Ten, small, &rules);
+  assert!(tell.stand.unwrap() > tell.hit.unwrap());
+  assert!(tell.stand.unwrap() > peeked.stand.unwrap());
+}
+
+#[test]
+fn next_cards() {
+  let rules = RuleSet::default();
+  let mut deck = create_standard_deck();
+  deck.add_cards(&[Card::Six, Card::Eight]);
+  let close = |a: Option<f64>, b: Option<f64>| (a.unwrap() - b.unwrap()).abs() < 1e-12;
+
+  // Knowing nothing about the order changes nothing
+  let sixteen = Hand::from([Card::Ten, Card::Six]);
+  let d = remaining(&deck, &[Card::Ten, Card::Six, Card::Ten]);
+  let plain = SpecificHandEV::create(&d, &sixteen, Card::Ten, &rules);
+  let none = SpecificHandEV::create_with_next_cards(&d, &sixteen, Card::Ten, &[], &rules);
+  assert_eq!(none.stand, plain.stand);
+  assert_eq!(none.hit, plain.hit);
+
+  // A five on top makes hitting a 21 against a random dealer
+  let mut five =
+    SpecificHandEV::create_with_next_cards(&d, &sixteen, Card::Ten, &[Card::Five], &rules);
+  let rest = remaining(&deck, &[Card::Ten, Card::Six, Card::Ten, Card::Five]);
+  let twenty_one = Hand::from([Card::Ten, Card::Six, Card::Five]);
+  let made = SpecificHandEV::create(&rest, &twenty_one, Card::Ten, &rules);
+  assert!(close(five.hit, made.stand));
+  assert!(five.hit.unwrap() > five.stand.unwrap());
+  five.add_card_to_hand(Card::Five);
+  assert!(close(five.stand, made.stand));
+
+  // A ten on top busts the hit, and the dealer's stiffs too
+  let ten = SpecificHandEV::create_with_next_cards(&d, &sixteen, Card::Ten, &[Card::Ten], &rules);
+  assert_eq!(ten.hit, Some(-1.0));
+  let twelve = Hand::from([Card::Ten, Card::Two]);
+  let d = remaining(&deck, &[Card::Ten, Card::Two, Card::Six]);
+  let stiff = SpecificHandEV::create_with_next_cards(&d, &twelve, Card::Six, &[Card::Ten], &rules);
+  let random = SpecificHandEV::create(&d, &twelve, Card::Six, &rules);
+  assert!(stiff.stand.unwrap() > random.stand.unwrap());
+  assert_eq!(stiff.hit, Some(-1.0));
+
+  // Doubling 11 into a ten
+  let eleven = Hand::from([Card::Five, Card::Six]);
+  let d = remaining(&deck, &[Card::Five, Card::Six, Card::Six]);
+  let double = SpecificHandEV::create_with_next_cards(&d, &eleven, Card::Six, &[Card::Ten], &rules);
+  let rest = remaining(&deck, &[Card::Five, Card::Six, Card::Six, Card::Ten]);
+  let made = SpecificHandEV::create(&rest, &(&eleven + Card::Ten), Card::Six, &rules);
+  assert!((double.double.unwrap() - 2.0 * made.stand.unwrap()).abs() < 1e-12);
+
+  // Splitting eights into two tens leaves two 18s to stand on
+  let eights = Hand::from([Card::Eight, Card::Eight]);
+  let d = remaining(&deck, &[Card::Eight, Card::Eight, Card::Ten]);
+  let next = [Card::Ten, Card::Ten];
+  let split = SpecificHandEV::create_with_next_cards(&d, &eights, Card::Ten, &next, &rules);
+  let rest = remaining(&deck, &[Card::Eight, Card::Eight, Card::Ten, Card::Ten, Card::Ten]);
+  let eighteen = Hand::from([Card::Eight, Card::Ten]);
+  let eighteen = SpecificHandEV::create(&rest, &eighteen, Card::Ten, &rules);
+  assert!((split.split.unwrap() - 2.0 * eighteen.stand.unwrap()).abs() < 1e-12);
+}
+
+#[test]
+fn dealer_policy() {
+  let deck = create_standard_deck();
+  let eighteen = Hand::from([Card::Eight, Card::Ten]);
+
+  // Policies that play like the built in dealers come out the same
+  let stand_on_17 = RuleSet {
+    dealer_policy: Some(Arc::new(StandOn(17))),
+    ..RuleSet::default()
+  };
+  let h17 = RuleSet {
+    dealer_hits_soft_17: true,
+    ..RuleSet::default()
+  };
+  let policy = |total: u32, soft: bool| total > 17 || (total == 17 && !soft);
+  let hits_soft_17 = RuleSet {
+    dealer_policy: Some(Arc::new(policy)),
+    ..RuleSet::default()
+  };
+  // Neither looks at the player, so each deck is only played out once
+  assert!(!stand_on_17.dealer_policy.as_ref().unwrap().reads_player());
+  assert!(!hits_soft_17.dealer_policy.as_ref().unwrap().reads_player());
+  for (a, b) in [(&RuleSet::default(), &stand_on_17), (&h17, &hits_soft_17)].iter() {
+    let a = compute_all_hand_ev(&deck, a);
+    let b = compute_all_hand_ev(&deck, b);
+    for (card, ev) in a.get(&eighteen).unwrap().stand.iter() {
+      assert!((b.get(&eighteen).unwrap().stand[card].unwrap() - ev).abs() < 1e-12);
+    }
+    assert!((compute_overall_prob(&deck, &a) - compute_overall_prob(&deck, &b)).abs() < 1e-12);
+  }
+
+  // A dealer hitting until they're ahead never pushes a 16, so it either
+  // busts or beats it
+  let rules = RuleSet {
+    dealer_policy: Some(Arc::new(HitToBeat)),
+    ..RuleSet::default()
+  };
+  let sixteen = Hand::from([Card::Ten, Card::Six]);
+  let mut remaining = deck.clone();
+  remaining.remove_cards(&[Card::Ten, Card::Six, Card::Five]);
+  let ev = SpecificHandEV::create(&remaining, &sixteen, Card::Five, &rules);
+  let dealer_deck = &remaining + Card::Five;
+  let p = lib_dealer::calculate_dealer_prob_with(&<[usize; 10]>::from(&dealer_deck), &|total, _| {
+    total > 16 || total >= 21
+  })[4];
+  assert!((ev.stand.unwrap() - (2.0 * p.p_bust - 1.0)).abs() < 1e-12);
+  // Against 13 they stop on 14 to 16 where a dealer who stops on 17 would
+  // still draw, which makes standing worse
+  let thirteen = Hand::from([Card::Ten, Card::Three]);
+  let mut remaining = deck.clone();
+  remaining.remove_cards(&[Card::Ten, Card::Three, Card::Five]);
+  let ev = SpecificHandEV::create(&remaining, &thirteen, Card::Five, &rules);
+  let standard = SpecificHandEV::create(&remaining, &thirteen, Card::Five, &RuleSet::default());
+  assert!(ev.stand.unwrap() < standard.stand.unwrap());
+
+  // Dealer totals below 17 beat lower player totals, push the same one and
+  // lose to higher ones
+  let hit_to_beat = ReadsPlayer(|t: u32, _: bool, player: u32| t > player || t >= 21);
+  let policies: [Arc<dyn DealerPolicy>; 3] =
+    [Arc::new(StandOn(16)), Arc::new(HitToBeat), Arc::new(hit_to_beat)];
+  for policy in policies.iter() {
+    let rules = RuleSet {
+      dealer_policy: Some(policy.clone()),
+      ..RuleSet::default()
+    };
+    for card in [Card::Two, Card::Three, Card::Four, Card::Five, Card::Six, Card::Seven].iter() {
+      let hand = Hand::from([Card::Ten, *card]);
+      let total = 10 + *card as usize;
+      let mut remaining = Deck::generate(1);
+      remaining.remove_cards(&[Card::Ten, *card, Card::Six]);
+      let ev = SpecificHandEV::create(&remaining, &hand, Card::Six, &rules);
+      let dealer_deck = &remaining + Card::Six;
+      let p = lib_dealer::calculate_dealer_prob_with(&dealer_deck.counts(), &|t, s| {
+        policy.stands_against(t, s, total as u32)
+      })[5];
+      let mut totals = p.p_low.to_vec();
+      totals.extend_from_slice(&[p.p_17, p.p_18, p.p_19, p.p_20, p.p_21]);
+      let won: f64 = totals[..total].iter().sum::<f64>() + p.p_bust;
+      let lost: f64 = totals[total + 1..].iter().sum::<f64>() + p.p_bj;
+      assert!((ev.stand.unwrap() - (won - lost)).abs() < 1e-12);
+    }
+  }
+}
+
+#[test]
+fn packed_deck() {
+  // Eight decks hold more tens than fit in a byte
+  let shoe = Deck::generate(8);
+  assert_eq!(shoe.get_count(), 416);
+  assert_eq!(shoe.get_count_of_card(Card::Ten), 128);
+  let hand = Hand::from([Card::Ten, Card::Ten, Card::Ace]);
+  let rest = (&shoe - &hand).unwrap();
+  assert_eq!(rest.get_count(), 413);
+  assert_eq!(rest.counts(), [31, 32, 32, 32, 32, 32, 32, 32, 32, 126]);
+  assert_eq!(<[usize; 10]>::from(&rest), rest.counts());
+  assert_eq!(&rest + &hand, shoe);
+  assert!((&hand - &shoe).is_none());
+  assert!((&Deck::from([Card::Two]) - &Deck::from([Card::Three])).is_none());
+  assert_eq!(rest.iter().count(), 413);
+  assert_eq!(rest.rank_iter().count(), 10);
+
+  let blackjack = Hand::from([Card::Ten, Card::Ace]);
+  assert!(blackjack.is_blackjack());
+  assert_eq!(
+    format!("{:?}", blackjack),
+    "Deck { cards: [1, 0, 0, 0, 0, 0, 0, 0, 0, 1], card_count: 2 }"
+  );
+}